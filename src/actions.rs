@@ -1,8 +1,21 @@
-use std::sync::{Arc, atomic::AtomicBool};
+use std::{
+    sync::{Arc, atomic::AtomicBool},
+    time::Duration,
+};
 
-use rusmpp::pdus::{BindAny, SubmitSm};
+use parking_lot::Mutex;
+use rusmpp::{
+    Command,
+    pdus::{
+        BindAny, BroadcastSm, CancelBroadcastSm, CancelSm, DataSm, QueryBroadcastSm, QuerySm,
+        ReplaceSm, SubmitMulti, SubmitSm,
+    },
+};
 
-use crate::{types::SmppUrl, values::BindMode};
+use crate::{
+    types::SmppUrl,
+    values::{BindMode, ResponseStatus},
+};
 
 #[derive(Clone)]
 pub struct ActionsChannel {
@@ -24,6 +37,7 @@ impl ActionsChannel {
         url: SmppUrl,
         interval: u64,
         bind: BindAny,
+        deliver_sm_resp: SharedAutoResponse,
         loading: Arc<AtomicBool>,
     ) {
         let action = Action::Bind(BindAction {
@@ -31,12 +45,35 @@ impl ActionsChannel {
             interval,
             url,
             bind,
+            deliver_sm_resp,
             loading,
         });
 
         self.send(action);
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn listen(
+        &self,
+        addr: std::net::SocketAddr,
+        interval: u64,
+        bind: BindAny,
+        deliver_sm_resp: SharedAutoResponse,
+        loading: Arc<AtomicBool>,
+        cancel: Arc<AtomicBool>,
+    ) {
+        let action = Action::Listen(ListenAction {
+            addr,
+            interval,
+            bind,
+            deliver_sm_resp,
+            loading,
+            cancel,
+        });
+
+        self.send(action);
+    }
+
     pub fn unbind(&self, loading: Arc<AtomicBool>) {
         self.send(Action::Unbind(UnbindAction { loading }));
     }
@@ -44,6 +81,108 @@ impl ActionsChannel {
     pub fn submit_sms(&self, sms: Vec<SubmitSm>) {
         self.send(Action::SubmitSms(SubmitSmsAction { sms }));
     }
+
+    pub fn query_sm(&self, query_sm: QuerySm, response: ResponseSlot, loading: Arc<AtomicBool>) {
+        self.send(Action::QuerySm(QuerySmAction {
+            query_sm,
+            response,
+            loading,
+        }));
+    }
+
+    pub fn cancel_sm(&self, cancel_sm: CancelSm, response: ResponseSlot, loading: Arc<AtomicBool>) {
+        self.send(Action::CancelSm(CancelSmAction {
+            cancel_sm,
+            response,
+            loading,
+        }));
+    }
+
+    pub fn replace_sm(
+        &self,
+        replace_sm: ReplaceSm,
+        response: ResponseSlot,
+        loading: Arc<AtomicBool>,
+    ) {
+        self.send(Action::ReplaceSm(ReplaceSmAction {
+            replace_sm,
+            response,
+            loading,
+        }));
+    }
+
+    pub fn submit_multi(
+        &self,
+        sms: Vec<SubmitMulti>,
+        responses: ResponsesSlot,
+        loading: Arc<AtomicBool>,
+    ) {
+        self.send(Action::SubmitMulti(SubmitMultiAction {
+            sms,
+            responses,
+            loading,
+        }));
+    }
+
+    pub fn data_sm(&self, data_sm: DataSm, response: ResponseSlot, loading: Arc<AtomicBool>) {
+        self.send(Action::DataSm(DataSmAction {
+            data_sm,
+            response,
+            loading,
+        }));
+    }
+
+    pub fn broadcast_sm(
+        &self,
+        broadcast_sm: BroadcastSm,
+        response: ResponseSlot,
+        loading: Arc<AtomicBool>,
+    ) {
+        self.send(Action::BroadcastSm(BroadcastSmAction {
+            broadcast_sm,
+            response,
+            loading,
+        }));
+    }
+
+    pub fn query_broadcast_sm(
+        &self,
+        query_broadcast_sm: QueryBroadcastSm,
+        response: ResponseSlot,
+        loading: Arc<AtomicBool>,
+    ) {
+        self.send(Action::QueryBroadcastSm(QueryBroadcastSmAction {
+            query_broadcast_sm,
+            response,
+            loading,
+        }));
+    }
+
+    pub fn cancel_broadcast_sm(
+        &self,
+        cancel_broadcast_sm: CancelBroadcastSm,
+        response: ResponseSlot,
+        loading: Arc<AtomicBool>,
+    ) {
+        self.send(Action::CancelBroadcastSm(CancelBroadcastSmAction {
+            cancel_broadcast_sm,
+            response,
+            loading,
+        }));
+    }
+
+    pub fn replay(
+        &self,
+        sms: Vec<(Duration, SubmitSm)>,
+        loading: Arc<AtomicBool>,
+        cancel: Arc<AtomicBool>,
+    ) {
+        self.send(Action::Replay(ReplayAction {
+            sms,
+            loading,
+            cancel,
+        }));
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -52,6 +191,17 @@ pub enum Action {
     Bind(BindAction),
     Unbind(UnbindAction),
     SubmitSms(SubmitSmsAction),
+    QuerySm(QuerySmAction),
+    CancelSm(CancelSmAction),
+    ReplaceSm(ReplaceSmAction),
+    SubmitMulti(SubmitMultiAction),
+    DataSm(DataSmAction),
+    BroadcastSm(BroadcastSmAction),
+    QueryBroadcastSm(QueryBroadcastSmAction),
+    CancelBroadcastSm(CancelBroadcastSmAction),
+    Replay(ReplayAction),
+    #[cfg(not(target_arch = "wasm32"))]
+    Listen(ListenAction),
 }
 
 #[derive(Debug, Clone)]
@@ -60,9 +210,33 @@ pub struct BindAction {
     pub url: SmppUrl,
     pub interval: u64,
     pub bind: BindAny,
+    pub deliver_sm_resp: SharedAutoResponse,
     pub loading: Arc<AtomicBool>,
 }
 
+/// Waits on a local address for an SMSC to `Outbind`, then binds as a receiver
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct ListenAction {
+    pub addr: std::net::SocketAddr,
+    pub interval: u64,
+    pub bind: BindAny,
+    pub deliver_sm_resp: SharedAutoResponse,
+    pub loading: Arc<AtomicBool>,
+    /// Set to stop waiting for the SMSC
+    pub cancel: Arc<AtomicBool>,
+}
+
+/// How the background app responds to incoming requests
+#[derive(Debug, Clone, Copy)]
+pub struct AutoResponse {
+    pub status: ResponseStatus,
+    pub delay: Duration,
+}
+
+/// [`AutoResponse`] of the `BindApp`, read for every incoming request so it can be changed while bound
+pub type SharedAutoResponse = Arc<Mutex<AutoResponse>>;
+
 #[derive(Debug, Clone)]
 pub struct UnbindAction {
     pub loading: Arc<AtomicBool>,
@@ -72,3 +246,74 @@ pub struct UnbindAction {
 pub struct SubmitSmsAction {
     pub sms: Vec<SubmitSm>,
 }
+
+/// Filled by the background app with the response to a request, or the error that prevented it
+pub type ResponseSlot = Arc<Mutex<Option<Result<Command, String>>>>;
+
+#[derive(Debug, Clone)]
+pub struct QuerySmAction {
+    pub query_sm: QuerySm,
+    pub response: ResponseSlot,
+    pub loading: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CancelSmAction {
+    pub cancel_sm: CancelSm,
+    pub response: ResponseSlot,
+    pub loading: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplaceSmAction {
+    pub replace_sm: ReplaceSm,
+    pub response: ResponseSlot,
+    pub loading: Arc<AtomicBool>,
+}
+
+/// Filled by the background app with the response to each part of a message, in order
+pub type ResponsesSlot = Arc<Mutex<Vec<Result<Command, String>>>>;
+
+#[derive(Debug, Clone)]
+pub struct SubmitMultiAction {
+    pub sms: Vec<SubmitMulti>,
+    pub responses: ResponsesSlot,
+    pub loading: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DataSmAction {
+    pub data_sm: DataSm,
+    pub response: ResponseSlot,
+    pub loading: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BroadcastSmAction {
+    pub broadcast_sm: BroadcastSm,
+    pub response: ResponseSlot,
+    pub loading: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct QueryBroadcastSmAction {
+    pub query_broadcast_sm: QueryBroadcastSm,
+    pub response: ResponseSlot,
+    pub loading: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CancelBroadcastSmAction {
+    pub cancel_broadcast_sm: CancelBroadcastSm,
+    pub response: ResponseSlot,
+    pub loading: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplayAction {
+    /// Messages with the time to send each of them at, relative to the start of the replay
+    pub sms: Vec<(Duration, SubmitSm)>,
+    pub loading: Arc<AtomicBool>,
+    /// Set by the UI to stop sending the remaining messages
+    pub cancel: Arc<AtomicBool>,
+}
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     actions::{Action, ActionsChannel},
-    apps::{SerdeTabs, Tabs},
+    apps::{Alerts, SerdeTabs, Tabs},
     background::BackgroundApp,
     state::AppState,
     widgets::{BindIndicator, EventIndicator},
@@ -19,6 +19,7 @@ struct SerdeApp {
 pub struct App {
     state: AppState,
     tabs: Tabs,
+    alerts: Alerts,
     incoming_event_indicator: EventIndicator,
     outgoing_event_indicator: EventIndicator,
     version: &'static str,
@@ -34,8 +35,11 @@ impl App {
     ) -> Self {
         egui_material_icons::initialize(&cc.egui_ctx);
 
+        let alerts = Alerts::new(state.holder());
+
         Self {
             tabs,
+            alerts,
             state,
             incoming_event_indicator,
             outgoing_event_indicator,
@@ -214,6 +218,8 @@ impl eframe::App for App {
             egui::CentralPanel::default().show(ctx, |ui| {
                 self.tabs.ui(ctx, ui);
             });
+
+            self.alerts.ui(ctx);
         });
     }
 
@@ -4,8 +4,39 @@ use bind::{BindApp, SerdeBindApp};
 mod submit_sm;
 use submit_sm::{SerdeSubmitSmApp, SubmitSmApp};
 
+mod submit_multi;
+use submit_multi::{SerdeSubmitMultiApp, SubmitMultiApp};
+
+mod data_sm;
+use data_sm::{DataSmApp, SerdeDataSmApp};
+
+mod broadcast_sm;
+use broadcast_sm::{BroadcastSmApp, SerdeBroadcastSmApp};
+
+mod query_sm;
+use query_sm::{QuerySmApp, SerdeQuerySmApp};
+
+mod cancel_sm;
+use cancel_sm::{CancelSmApp, SerdeCancelSmApp};
+
+mod replace_sm;
+use replace_sm::{ReplaceSmApp, SerdeReplaceSmApp};
+
+mod response;
+
+mod tlv_table;
+
+mod selection;
+use selection::Selection;
+
+mod alerts;
+pub use alerts::Alerts;
+
 mod logs;
 use logs::{LogsApp, SerdeLogsApp};
 
+mod delivery_reports;
+use delivery_reports::{DeliveryReportsApp, SerdeDeliveryReportsApp};
+
 mod tabs;
 pub use tabs::{SerdeTabs, Tabs};
@@ -0,0 +1,121 @@
+use std::collections::VecDeque;
+
+use eframe::egui::{self, RichText};
+use rusmpp::{Pdu, pdus::AlertNotification};
+
+use crate::{
+    colors::HIGH_BLUE,
+    state::EventsHolder,
+    store::{Progress, Snapshot},
+    values::{Event, Npi, Ton},
+};
+
+/// How many alerts are kept until dismissed
+const MAX_ALERTS: usize = 20;
+
+/// An ESME address reported available by an `AlertNotification`
+struct Alert {
+    esme_addr_ton: Ton,
+    esme_addr_npi: Npi,
+    esme_addr: String,
+    source_addr: String,
+}
+
+impl Alert {
+    fn from_alert_notification(alert_notification: &AlertNotification) -> Self {
+        Self {
+            esme_addr_ton: alert_notification.esme_addr_ton.into(),
+            esme_addr_npi: alert_notification.esme_addr_npi.into(),
+            esme_addr: alert_notification.esme_addr.to_string(),
+            source_addr: alert_notification.source_addr.to_string(),
+        }
+    }
+}
+
+/// Received `AlertNotification`s, shown in a window until dismissed
+pub struct Alerts {
+    events_holder: EventsHolder,
+    snapshot: Snapshot,
+    progress: Progress,
+    alerts: VecDeque<Alert>,
+}
+
+impl Alerts {
+    pub fn new(events_holder: EventsHolder) -> Self {
+        Self {
+            events_holder,
+            snapshot: Snapshot::default(),
+            progress: Progress::default(),
+            alerts: VecDeque::new(),
+        }
+    }
+
+    fn update(&mut self) {
+        self.events_holder.sync(&mut self.snapshot);
+
+        for record in self.progress.advance(&self.snapshot) {
+            let Event::Received(command) = &record.event else {
+                continue;
+            };
+
+            if let Some(Pdu::AlertNotification(alert_notification)) = command.pdu() {
+                self.alerts
+                    .push_front(Alert::from_alert_notification(alert_notification));
+                self.alerts.truncate(MAX_ALERTS);
+            }
+        }
+    }
+
+    pub fn ui(&mut self, ctx: &egui::Context) {
+        self.update();
+
+        if self.alerts.is_empty() {
+            return;
+        }
+
+        let mut dismissed = None;
+        let mut dismiss_all = false;
+
+        egui::Window::new("Alert Notification")
+            .collapsible(true)
+            .resizable(false)
+            .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-12.0, 12.0))
+            .show(ctx, |ui| {
+                for (index, alert) in self.alerts.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.vertical(|ui| {
+                            ui.label(
+                                RichText::new(format!("{} is available", alert.esme_addr))
+                                    .color(HIGH_BLUE)
+                                    .strong(),
+                            );
+                            ui.weak(format!(
+                                "TON {}, NPI {}, reported by {}",
+                                <&'static str>::from(alert.esme_addr_ton),
+                                <&'static str>::from(alert.esme_addr_npi),
+                                alert.source_addr,
+                            ));
+                        });
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            ui.small_button("Dismiss").clicked().then(|| {
+                                dismissed = Some(index);
+                            });
+                        });
+                    });
+
+                    ui.separator();
+                }
+
+                ui.button("Dismiss all").clicked().then(|| {
+                    dismiss_all = true;
+                });
+            });
+
+        if dismiss_all {
+            self.alerts.clear();
+        } else if let Some(index) = dismissed {
+            self.alerts.remove(index);
+        }
+    }
+}
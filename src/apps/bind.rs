@@ -4,6 +4,7 @@ use std::{
         Arc,
         atomic::{AtomicBool, Ordering},
     },
+    time::Duration,
 };
 
 use eframe::egui::{self, Color32, RichText};
@@ -11,16 +12,17 @@ use egui_material_icons::{
     icon_button,
     icons::{ICON_VISIBILITY, ICON_VISIBILITY_OFF},
 };
+use parking_lot::Mutex;
 use rusmpp::{pdus::BindAny, types::COctetString};
 use serde::{Deserialize, Serialize};
 use strum::VariantArray;
 
 use crate::{
-    actions::ActionsChannel,
+    actions::{ActionsChannel, AutoResponse, SharedAutoResponse},
     colors::{FUSION_RED, HIGH_BLUE},
     result::{AppResult, AppUiError},
     types::SmppUrl,
-    values::{BindMode, InterfaceVersion, Npi, Ton},
+    values::{BindMode, InterfaceVersion, Npi, ResponseStatus, Ton},
     widgets::ComboBox,
 };
 
@@ -30,6 +32,9 @@ struct RusmppFields {
     password: AppResult<COctetString<1, 9>>,
     system_type: AppResult<COctetString<1, 13>>,
     enquire_link_interval_secs: AppResult<u64>,
+    deliver_sm_resp_delay_millis: AppResult<u64>,
+    #[cfg(not(target_arch = "wasm32"))]
+    listen_addr: AppResult<std::net::SocketAddr>,
 }
 
 impl RusmppFields {
@@ -39,6 +44,8 @@ impl RusmppFields {
         password: &str,
         system_type: &str,
         enquire_link_interval_secs: &str,
+        deliver_sm_resp_delay_millis: &str,
+        #[cfg(not(target_arch = "wasm32"))] listen_addr: &str,
     ) -> Self {
         Self {
             url: SmppUrl::new(url).map_err(AppUiError::Url),
@@ -50,6 +57,13 @@ impl RusmppFields {
             enquire_link_interval_secs: enquire_link_interval_secs
                 .parse::<u64>()
                 .map_err(|_| AppUiError::invalid_enquire_link_interval()),
+            deliver_sm_resp_delay_millis: deliver_sm_resp_delay_millis
+                .parse::<u64>()
+                .map_err(|_| AppUiError::invalid_deliver_sm_resp_delay()),
+            #[cfg(not(target_arch = "wasm32"))]
+            listen_addr: listen_addr
+                .parse()
+                .map_err(|_| AppUiError::invalid_listen_address()),
         }
     }
 
@@ -78,6 +92,19 @@ impl RusmppFields {
             .map_err(|_| AppUiError::invalid_enquire_link_interval());
     }
 
+    fn set_deliver_sm_resp_delay_millis(&mut self, millis: &str) {
+        self.deliver_sm_resp_delay_millis = millis
+            .parse::<u64>()
+            .map_err(|_| AppUiError::invalid_deliver_sm_resp_delay());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn set_listen_addr(&mut self, listen_addr: &str) {
+        self.listen_addr = listen_addr
+            .parse()
+            .map_err(|_| AppUiError::invalid_listen_address());
+    }
+
     fn all_fields_valid(&self) -> bool {
         matches!(
             (
@@ -86,8 +113,9 @@ impl RusmppFields {
                 &self.password,
                 &self.system_type,
                 &self.enquire_link_interval_secs,
+                &self.deliver_sm_resp_delay_millis,
             ),
-            (Ok(_), Ok(_), Ok(_), Ok(_), Ok(_))
+            (Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_))
         )
     }
 }
@@ -102,6 +130,20 @@ pub struct SerdeBindApp {
     interface_version: InterfaceVersion,
     mode: BindMode,
     enquire_link_interval_secs: String,
+    #[serde(default)]
+    deliver_sm_resp_status: ResponseStatus,
+    #[serde(default = "default_deliver_sm_resp_delay_millis")]
+    deliver_sm_resp_delay_millis: String,
+    #[serde(default = "default_listen_addr")]
+    listen_addr: String,
+}
+
+fn default_deliver_sm_resp_delay_millis() -> String {
+    String::from("0")
+}
+
+fn default_listen_addr() -> String {
+    String::from("127.0.0.1:2775")
 }
 
 pub struct BindApp {
@@ -115,10 +157,21 @@ pub struct BindApp {
     interface_version: InterfaceVersion,
     mode: BindMode,
     enquire_link_interval_secs: String,
+    deliver_sm_resp_status: ResponseStatus,
+    deliver_sm_resp_delay_millis: String,
+    /// Local address an SMSC connects to for an `Outbind`
+    listen_addr: String,
     fields: RusmppFields,
+    /// Read by the background app for every incoming request
+    auto_response: SharedAutoResponse,
     bound: bool,
     password_visible: bool,
     loading: Arc<AtomicBool>,
+    /// Set while waiting for an SMSC to `Outbind`
+    #[cfg(not(target_arch = "wasm32"))]
+    listening: Arc<AtomicBool>,
+    #[cfg(not(target_arch = "wasm32"))]
+    cancel_listen: Arc<AtomicBool>,
 }
 
 impl BindApp {
@@ -134,6 +187,9 @@ impl BindApp {
         interface_version: InterfaceVersion,
         mode: BindMode,
         enquire_link_interval_secs: String,
+        deliver_sm_resp_status: ResponseStatus,
+        deliver_sm_resp_delay_millis: String,
+        listen_addr: String,
     ) -> Self {
         let fields = RusmppFields::new(
             &url,
@@ -141,8 +197,21 @@ impl BindApp {
             &password,
             &system_type,
             &enquire_link_interval_secs,
+            &deliver_sm_resp_delay_millis,
+            #[cfg(not(target_arch = "wasm32"))]
+            &listen_addr,
         );
 
+        let auto_response = Arc::new(Mutex::new(AutoResponse {
+            status: deliver_sm_resp_status,
+            delay: Duration::from_millis(
+                fields
+                    .deliver_sm_resp_delay_millis
+                    .clone()
+                    .unwrap_or_default(),
+            ),
+        }));
+
         Self {
             actions,
             url,
@@ -154,10 +223,18 @@ impl BindApp {
             interface_version,
             mode,
             enquire_link_interval_secs,
+            deliver_sm_resp_status,
+            deliver_sm_resp_delay_millis,
+            listen_addr,
             fields,
+            auto_response,
             bound: false,
             password_visible: false,
             loading: Arc::new(AtomicBool::new(false)),
+            #[cfg(not(target_arch = "wasm32"))]
+            listening: Arc::new(AtomicBool::new(false)),
+            #[cfg(not(target_arch = "wasm32"))]
+            cancel_listen: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -171,6 +248,9 @@ impl BindApp {
         let interface_version = InterfaceVersion::default();
         let mode = BindMode::default();
         let enquire_link_interval_secs = String::from("30");
+        let deliver_sm_resp_status = ResponseStatus::default();
+        let deliver_sm_resp_delay_millis = default_deliver_sm_resp_delay_millis();
+        let listen_addr = default_listen_addr();
 
         Self::new_from_values(
             actions,
@@ -183,6 +263,9 @@ impl BindApp {
             interface_version,
             mode,
             enquire_link_interval_secs,
+            deliver_sm_resp_status,
+            deliver_sm_resp_delay_millis,
+            listen_addr,
         )
     }
 
@@ -198,6 +281,9 @@ impl BindApp {
             serde_bind_app.interface_version,
             serde_bind_app.mode,
             serde_bind_app.enquire_link_interval_secs,
+            serde_bind_app.deliver_sm_resp_status,
+            serde_bind_app.deliver_sm_resp_delay_millis,
+            serde_bind_app.listen_addr,
         )
     }
 
@@ -211,6 +297,9 @@ impl BindApp {
             interface_version: self.interface_version,
             mode: self.mode,
             enquire_link_interval_secs: self.enquire_link_interval_secs.clone(),
+            deliver_sm_resp_status: self.deliver_sm_resp_status,
+            deliver_sm_resp_delay_millis: self.deliver_sm_resp_delay_millis.clone(),
+            listen_addr: self.listen_addr.clone(),
         }
     }
 
@@ -235,6 +324,17 @@ impl BindApp {
         Ok((url, interval, bind_pdu))
     }
 
+    /// Applies the response fields to the running session, an invalid delay keeps the previous one.
+    fn update_auto_response(&self) {
+        let mut auto_response = self.auto_response.lock();
+
+        auto_response.status = self.deliver_sm_resp_status;
+
+        if let Ok(delay) = self.fields.deliver_sm_resp_delay_millis.clone() {
+            auto_response.delay = Duration::from_millis(delay);
+        }
+    }
+
     fn update_url(&mut self) {
         self.fields.set_url(&self.url);
     }
@@ -261,6 +361,21 @@ impl BindApp {
             .set_enquire_link_interval_secs(&self.enquire_link_interval_secs);
     }
 
+    fn update_deliver_sm_resp_delay_millis(&mut self) {
+        self.deliver_sm_resp_delay_millis
+            .retain(|c| c.is_ascii_digit());
+        self.fields
+            .set_deliver_sm_resp_delay_millis(&self.deliver_sm_resp_delay_millis);
+
+        self.update_auto_response();
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn update_listen_addr(&mut self) {
+        self.listen_addr.retain(|c| c.is_ascii());
+        self.fields.set_listen_addr(&self.listen_addr);
+    }
+
     fn toggle_password_visibility(&mut self) {
         self.password_visible = !self.password_visible;
     }
@@ -288,14 +403,48 @@ impl BindApp {
     fn on_bind_button_clicked(&mut self) {
         if self.bound {
             self.actions.unbind(self.loading.clone());
-        } else if let Ok((url, interval, bind)) = self.get_url_and_interval_and_and_pdu() {
-            self.actions
-                .bind(self.mode, url, interval, bind, self.loading.clone());
+        } else if let (Ok((url, interval, bind)), Ok(_)) = (
+            self.get_url_and_interval_and_and_pdu(),
+            &self.fields.deliver_sm_resp_delay_millis,
+        ) {
+            self.actions.bind(
+                self.mode,
+                url,
+                interval,
+                bind,
+                self.auto_response.clone(),
+                self.loading.clone(),
+            );
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn on_listen_button_clicked(&mut self) {
+        if let (Ok(addr), Ok(interval), Ok(bind), Ok(_)) = (
+            self.fields.listen_addr.clone(),
+            self.fields.enquire_link_interval_secs.clone(),
+            self.create_bind_pdu(),
+            &self.fields.deliver_sm_resp_delay_millis,
+        ) {
+            self.cancel_listen.store(false, Ordering::Relaxed);
+            self.actions.listen(
+                addr,
+                interval,
+                bind,
+                self.auto_response.clone(),
+                self.listening.clone(),
+                self.cancel_listen.clone(),
+            );
         }
     }
 
     pub fn ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
-        let loading = self.loading.load(Ordering::Relaxed);
+        #[cfg(not(target_arch = "wasm32"))]
+        let listening = self.listening.load(Ordering::Relaxed);
+        #[cfg(target_arch = "wasm32")]
+        let listening = false;
+
+        let loading = self.loading.load(Ordering::Relaxed) || listening;
 
         ui.vertical_centered(|ui| {
             let display_err = |ui: &mut egui::Ui, err: &AppUiError| {
@@ -377,45 +526,49 @@ impl BindApp {
                 ui.add_space(16.0);
                 ui.separator();
                 ui.add_space(12.0);
+            });
 
+            // The responses to incoming requests can be changed while bound.
+            ui.add_enabled_ui(!loading, |ui| {
                 egui::Grid::new("bind_grid_2")
                     .num_columns(2)
                     .spacing([12.0, 10.0])
                     .show(ui, |ui| {
                         ui.label("Address TON");
-                        ui.add(ComboBox::new(
-                            "bind_addr_ton",
-                            &mut self.addr_ton,
-                            Ton::VARIANTS,
-                        ));
+                        ui.add_enabled(
+                            !self.bound,
+                            ComboBox::new("bind_addr_ton", &mut self.addr_ton, Ton::VARIANTS),
+                        );
                         ui.end_row();
 
                         ui.label("Address NPI");
-                        ui.add(ComboBox::new(
-                            "bind_addr_npi",
-                            &mut self.addr_npi,
-                            Npi::VARIANTS,
-                        ));
+                        ui.add_enabled(
+                            !self.bound,
+                            ComboBox::new("bind_addr_npi", &mut self.addr_npi, Npi::VARIANTS),
+                        );
                         ui.end_row();
 
                         ui.label("Interface Version");
-                        ui.add(ComboBox::new(
-                            "bind_interface_version",
-                            &mut self.interface_version,
-                            InterfaceVersion::VARIANTS,
-                        ));
+                        ui.add_enabled(
+                            !self.bound,
+                            ComboBox::new(
+                                "bind_interface_version",
+                                &mut self.interface_version,
+                                InterfaceVersion::VARIANTS,
+                            ),
+                        );
                         ui.end_row();
 
                         ui.label("Bind Mode");
-                        let bind_mode_combo_response = ui.add(ComboBox::new(
-                            "bind_bind_mode",
-                            &mut self.mode,
-                            BindMode::VARIANTS,
-                        ));
+                        let bind_mode_combo_response = ui.add_enabled(
+                            !self.bound,
+                            ComboBox::new("bind_bind_mode", &mut self.mode, BindMode::VARIANTS),
+                        );
                         ui.end_row();
 
                         ui.label("Enquire Link Interval");
-                        ui.add(
+                        ui.add_enabled(
+                            !self.bound,
                             egui::TextEdit::singleline(&mut self.enquire_link_interval_secs)
                                 .desired_width(bind_mode_combo_response.rect.width() - 8.0),
                         )
@@ -429,6 +582,59 @@ impl BindApp {
                         if let Err(err) = &self.fields.enquire_link_interval_secs {
                             display_err(ui, err);
                         }
+
+                        ui.label("Deliver Sm Response");
+                        ui.add(ComboBox::new(
+                            "bind_deliver_sm_resp_status",
+                            &mut self.deliver_sm_resp_status,
+                            ResponseStatus::VARIANTS,
+                        ))
+                        .on_hover_text(
+                            "Command status of the response sent for every received DeliverSm or DataSm",
+                        )
+                        .changed()
+                        .then(|| {
+                            self.update_auto_response();
+                        });
+                        ui.end_row();
+
+                        ui.label("Deliver Sm Response Delay");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.deliver_sm_resp_delay_millis)
+                                .desired_width(bind_mode_combo_response.rect.width() - 8.0),
+                        )
+                        .on_hover_text("Delay before responding to a DeliverSm or DataSm in milliseconds")
+                        .changed()
+                        .then(|| {
+                            self.update_deliver_sm_resp_delay_millis();
+                        });
+                        ui.end_row();
+
+                        if let Err(err) = &self.fields.deliver_sm_resp_delay_millis {
+                            display_err(ui, err);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            ui.label("Listen Address");
+                            ui.add_enabled(
+                                !self.bound,
+                                egui::TextEdit::singleline(&mut self.listen_addr)
+                                    .desired_width(bind_mode_combo_response.rect.width() - 8.0),
+                            )
+                            .on_hover_text(
+                                "Local address an SMSC connects to for an Outbind, answered with a BindReceiver",
+                            )
+                            .changed()
+                            .then(|| {
+                                self.update_listen_addr();
+                            });
+                            ui.end_row();
+
+                            if let Err(err) = &self.fields.listen_addr {
+                                display_err(ui, err);
+                            }
+                        }
                     });
 
                 ui.add_space(20.0);
@@ -453,6 +659,35 @@ impl BindApp {
                             self.on_bind_button_clicked();
                         });
                     });
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    if listening {
+                        ui.add_sized([140.0, 32.0], egui::Button::new("Cancel"))
+                            .on_hover_text("Stop waiting for an SMSC to Outbind")
+                            .clicked()
+                            .then(|| {
+                                self.cancel_listen.store(true, Ordering::Relaxed);
+                            });
+
+                        ui.spinner();
+                    } else {
+                        ui.add_enabled_ui(
+                            !loading
+                                && !self.bound
+                                && self.fields.all_fields_valid()
+                                && self.fields.listen_addr.is_ok(),
+                            |ui| {
+                                ui.add_sized([140.0, 32.0], egui::Button::new("Listen"))
+                                    .on_hover_text(
+                                        "Wait for an SMSC to Outbind on the listen address",
+                                    )
+                                    .clicked()
+                                    .then(|| {
+                                        self.on_listen_button_clicked();
+                                    });
+                            },
+                        );
+                    }
                 });
             });
         })
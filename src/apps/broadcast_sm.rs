@@ -0,0 +1,1066 @@
+use std::{
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use eframe::egui::{self, Color32, RichText, Separator};
+use parking_lot::Mutex;
+use rusmpp::{
+    Command, Pdu,
+    pdus::{BroadcastSm, CancelBroadcastSm, QueryBroadcastSm},
+    tlvs::{MessagePayload, MessageSubmissionRequestTlvValue, Tlv, TlvTag},
+    types::{AnyOctetString, COctetString, EmptyOrFullCOctetString},
+    values::{
+        BroadcastAreaIdentifier, BroadcastContentType, BroadcastFrequencyInterval,
+        EncodingContentType, PriorityFlag, ReplaceIfPresentFlag, ServiceType,
+    },
+};
+use serde::{Deserialize, Serialize};
+use strum::VariantArray;
+
+use crate::{
+    actions::{ActionsChannel, ResponseSlot},
+    apps::{response::response_ui, submit_sm::encoder::Encoder, tlv_table::TlvTable},
+    colors::{FUSION_RED, HIGH_BLUE, REPTILE_GREEN},
+    hex,
+    inspect::Inspect,
+    result::{AppResult, AppUiError},
+    values::{
+        Ansi41CbsPriorityFlag, BroadcastAreaFormat, DataCoding, GsmCbsPriorityFlag, Npi, TlvEntry,
+        Ton, TypeOfNetwork, UnitOfTime,
+    },
+    widgets::{AddressInput, ComboBox, NodeTree},
+};
+
+/// Largest value a TLV can carry
+const MAX_MESSAGE_PAYLOAD_SIZE: usize = u16::MAX as usize;
+
+/// Set by the message payload field
+const RESERVED_TLV_TAGS: &[TlvTag] = &[TlvTag::MessagePayload];
+
+struct RusmppFields {
+    service_type: AppResult<COctetString<1, 6>>,
+    source_addr: AppResult<COctetString<1, 21>>,
+    message_id: AppResult<COctetString<1, 65>>,
+    priority_flag: AppResult<u8>,
+    schedule_delivery_time: AppResult<EmptyOrFullCOctetString<17>>,
+    validity_period: AppResult<EmptyOrFullCOctetString<17>>,
+    sm_default_msg_id: AppResult<u8>,
+    broadcast_area: AppResult<AnyOctetString>,
+    broadcast_content_type: AppResult<u16>,
+    broadcast_rep_num: AppResult<u16>,
+    broadcast_frequency_interval: AppResult<u16>,
+    message_payload: AppResult<Option<AnyOctetString>>,
+    /// Message ID of the broadcast to query or cancel
+    follow_up_message_id: AppResult<COctetString<1, 65>>,
+}
+
+impl RusmppFields {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        service_type: &str,
+        source_addr: &str,
+        message_id: &str,
+        priority_flag: &str,
+        schedule_delivery_time: &str,
+        validity_period: &str,
+        sm_default_msg_id: &str,
+        broadcast_area: &str,
+        broadcast_content_type: &str,
+        broadcast_rep_num: &str,
+        broadcast_frequency_interval: &str,
+        data_coding: DataCoding,
+        message_payload: &str,
+        follow_up_message_id: &str,
+    ) -> Self {
+        let mut fields = Self {
+            service_type: COctetString::from_str(service_type)
+                .map_err(|_| AppUiError::invalid_service_type()),
+            source_addr: COctetString::from_str(source_addr)
+                .map_err(|_| AppUiError::invalid_source_addr()),
+            message_id: COctetString::from_str(message_id)
+                .map_err(|_| AppUiError::invalid_message_id()),
+            priority_flag: priority_flag
+                .parse::<u8>()
+                .map_err(|_| AppUiError::invalid_priority_flag()),
+            schedule_delivery_time: EmptyOrFullCOctetString::from_str(schedule_delivery_time)
+                .map_err(|_| AppUiError::invalid_schedule_delivery_time()),
+            validity_period: EmptyOrFullCOctetString::from_str(validity_period)
+                .map_err(|_| AppUiError::invalid_validity_period()),
+            sm_default_msg_id: sm_default_msg_id
+                .parse::<u8>()
+                .map_err(|_| AppUiError::invalid_sm_default_msg_id()),
+            broadcast_area: Err(AppUiError::invalid_broadcast_area()),
+            broadcast_content_type: broadcast_content_type
+                .parse::<u16>()
+                .map_err(|_| AppUiError::invalid_broadcast_content_type()),
+            broadcast_rep_num: broadcast_rep_num
+                .parse::<u16>()
+                .map_err(|_| AppUiError::invalid_broadcast_rep_num()),
+            broadcast_frequency_interval: broadcast_frequency_interval
+                .parse::<u16>()
+                .map_err(|_| AppUiError::invalid_broadcast_frequency_interval()),
+            message_payload: Ok(None),
+            follow_up_message_id: Err(AppUiError::invalid_message_id()),
+        };
+
+        fields.set_broadcast_area(broadcast_area);
+        fields.set_message_payload(data_coding, message_payload);
+        fields.set_follow_up_message_id(follow_up_message_id);
+
+        fields
+    }
+
+    fn set_service_type(&mut self, service_type: &str) {
+        self.service_type =
+            COctetString::from_str(service_type).map_err(|_| AppUiError::invalid_service_type());
+    }
+
+    fn set_source_addr(&mut self, source_addr: &str) {
+        self.source_addr =
+            COctetString::from_str(source_addr).map_err(|_| AppUiError::invalid_source_addr());
+    }
+
+    /// Empty for a new broadcast, the message to replace otherwise.
+    fn set_message_id(&mut self, message_id: &str) {
+        self.message_id =
+            COctetString::from_str(message_id).map_err(|_| AppUiError::invalid_message_id());
+    }
+
+    fn set_priority_flag(&mut self, priority_flag: &str) {
+        self.priority_flag = priority_flag
+            .parse::<u8>()
+            .map_err(|_| AppUiError::invalid_priority_flag());
+    }
+
+    fn set_schedule_delivery_time(&mut self, schedule_delivery_time: &str) {
+        self.schedule_delivery_time = EmptyOrFullCOctetString::from_str(schedule_delivery_time)
+            .map_err(|_| AppUiError::invalid_schedule_delivery_time());
+    }
+
+    fn set_validity_period(&mut self, validity_period: &str) {
+        self.validity_period = EmptyOrFullCOctetString::from_str(validity_period)
+            .map_err(|_| AppUiError::invalid_validity_period());
+    }
+
+    fn set_sm_default_msg_id(&mut self, sm_default_msg_id: &str) {
+        self.sm_default_msg_id = sm_default_msg_id
+            .parse::<u8>()
+            .map_err(|_| AppUiError::invalid_sm_default_msg_id());
+    }
+
+    fn set_broadcast_area(&mut self, broadcast_area: &str) {
+        self.broadcast_area = hex::decode(broadcast_area)
+            .filter(|bytes| !bytes.is_empty())
+            .and_then(|bytes| AnyOctetString::new(bytes).ok())
+            .ok_or_else(AppUiError::invalid_broadcast_area);
+    }
+
+    fn set_broadcast_content_type(&mut self, broadcast_content_type: &str) {
+        self.broadcast_content_type = broadcast_content_type
+            .parse::<u16>()
+            .map_err(|_| AppUiError::invalid_broadcast_content_type());
+    }
+
+    fn set_broadcast_rep_num(&mut self, broadcast_rep_num: &str) {
+        self.broadcast_rep_num = broadcast_rep_num
+            .parse::<u16>()
+            .map_err(|_| AppUiError::invalid_broadcast_rep_num());
+    }
+
+    fn set_broadcast_frequency_interval(&mut self, broadcast_frequency_interval: &str) {
+        self.broadcast_frequency_interval = broadcast_frequency_interval
+            .parse::<u16>()
+            .map_err(|_| AppUiError::invalid_broadcast_frequency_interval());
+    }
+
+    /// The content is optional, an empty message omits the `message_payload` TLV.
+    fn set_message_payload(&mut self, data_coding: DataCoding, message_payload: &str) {
+        if message_payload.is_empty() {
+            self.message_payload = Ok(None);
+
+            return;
+        }
+
+        self.message_payload = Encoder::new(data_coding)
+            .encode_single(message_payload, MAX_MESSAGE_PAYLOAD_SIZE)
+            .map_err(AppUiError::MultiPart)
+            .and_then(|bytes| {
+                AnyOctetString::new(bytes).map_err(|_| AppUiError::invalid_message_payload())
+            })
+            .map(Some);
+    }
+
+    fn set_follow_up_message_id(&mut self, message_id: &str) {
+        self.follow_up_message_id = COctetString::from_str(message_id)
+            .ok()
+            .filter(|_| !message_id.is_empty())
+            .ok_or_else(AppUiError::invalid_message_id);
+    }
+
+    fn priority_flag_text(&self) -> String {
+        let Ok(priority_flag) = self.priority_flag else {
+            return String::from("N/A");
+        };
+
+        let gsm_cbs = GsmCbsPriorityFlag::from_u8(priority_flag)
+            .map(<&'static str>::from)
+            .unwrap_or("N/A");
+        let ansi41_cbs = Ansi41CbsPriorityFlag::from_u8(priority_flag)
+            .map(<&'static str>::from)
+            .unwrap_or("N/A");
+
+        format!("GSM CBS: {gsm_cbs}\nANSI-41 CBS: {ansi41_cbs}")
+    }
+
+    fn all_fields_valid(&self) -> bool {
+        matches!(
+            (
+                &self.service_type,
+                &self.source_addr,
+                &self.message_id,
+                &self.priority_flag,
+                &self.schedule_delivery_time,
+                &self.validity_period,
+                &self.sm_default_msg_id,
+            ),
+            (Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_))
+        ) && matches!(
+            (
+                &self.broadcast_area,
+                &self.broadcast_content_type,
+                &self.broadcast_rep_num,
+                &self.broadcast_frequency_interval,
+                &self.message_payload,
+            ),
+            (Ok(_), Ok(_), Ok(_), Ok(_), Ok(_))
+        )
+    }
+
+    fn follow_up_fields_valid(&self) -> bool {
+        matches!(
+            (
+                &self.service_type,
+                &self.source_addr,
+                &self.follow_up_message_id,
+            ),
+            (Ok(_), Ok(_), Ok(_))
+        )
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeBroadcastSmApp {
+    service_type: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+    message_id: String,
+    priority_flag: String,
+    schedule_delivery_time: String,
+    validity_period: String,
+    replace_if_present: bool,
+    sm_default_msg_id: String,
+    broadcast_area_format: BroadcastAreaFormat,
+    broadcast_area: String,
+    type_of_network: TypeOfNetwork,
+    broadcast_content_type: String,
+    broadcast_rep_num: String,
+    broadcast_frequency_unit: UnitOfTime,
+    broadcast_frequency_interval: String,
+    data_coding: DataCoding,
+    message_payload: String,
+    #[serde(default)]
+    tlvs: Vec<TlvEntry>,
+}
+
+pub struct BroadcastSmApp {
+    actions: ActionsChannel,
+    service_type: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+    message_id: String,
+    priority_flag: String,
+    schedule_delivery_time: String,
+    validity_period: String,
+    replace_if_present: bool,
+    sm_default_msg_id: String,
+    broadcast_area_format: BroadcastAreaFormat,
+    broadcast_area: String,
+    type_of_network: TypeOfNetwork,
+    broadcast_content_type: String,
+    broadcast_rep_num: String,
+    broadcast_frequency_unit: UnitOfTime,
+    broadcast_frequency_interval: String,
+    data_coding: DataCoding,
+    message_payload: String,
+    tlvs: TlvTable,
+    follow_up_message_id: String,
+    fields: RusmppFields,
+    /// Response to the last broadcast
+    response: ResponseSlot,
+    /// Whether the `message_id` of the next `BroadcastSmResp` should be followed up
+    follow_response: bool,
+    /// Response to the last query or cancel
+    follow_up_response: ResponseSlot,
+    bound: bool,
+    loading: Arc<AtomicBool>,
+}
+
+impl BroadcastSmApp {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_from_values(
+        actions: ActionsChannel,
+        service_type: String,
+        source_addr_ton: Ton,
+        source_addr_npi: Npi,
+        source_addr: String,
+        message_id: String,
+        priority_flag: String,
+        schedule_delivery_time: String,
+        validity_period: String,
+        replace_if_present: bool,
+        sm_default_msg_id: String,
+        broadcast_area_format: BroadcastAreaFormat,
+        broadcast_area: String,
+        type_of_network: TypeOfNetwork,
+        broadcast_content_type: String,
+        broadcast_rep_num: String,
+        broadcast_frequency_unit: UnitOfTime,
+        broadcast_frequency_interval: String,
+        data_coding: DataCoding,
+        message_payload: String,
+        tlvs: Vec<TlvEntry>,
+    ) -> Self {
+        let follow_up_message_id = String::new();
+
+        let fields = RusmppFields::new(
+            &service_type,
+            &source_addr,
+            &message_id,
+            &priority_flag,
+            &schedule_delivery_time,
+            &validity_period,
+            &sm_default_msg_id,
+            &broadcast_area,
+            &broadcast_content_type,
+            &broadcast_rep_num,
+            &broadcast_frequency_interval,
+            data_coding,
+            &message_payload,
+            &follow_up_message_id,
+        );
+
+        Self {
+            actions,
+            service_type,
+            source_addr_ton,
+            source_addr_npi,
+            source_addr,
+            message_id,
+            priority_flag,
+            schedule_delivery_time,
+            validity_period,
+            replace_if_present,
+            sm_default_msg_id,
+            broadcast_area_format,
+            broadcast_area,
+            type_of_network,
+            broadcast_content_type,
+            broadcast_rep_num,
+            broadcast_frequency_unit,
+            broadcast_frequency_interval,
+            data_coding,
+            message_payload,
+            tlvs: TlvTable::new("broadcast_sm_tlv_table", RESERVED_TLV_TAGS, tlvs),
+            follow_up_message_id,
+            fields,
+            response: Arc::new(Mutex::new(None)),
+            follow_response: false,
+            follow_up_response: Arc::new(Mutex::new(None)),
+            bound: false,
+            loading: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn new_default(actions: ActionsChannel) -> Self {
+        Self::new_from_values(
+            actions,
+            String::new(),
+            Ton::default(),
+            Npi::default(),
+            String::new(),
+            String::new(),
+            String::from("0"),
+            String::new(),
+            String::new(),
+            false,
+            String::from("0"),
+            BroadcastAreaFormat::default(),
+            String::new(),
+            TypeOfNetwork::default(),
+            String::from("0"),
+            String::from("1"),
+            UnitOfTime::default(),
+            String::from("1"),
+            DataCoding::default(),
+            String::from("Hello area from Rusmppc!"),
+            Vec::new(),
+        )
+    }
+
+    pub fn from_serde(actions: ActionsChannel, serde_app: SerdeBroadcastSmApp) -> Self {
+        Self::new_from_values(
+            actions,
+            serde_app.service_type,
+            serde_app.source_addr_ton,
+            serde_app.source_addr_npi,
+            serde_app.source_addr,
+            serde_app.message_id,
+            serde_app.priority_flag,
+            serde_app.schedule_delivery_time,
+            serde_app.validity_period,
+            serde_app.replace_if_present,
+            serde_app.sm_default_msg_id,
+            serde_app.broadcast_area_format,
+            serde_app.broadcast_area,
+            serde_app.type_of_network,
+            serde_app.broadcast_content_type,
+            serde_app.broadcast_rep_num,
+            serde_app.broadcast_frequency_unit,
+            serde_app.broadcast_frequency_interval,
+            serde_app.data_coding,
+            serde_app.message_payload,
+            serde_app.tlvs,
+        )
+    }
+
+    pub fn to_serde(&self) -> SerdeBroadcastSmApp {
+        SerdeBroadcastSmApp {
+            service_type: self.service_type.clone(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.clone(),
+            message_id: self.message_id.clone(),
+            priority_flag: self.priority_flag.clone(),
+            schedule_delivery_time: self.schedule_delivery_time.clone(),
+            validity_period: self.validity_period.clone(),
+            replace_if_present: self.replace_if_present,
+            sm_default_msg_id: self.sm_default_msg_id.clone(),
+            broadcast_area_format: self.broadcast_area_format,
+            broadcast_area: self.broadcast_area.clone(),
+            type_of_network: self.type_of_network,
+            broadcast_content_type: self.broadcast_content_type.clone(),
+            broadcast_rep_num: self.broadcast_rep_num.clone(),
+            broadcast_frequency_unit: self.broadcast_frequency_unit,
+            broadcast_frequency_interval: self.broadcast_frequency_interval.clone(),
+            data_coding: self.data_coding,
+            message_payload: self.message_payload.clone(),
+            tlvs: self.tlvs.entries().to_vec(),
+        }
+    }
+
+    pub fn set_bound(&mut self, bound: bool) {
+        self.bound = bound;
+    }
+
+    fn update_service_type(&mut self) {
+        self.service_type.retain(|c| c.is_ascii());
+        self.fields.set_service_type(&self.service_type);
+    }
+
+    fn update_source_addr(&mut self) {
+        self.source_addr.retain(|c| c.is_ascii());
+        self.fields.set_source_addr(&self.source_addr);
+    }
+
+    fn update_message_id(&mut self) {
+        self.message_id.retain(|c| c.is_ascii());
+        self.fields.set_message_id(&self.message_id);
+    }
+
+    fn update_priority_flag(&mut self) {
+        self.priority_flag.retain(|c| c.is_ascii_digit());
+        self.fields.set_priority_flag(&self.priority_flag);
+    }
+
+    fn update_schedule_delivery_time(&mut self) {
+        self.schedule_delivery_time.retain(|c| c.is_ascii());
+        self.fields
+            .set_schedule_delivery_time(&self.schedule_delivery_time);
+    }
+
+    fn update_validity_period(&mut self) {
+        self.validity_period.retain(|c| c.is_ascii());
+        self.fields.set_validity_period(&self.validity_period);
+    }
+
+    fn update_sm_default_msg_id(&mut self) {
+        self.sm_default_msg_id.retain(|c| c.is_ascii_digit());
+        self.fields.set_sm_default_msg_id(&self.sm_default_msg_id);
+    }
+
+    fn update_broadcast_area(&mut self) {
+        self.broadcast_area
+            .retain(|c| c.is_ascii_hexdigit() || c.is_whitespace());
+        self.fields.set_broadcast_area(&self.broadcast_area);
+    }
+
+    fn update_broadcast_content_type(&mut self) {
+        self.broadcast_content_type.retain(|c| c.is_ascii_digit());
+        self.fields
+            .set_broadcast_content_type(&self.broadcast_content_type);
+    }
+
+    fn update_broadcast_rep_num(&mut self) {
+        self.broadcast_rep_num.retain(|c| c.is_ascii_digit());
+        self.fields.set_broadcast_rep_num(&self.broadcast_rep_num);
+    }
+
+    fn update_broadcast_frequency_interval(&mut self) {
+        self.broadcast_frequency_interval
+            .retain(|c| c.is_ascii_digit());
+        self.fields
+            .set_broadcast_frequency_interval(&self.broadcast_frequency_interval);
+    }
+
+    fn update_message_payload(&mut self) {
+        self.fields
+            .set_message_payload(self.data_coding, &self.message_payload);
+    }
+
+    fn update_follow_up_message_id(&mut self) {
+        self.follow_up_message_id.retain(|c| c.is_ascii());
+        self.fields
+            .set_follow_up_message_id(&self.follow_up_message_id);
+    }
+
+    fn build_broadcast_sm(&self) -> AppResult<BroadcastSm> {
+        let replace_if_present_flag = if self.replace_if_present {
+            ReplaceIfPresentFlag::Replace
+        } else {
+            ReplaceIfPresentFlag::DoNotReplace
+        };
+
+        let mut tlvs = self
+            .fields
+            .message_payload
+            .clone()?
+            .map(|payload| {
+                Tlv::new(MessageSubmissionRequestTlvValue::MessagePayload(
+                    MessagePayload::new(payload),
+                ))
+            })
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        tlvs.extend(self.tlvs.tlvs()?);
+
+        let broadcast_sm = BroadcastSm::builder()
+            .service_type(ServiceType::new(self.fields.service_type.clone()?))
+            .source_addr_ton(self.source_addr_ton.into())
+            .source_addr_npi(self.source_addr_npi.into())
+            .source_addr(self.fields.source_addr.clone()?)
+            .message_id(self.fields.message_id.clone()?)
+            .priority_flag(PriorityFlag::from(self.fields.priority_flag.clone()?))
+            .schedule_delivery_time(self.fields.schedule_delivery_time.clone()?)
+            .validity_period(self.fields.validity_period.clone()?)
+            .replace_if_present_flag(replace_if_present_flag)
+            .data_coding(self.data_coding.into())
+            .sm_default_msg_id(self.fields.sm_default_msg_id.clone()?)
+            .broadcast_area_identifier(BroadcastAreaIdentifier::new(
+                self.broadcast_area_format.into(),
+                self.fields.broadcast_area.clone()?,
+            ))
+            .broadcast_content_type(BroadcastContentType::new(
+                self.type_of_network.into(),
+                EncodingContentType::from(self.fields.broadcast_content_type.clone()?),
+            ))
+            .broadcast_rep_num(self.fields.broadcast_rep_num.clone()?)
+            .broadcast_frequency_interval(BroadcastFrequencyInterval::new(
+                self.broadcast_frequency_unit.into(),
+                self.fields.broadcast_frequency_interval.clone()?,
+            ))
+            .tlvs(tlvs)
+            .build();
+
+        Ok(broadcast_sm)
+    }
+
+    fn build_query_broadcast_sm(&self) -> AppResult<QueryBroadcastSm> {
+        let query_broadcast_sm = QueryBroadcastSm::builder()
+            .message_id(self.fields.follow_up_message_id.clone()?)
+            .source_addr_ton(self.source_addr_ton.into())
+            .source_addr_npi(self.source_addr_npi.into())
+            .source_addr(self.fields.source_addr.clone()?)
+            .build();
+
+        Ok(query_broadcast_sm)
+    }
+
+    fn build_cancel_broadcast_sm(&self) -> AppResult<CancelBroadcastSm> {
+        let cancel_broadcast_sm = CancelBroadcastSm::builder()
+            .service_type(ServiceType::new(self.fields.service_type.clone()?))
+            .message_id(self.fields.follow_up_message_id.clone()?)
+            .source_addr_ton(self.source_addr_ton.into())
+            .source_addr_npi(self.source_addr_npi.into())
+            .source_addr(self.fields.source_addr.clone()?)
+            .build();
+
+        Ok(cancel_broadcast_sm)
+    }
+
+    fn on_broadcast_button_clicked(&mut self) {
+        if let Ok(broadcast_sm) = self.build_broadcast_sm() {
+            *self.response.lock() = None;
+            self.follow_response = true;
+
+            self.actions
+                .broadcast_sm(broadcast_sm, self.response.clone(), self.loading.clone());
+        }
+    }
+
+    fn on_query_button_clicked(&mut self) {
+        if let Ok(query_broadcast_sm) = self.build_query_broadcast_sm() {
+            *self.follow_up_response.lock() = None;
+
+            self.actions.query_broadcast_sm(
+                query_broadcast_sm,
+                self.follow_up_response.clone(),
+                self.loading.clone(),
+            );
+        }
+    }
+
+    fn on_cancel_button_clicked(&mut self) {
+        if let Ok(cancel_broadcast_sm) = self.build_cancel_broadcast_sm() {
+            *self.follow_up_response.lock() = None;
+
+            self.actions.cancel_broadcast_sm(
+                cancel_broadcast_sm,
+                self.follow_up_response.clone(),
+                self.loading.clone(),
+            );
+        }
+    }
+
+    /// Follows up the broadcast acknowledged by the last `BroadcastSmResp`.
+    fn follow_response(&mut self) {
+        if !self.follow_response {
+            return;
+        }
+
+        let message_id = match self.response.lock().as_ref() {
+            Some(Ok(command)) => match command.pdu() {
+                Some(Pdu::BroadcastSmResp(broadcast_sm_resp)) => {
+                    Some(broadcast_sm_resp.message_id.to_string())
+                }
+                _ => None,
+            },
+            Some(Err(_)) => None,
+            None => return,
+        };
+
+        self.follow_response = false;
+
+        if let Some(message_id) = message_id {
+            self.follow_up_message_id = message_id;
+            self.update_follow_up_message_id();
+        }
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        let loading = self.loading.load(Ordering::Relaxed);
+
+        self.follow_response();
+
+        ui.vertical_centered(|ui| {
+            let display_err = |ui: &mut egui::Ui, err: &AppUiError| {
+                ui.allocate_space(egui::vec2(0.0, 0.0));
+                ui.colored_label(FUSION_RED, err.display_message());
+                ui.end_row();
+            };
+
+            egui::Grid::new("broadcast_sm_grid")
+                .num_columns(2)
+                .spacing([12.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.label("Service Type");
+                    ui.add(egui::TextEdit::singleline(&mut self.service_type).char_limit(5))
+                        .on_hover_text("Max 5 ASCII characters")
+                        .changed()
+                        .then(|| self.update_service_type());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.service_type {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Message ID");
+                    ui.add(egui::TextEdit::singleline(&mut self.message_id).char_limit(64))
+                        .on_hover_text("Empty for a new broadcast, or the broadcast to replace")
+                        .changed()
+                        .then(|| self.update_message_id());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.message_id {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Priority Flag").on_hover_ui(|ui| {
+                        ui.label(self.fields.priority_flag_text());
+                    });
+                    ui.add(egui::TextEdit::singleline(&mut self.priority_flag).char_limit(3))
+                        .on_hover_text("Unsigned 8-bit integer")
+                        .changed()
+                        .then(|| self.update_priority_flag());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.priority_flag {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Schedule Delivery Time");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.schedule_delivery_time).char_limit(16),
+                    )
+                    .on_hover_text("Empty for immediate, or YYMMDDhhmmsstnnp")
+                    .changed()
+                    .then(|| self.update_schedule_delivery_time());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.schedule_delivery_time {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Validity Period");
+                    ui.add(egui::TextEdit::singleline(&mut self.validity_period).char_limit(16))
+                        .on_hover_text("Empty for the MC default, or YYMMDDhhmmsstnnp")
+                        .changed()
+                        .then(|| self.update_validity_period());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.validity_period {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Replace If Present");
+                    ui.checkbox(&mut self.replace_if_present, "");
+                    ui.end_row();
+
+                    ui.label("SM Default Msg ID");
+                    ui.add(egui::TextEdit::singleline(&mut self.sm_default_msg_id).char_limit(3))
+                        .on_hover_text("Unsigned 8-bit integer")
+                        .changed()
+                        .then(|| self.update_sm_default_msg_id());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.sm_default_msg_id {
+                        display_err(ui, err);
+                    }
+                });
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.add_space(12.0);
+
+            egui::Grid::new("broadcast_sm_addr_grid")
+                .num_columns(6)
+                .spacing([16.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.add(AddressInput::new(
+                        "broadcast_sm_source_addr",
+                        "Source Address",
+                        &mut self.source_addr_ton,
+                        &mut self.source_addr_npi,
+                        &mut self.source_addr,
+                    ))
+                    .changed()
+                    .then(|| self.update_source_addr());
+
+                    ui.end_row();
+                });
+
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.heading("Broadcast Parameters");
+                ui.add(Separator::default().horizontal().spacing(8.0));
+            });
+
+            ui.add_space(12.0);
+
+            egui::Grid::new("broadcast_sm_tlvs_grid")
+                .num_columns(2)
+                .spacing([12.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.label("Area Format");
+                    ui.add(ComboBox::new(
+                        "broadcast_sm_area_format",
+                        &mut self.broadcast_area_format,
+                        BroadcastAreaFormat::VARIANTS,
+                    ));
+                    ui.end_row();
+
+                    ui.label("Area");
+                    ui.add(egui::TextEdit::singleline(&mut self.broadcast_area))
+                        .on_hover_text("Hex octets of the area details")
+                        .changed()
+                        .then(|| self.update_broadcast_area());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.broadcast_area {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Type Of Network");
+                    ui.add(ComboBox::new(
+                        "broadcast_sm_type_of_network",
+                        &mut self.type_of_network,
+                        TypeOfNetwork::VARIANTS,
+                    ));
+                    ui.end_row();
+
+                    ui.label("Content Type");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.broadcast_content_type).char_limit(5),
+                    )
+                    .on_hover_text("Encoding content type, unsigned 16-bit integer")
+                    .changed()
+                    .then(|| self.update_broadcast_content_type());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.broadcast_content_type {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Repetitions");
+                    ui.add(egui::TextEdit::singleline(&mut self.broadcast_rep_num).char_limit(5))
+                        .on_hover_text(
+                            "How many times the message is broadcast, 0 for the MC default",
+                        )
+                        .changed()
+                        .then(|| self.update_broadcast_rep_num());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.broadcast_rep_num {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Frequency Interval");
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.broadcast_frequency_interval)
+                                .char_limit(5)
+                                .desired_width(60.0),
+                        )
+                        .on_hover_text("Time between repetitions, unsigned 16-bit integer")
+                        .changed()
+                        .then(|| self.update_broadcast_frequency_interval());
+
+                        ui.add(ComboBox::new(
+                            "broadcast_sm_frequency_unit",
+                            &mut self.broadcast_frequency_unit,
+                            UnitOfTime::VARIANTS,
+                        ));
+                    });
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.broadcast_frequency_interval {
+                        display_err(ui, err);
+                    }
+                });
+
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.heading("Optional Parameters");
+                ui.add(Separator::default().horizontal().spacing(8.0));
+            });
+
+            ui.add_space(12.0);
+
+            self.tlvs.ui(ui);
+
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.heading("Message Payload");
+                ui.add(Separator::default().horizontal().spacing(8.0));
+            });
+
+            ui.add_space(12.0);
+
+            ui.vertical(|ui| {
+                egui::Grid::new("broadcast_sm_data_coding_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 10.0])
+                    .striped(false)
+                    .show(ui, |ui| {
+                        ui.label("Data Coding");
+                        ui.add(ComboBox::new(
+                            "broadcast_sm_data_coding",
+                            &mut self.data_coding,
+                            DataCoding::FIXED,
+                        ))
+                        .changed()
+                        .then(|| self.update_message_payload());
+
+                        ui.end_row();
+                    });
+
+                ui.add_space(10.0);
+
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.message_payload)
+                        .hint_text("Empty to omit the message payload")
+                        .desired_width(ui.available_width()),
+                )
+                .changed()
+                .then(|| self.update_message_payload());
+
+                if let Err(err) = &self.fields.message_payload {
+                    ui.add_space(10.0);
+                    ui.colored_label(FUSION_RED, err.display_message());
+                }
+            });
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.add_enabled_ui(
+                        self.bound
+                            && !loading
+                            && self.fields.all_fields_valid()
+                            && self.tlvs.all_rows_valid(),
+                        |ui| {
+                            ui.add_sized(
+                                [140.0, 32.0],
+                                egui::Button::new(
+                                    RichText::new("Broadcast").color(Color32::WHITE).strong(),
+                                )
+                                .fill(HIGH_BLUE),
+                            )
+                            .clicked()
+                            .then(|| {
+                                self.on_broadcast_button_clicked();
+                            });
+                        },
+                    );
+
+                    if loading {
+                        ui.spinner();
+                    }
+                });
+            });
+
+            if let Some(response) = self.response.lock().as_ref() {
+                ui.add_space(12.0);
+
+                if let Some(Pdu::BroadcastSmResp(broadcast_sm_resp)) =
+                    response_ui(ui, response).and_then(Command::pdu)
+                {
+                    ui.colored_label(
+                        REPTILE_GREEN,
+                        format!("Accepted with Message ID {}", broadcast_sm_resp.message_id),
+                    );
+                }
+            }
+
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.heading("Follow Up");
+                ui.add(Separator::default().horizontal().spacing(8.0));
+            });
+
+            ui.add_space(12.0);
+
+            egui::Grid::new("broadcast_sm_follow_up_grid")
+                .num_columns(2)
+                .spacing([12.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.label("Message ID");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.follow_up_message_id).char_limit(64),
+                    )
+                    .on_hover_text("Filled with the Message ID of the last accepted broadcast")
+                    .changed()
+                    .then(|| self.update_follow_up_message_id());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.follow_up_message_id {
+                        display_err(ui, err);
+                    }
+                });
+
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.add_enabled_ui(
+                        self.bound && !loading && self.fields.follow_up_fields_valid(),
+                        |ui| {
+                            ui.add_sized(
+                                [140.0, 32.0],
+                                egui::Button::new(
+                                    RichText::new("Cancel Broadcast")
+                                        .color(Color32::WHITE)
+                                        .strong(),
+                                )
+                                .fill(FUSION_RED),
+                            )
+                            .clicked()
+                            .then(|| {
+                                self.on_cancel_button_clicked();
+                            });
+
+                            ui.add_sized(
+                                [140.0, 32.0],
+                                egui::Button::new(
+                                    RichText::new("Query Broadcast")
+                                        .color(Color32::WHITE)
+                                        .strong(),
+                                )
+                                .fill(HIGH_BLUE),
+                            )
+                            .clicked()
+                            .then(|| {
+                                self.on_query_button_clicked();
+                            });
+                        },
+                    );
+                });
+            });
+
+            if let Some(response) = self.follow_up_response.lock().as_ref() {
+                ui.add_space(12.0);
+
+                match response_ui(ui, response).and_then(Command::pdu) {
+                    Some(pdu @ Pdu::QueryBroadcastSmResp(_)) => {
+                        ui.add(NodeTree::new(
+                            "broadcast_sm_query_resp_tree",
+                            &pdu.inspect(),
+                        ));
+                    }
+                    Some(Pdu::CancelBroadcastSmResp) => {
+                        ui.colored_label(REPTILE_GREEN, "Cancelled");
+                    }
+                    _ => {}
+                }
+            }
+        })
+        .response
+    }
+}
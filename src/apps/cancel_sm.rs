@@ -0,0 +1,386 @@
+use std::{
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use eframe::egui::{self, Color32, RichText};
+use parking_lot::Mutex;
+use rusmpp::{pdus::CancelSm, types::COctetString, values::ServiceType};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    actions::{ActionsChannel, ResponseSlot},
+    apps::{
+        response::response_ui,
+        selection::{MessageTarget, Selection},
+    },
+    colors::{FUSION_RED, REPTILE_GREEN},
+    result::{AppResult, AppUiError},
+    values::{Npi, Ton},
+    widgets::AddressInput,
+};
+
+struct RusmppFields {
+    service_type: AppResult<COctetString<1, 6>>,
+    message_id: AppResult<COctetString<1, 65>>,
+    source_addr: AppResult<COctetString<1, 21>>,
+    destination_addr: AppResult<COctetString<1, 21>>,
+}
+
+impl RusmppFields {
+    fn new(
+        service_type: &str,
+        message_id: &str,
+        source_addr: &str,
+        destination_addr: &str,
+    ) -> Self {
+        Self {
+            service_type: COctetString::from_str(service_type)
+                .map_err(|_| AppUiError::invalid_service_type()),
+            message_id: COctetString::from_str(message_id)
+                .map_err(|_| AppUiError::invalid_message_id()),
+            source_addr: COctetString::from_str(source_addr)
+                .map_err(|_| AppUiError::invalid_source_addr()),
+            destination_addr: COctetString::from_str(destination_addr)
+                .map_err(|_| AppUiError::invalid_destination_addr()),
+        }
+    }
+
+    fn set_service_type(&mut self, service_type: &str) {
+        self.service_type =
+            COctetString::from_str(service_type).map_err(|_| AppUiError::invalid_service_type());
+    }
+
+    fn set_message_id(&mut self, message_id: &str) {
+        self.message_id =
+            COctetString::from_str(message_id).map_err(|_| AppUiError::invalid_message_id());
+    }
+
+    fn set_source_addr(&mut self, source_addr: &str) {
+        self.source_addr =
+            COctetString::from_str(source_addr).map_err(|_| AppUiError::invalid_source_addr());
+    }
+
+    fn set_destination_addr(&mut self, destination_addr: &str) {
+        self.destination_addr = COctetString::from_str(destination_addr)
+            .map_err(|_| AppUiError::invalid_destination_addr());
+    }
+
+    fn all_fields_valid(&self) -> bool {
+        matches!(
+            (
+                &self.service_type,
+                &self.message_id,
+                &self.source_addr,
+                &self.destination_addr,
+            ),
+            (Ok(_), Ok(_), Ok(_), Ok(_))
+        )
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeCancelSmApp {
+    service_type: String,
+    message_id: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+    dest_addr_ton: Ton,
+    dest_addr_npi: Npi,
+    destination_addr: String,
+}
+
+pub struct CancelSmApp {
+    actions: ActionsChannel,
+    selection: Selection,
+    service_type: String,
+    message_id: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+    dest_addr_ton: Ton,
+    dest_addr_npi: Npi,
+    destination_addr: String,
+    fields: RusmppFields,
+    /// Response to the last cancel
+    response: ResponseSlot,
+    bound: bool,
+    loading: Arc<AtomicBool>,
+}
+
+impl CancelSmApp {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_from_values(
+        actions: ActionsChannel,
+        selection: Selection,
+        service_type: String,
+        message_id: String,
+        source_addr_ton: Ton,
+        source_addr_npi: Npi,
+        source_addr: String,
+        dest_addr_ton: Ton,
+        dest_addr_npi: Npi,
+        destination_addr: String,
+    ) -> Self {
+        let fields = RusmppFields::new(&service_type, &message_id, &source_addr, &destination_addr);
+
+        Self {
+            actions,
+            selection,
+            service_type,
+            message_id,
+            source_addr_ton,
+            source_addr_npi,
+            source_addr,
+            dest_addr_ton,
+            dest_addr_npi,
+            destination_addr,
+            fields,
+            response: Arc::new(Mutex::new(None)),
+            bound: false,
+            loading: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn new_default(actions: ActionsChannel, selection: Selection) -> Self {
+        Self::new_from_values(
+            actions,
+            selection,
+            String::new(),
+            String::new(),
+            Ton::default(),
+            Npi::default(),
+            String::new(),
+            Ton::default(),
+            Npi::default(),
+            String::new(),
+        )
+    }
+
+    pub fn from_serde(
+        actions: ActionsChannel,
+        selection: Selection,
+        serde_app: SerdeCancelSmApp,
+    ) -> Self {
+        Self::new_from_values(
+            actions,
+            selection,
+            serde_app.service_type,
+            serde_app.message_id,
+            serde_app.source_addr_ton,
+            serde_app.source_addr_npi,
+            serde_app.source_addr,
+            serde_app.dest_addr_ton,
+            serde_app.dest_addr_npi,
+            serde_app.destination_addr,
+        )
+    }
+
+    pub fn to_serde(&self) -> SerdeCancelSmApp {
+        SerdeCancelSmApp {
+            service_type: self.service_type.clone(),
+            message_id: self.message_id.clone(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.clone(),
+            dest_addr_ton: self.dest_addr_ton,
+            dest_addr_npi: self.dest_addr_npi,
+            destination_addr: self.destination_addr.clone(),
+        }
+    }
+
+    pub fn set_bound(&mut self, bound: bool) {
+        self.bound = bound;
+    }
+
+    fn update_service_type(&mut self) {
+        self.service_type.retain(|c| c.is_ascii());
+        self.fields.set_service_type(&self.service_type);
+    }
+
+    fn update_message_id(&mut self) {
+        self.message_id.retain(|c| c.is_ascii());
+        self.fields.set_message_id(&self.message_id);
+    }
+
+    fn update_source_addr(&mut self) {
+        self.source_addr.retain(|c| c.is_ascii());
+        self.fields.set_source_addr(&self.source_addr);
+    }
+
+    fn update_destination_addr(&mut self) {
+        self.destination_addr.retain(|c| c.is_ascii());
+        self.fields.set_destination_addr(&self.destination_addr);
+    }
+
+    fn set_target(&mut self, target: MessageTarget) {
+        self.message_id = target.message_id;
+        self.source_addr_ton = target.source_addr_ton;
+        self.source_addr_npi = target.source_addr_npi;
+        self.source_addr = target.source_addr;
+        self.dest_addr_ton = target.dest_addr_ton;
+        self.dest_addr_npi = target.dest_addr_npi;
+        self.destination_addr = target.destination_addr;
+
+        self.update_message_id();
+        self.update_source_addr();
+        self.update_destination_addr();
+    }
+
+    fn build_cancel_sm(&self) -> AppResult<CancelSm> {
+        let cancel_sm = CancelSm::builder()
+            .service_type(ServiceType::new(self.fields.service_type.clone()?))
+            .message_id(self.fields.message_id.clone()?)
+            .source_addr_ton(self.source_addr_ton.into())
+            .source_addr_npi(self.source_addr_npi.into())
+            .source_addr(self.fields.source_addr.clone()?)
+            .dest_addr_ton(self.dest_addr_ton.into())
+            .dest_addr_npi(self.dest_addr_npi.into())
+            .destination_addr(self.fields.destination_addr.clone()?)
+            .build();
+
+        Ok(cancel_sm)
+    }
+
+    fn on_cancel_button_clicked(&mut self) {
+        if let Ok(cancel_sm) = self.build_cancel_sm() {
+            *self.response.lock() = None;
+
+            self.actions
+                .cancel_sm(cancel_sm, self.response.clone(), self.loading.clone());
+        }
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        let loading = self.loading.load(Ordering::Relaxed);
+
+        ui.vertical_centered(|ui| {
+            let display_err = |ui: &mut egui::Ui, err: &AppUiError| {
+                ui.allocate_space(egui::vec2(0.0, 0.0));
+                ui.colored_label(FUSION_RED, err.display_message());
+                ui.end_row();
+            };
+
+            let mut target = None;
+
+            egui::Grid::new("cancel_sm_grid")
+                .num_columns(2)
+                .spacing([12.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.label("Service Type");
+                    ui.add(egui::TextEdit::singleline(&mut self.service_type).char_limit(5))
+                        .on_hover_text("Max 5 ASCII characters")
+                        .changed()
+                        .then(|| self.update_service_type());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.service_type {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Message ID");
+                    ui.horizontal(|ui| {
+                        ui.add(egui::TextEdit::singleline(&mut self.message_id).char_limit(64))
+                            .on_hover_text(
+                                "Max 64 ASCII characters, empty to cancel all the messages \
+                                 from the source to the destination address",
+                            )
+                            .changed()
+                            .then(|| self.update_message_id());
+
+                        ui.add_enabled(self.selection.is_some(), egui::Button::new("From Logs"))
+                            .on_hover_text("Use the message selected in the logs")
+                            .on_disabled_hover_text("Select a SubmitSmResp in the logs first")
+                            .clicked()
+                            .then(|| target = self.selection.get());
+                    });
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.message_id {
+                        display_err(ui, err);
+                    }
+                });
+
+            if let Some(target) = target {
+                self.set_target(target);
+            }
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.add_space(12.0);
+
+            egui::Grid::new("cancel_sm_addr_grid")
+                .num_columns(6)
+                .spacing([16.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.add(AddressInput::new(
+                        "cancel_sm_source_addr",
+                        "Source Address",
+                        &mut self.source_addr_ton,
+                        &mut self.source_addr_npi,
+                        &mut self.source_addr,
+                    ))
+                    .changed()
+                    .then(|| self.update_source_addr());
+
+                    ui.end_row();
+
+                    ui.add(AddressInput::new(
+                        "cancel_sm_dest_addr",
+                        "Destination Address",
+                        &mut self.dest_addr_ton,
+                        &mut self.dest_addr_npi,
+                        &mut self.destination_addr,
+                    ))
+                    .changed()
+                    .then(|| self.update_destination_addr());
+
+                    ui.end_row();
+                });
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.add_enabled_ui(
+                        self.bound && !loading && self.fields.all_fields_valid(),
+                        |ui| {
+                            ui.add_sized(
+                                [140.0, 32.0],
+                                egui::Button::new(
+                                    RichText::new("Cancel SMS").color(Color32::WHITE).strong(),
+                                )
+                                .fill(FUSION_RED),
+                            )
+                            .clicked()
+                            .then(|| {
+                                self.on_cancel_button_clicked();
+                            });
+                        },
+                    );
+
+                    if loading {
+                        ui.spinner();
+                    }
+                });
+            });
+
+            if let Some(response) = self.response.lock().as_ref() {
+                ui.add_space(12.0);
+
+                if response_ui(ui, response).is_some() {
+                    ui.colored_label(REPTILE_GREEN, "Cancelled");
+                }
+            }
+        })
+        .response
+    }
+}
@@ -0,0 +1,585 @@
+use std::{
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use eframe::egui::{self, Color32, RichText, Separator};
+use parking_lot::Mutex;
+use rusmpp::{
+    Command, Pdu,
+    pdus::DataSm,
+    tlvs::{MessagePayload, MessageSubmissionRequestTlvValue, Tlv, TlvTag},
+    types::{AnyOctetString, COctetString},
+    values::{RegisteredDelivery, ServiceType},
+};
+use serde::{Deserialize, Serialize};
+use strum::VariantArray;
+
+use crate::{
+    actions::{ActionsChannel, ResponseSlot},
+    apps::{response::response_ui, submit_sm::encoder::Encoder, tlv_table::TlvTable},
+    colors::{FUSION_RED, HIGH_BLUE, REPTILE_GREEN},
+    result::{AppResult, AppUiError},
+    values::{DataCoding, Npi, PayloadType, TlvEntry, Ton},
+    widgets::{AddressInput, ComboBox},
+};
+
+/// Largest value a TLV can carry
+const MAX_MESSAGE_PAYLOAD_SIZE: usize = u16::MAX as usize;
+
+/// Set by the message payload, payload type and QoS time to live fields
+const RESERVED_TLV_TAGS: &[TlvTag] = &[
+    TlvTag::MessagePayload,
+    TlvTag::PayloadType,
+    TlvTag::QosTimeToLive,
+];
+
+/// Parses an optional parameter, empty meaning the TLV is not sent.
+fn parse_optional<T: FromStr>(value: &str, error: fn() -> AppUiError) -> AppResult<Option<T>> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    value.parse().map(Some).map_err(|_| error())
+}
+
+struct RusmppFields {
+    service_type: AppResult<COctetString<1, 6>>,
+    source_addr: AppResult<COctetString<1, 65>>,
+    destination_addr: AppResult<COctetString<1, 65>>,
+    registered_delivery: AppResult<u8>,
+    qos_time_to_live: AppResult<Option<u32>>,
+    message_payload: AppResult<AnyOctetString>,
+}
+
+impl RusmppFields {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        service_type: &str,
+        source_addr: &str,
+        destination_addr: &str,
+        registered_delivery: &str,
+        qos_time_to_live: &str,
+        data_coding: DataCoding,
+        message_payload: &str,
+    ) -> Self {
+        let mut fields = Self {
+            service_type: COctetString::from_str(service_type)
+                .map_err(|_| AppUiError::invalid_service_type()),
+            source_addr: COctetString::from_str(source_addr)
+                .map_err(|_| AppUiError::invalid_source_addr()),
+            destination_addr: COctetString::from_str(destination_addr)
+                .map_err(|_| AppUiError::invalid_destination_addr()),
+            registered_delivery: registered_delivery
+                .parse::<u8>()
+                .map_err(|_| AppUiError::invalid_registered_delivery()),
+            qos_time_to_live: Ok(None),
+            message_payload: Err(AppUiError::invalid_message_payload()),
+        };
+
+        fields.set_qos_time_to_live(qos_time_to_live);
+        fields.set_message_payload(data_coding, message_payload);
+
+        fields
+    }
+
+    fn set_service_type(&mut self, service_type: &str) {
+        self.service_type =
+            COctetString::from_str(service_type).map_err(|_| AppUiError::invalid_service_type());
+    }
+
+    fn set_source_addr(&mut self, source_addr: &str) {
+        self.source_addr =
+            COctetString::from_str(source_addr).map_err(|_| AppUiError::invalid_source_addr());
+    }
+
+    fn set_destination_addr(&mut self, destination_addr: &str) {
+        self.destination_addr = COctetString::from_str(destination_addr)
+            .map_err(|_| AppUiError::invalid_destination_addr());
+    }
+
+    fn set_registered_delivery(&mut self, registered_delivery: &str) {
+        self.registered_delivery = registered_delivery
+            .parse::<u8>()
+            .map_err(|_| AppUiError::invalid_registered_delivery());
+    }
+
+    fn set_qos_time_to_live(&mut self, qos_time_to_live: &str) {
+        self.qos_time_to_live =
+            parse_optional(qos_time_to_live, AppUiError::invalid_qos_time_to_live);
+    }
+
+    /// The whole message goes in a single `message_payload` TLV, there is no concatenation.
+    fn set_message_payload(&mut self, data_coding: DataCoding, message_payload: &str) {
+        self.message_payload = Encoder::new(data_coding)
+            .encode_single(message_payload, MAX_MESSAGE_PAYLOAD_SIZE)
+            .map_err(AppUiError::MultiPart)
+            .and_then(|bytes| {
+                AnyOctetString::new(bytes).map_err(|_| AppUiError::invalid_message_payload())
+            });
+    }
+
+    fn all_fields_valid(&self) -> bool {
+        matches!(
+            (
+                &self.service_type,
+                &self.source_addr,
+                &self.destination_addr,
+                &self.registered_delivery,
+                &self.qos_time_to_live,
+                &self.message_payload,
+            ),
+            (Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_))
+        )
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeDataSmApp {
+    service_type: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+    dest_addr_ton: Ton,
+    dest_addr_npi: Npi,
+    destination_addr: String,
+    registered_delivery: String,
+    payload_type: PayloadType,
+    qos_time_to_live: String,
+    data_coding: DataCoding,
+    message_payload: String,
+    #[serde(default)]
+    tlvs: Vec<TlvEntry>,
+}
+
+pub struct DataSmApp {
+    actions: ActionsChannel,
+    service_type: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+    dest_addr_ton: Ton,
+    dest_addr_npi: Npi,
+    destination_addr: String,
+    registered_delivery: String,
+    payload_type: PayloadType,
+    qos_time_to_live: String,
+    data_coding: DataCoding,
+    message_payload: String,
+    tlvs: TlvTable,
+    fields: RusmppFields,
+    /// Response to the last data sm
+    response: ResponseSlot,
+    bound: bool,
+    loading: Arc<AtomicBool>,
+}
+
+impl DataSmApp {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_from_values(
+        actions: ActionsChannel,
+        service_type: String,
+        source_addr_ton: Ton,
+        source_addr_npi: Npi,
+        source_addr: String,
+        dest_addr_ton: Ton,
+        dest_addr_npi: Npi,
+        destination_addr: String,
+        registered_delivery: String,
+        payload_type: PayloadType,
+        qos_time_to_live: String,
+        data_coding: DataCoding,
+        message_payload: String,
+        tlvs: Vec<TlvEntry>,
+    ) -> Self {
+        let fields = RusmppFields::new(
+            &service_type,
+            &source_addr,
+            &destination_addr,
+            &registered_delivery,
+            &qos_time_to_live,
+            data_coding,
+            &message_payload,
+        );
+
+        Self {
+            actions,
+            service_type,
+            source_addr_ton,
+            source_addr_npi,
+            source_addr,
+            dest_addr_ton,
+            dest_addr_npi,
+            destination_addr,
+            registered_delivery,
+            payload_type,
+            qos_time_to_live,
+            data_coding,
+            message_payload,
+            tlvs: TlvTable::new("data_sm_tlv_table", RESERVED_TLV_TAGS, tlvs),
+            fields,
+            response: Arc::new(Mutex::new(None)),
+            bound: false,
+            loading: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn new_default(actions: ActionsChannel) -> Self {
+        Self::new_from_values(
+            actions,
+            String::new(),
+            Ton::default(),
+            Npi::default(),
+            String::new(),
+            Ton::default(),
+            Npi::default(),
+            String::new(),
+            String::from("0"),
+            PayloadType::default(),
+            String::new(),
+            DataCoding::default(),
+            String::from("Hello from Rusmppc!"),
+            Vec::new(),
+        )
+    }
+
+    pub fn from_serde(actions: ActionsChannel, serde_app: SerdeDataSmApp) -> Self {
+        Self::new_from_values(
+            actions,
+            serde_app.service_type,
+            serde_app.source_addr_ton,
+            serde_app.source_addr_npi,
+            serde_app.source_addr,
+            serde_app.dest_addr_ton,
+            serde_app.dest_addr_npi,
+            serde_app.destination_addr,
+            serde_app.registered_delivery,
+            serde_app.payload_type,
+            serde_app.qos_time_to_live,
+            serde_app.data_coding,
+            serde_app.message_payload,
+            serde_app.tlvs,
+        )
+    }
+
+    pub fn to_serde(&self) -> SerdeDataSmApp {
+        SerdeDataSmApp {
+            service_type: self.service_type.clone(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.clone(),
+            dest_addr_ton: self.dest_addr_ton,
+            dest_addr_npi: self.dest_addr_npi,
+            destination_addr: self.destination_addr.clone(),
+            registered_delivery: self.registered_delivery.clone(),
+            payload_type: self.payload_type,
+            qos_time_to_live: self.qos_time_to_live.clone(),
+            data_coding: self.data_coding,
+            message_payload: self.message_payload.clone(),
+            tlvs: self.tlvs.entries().to_vec(),
+        }
+    }
+
+    pub fn set_bound(&mut self, bound: bool) {
+        self.bound = bound;
+    }
+
+    fn update_service_type(&mut self) {
+        self.service_type.retain(|c| c.is_ascii());
+        self.fields.set_service_type(&self.service_type);
+    }
+
+    fn update_source_addr(&mut self) {
+        self.source_addr.retain(|c| c.is_ascii());
+        self.fields.set_source_addr(&self.source_addr);
+    }
+
+    fn update_destination_addr(&mut self) {
+        self.destination_addr.retain(|c| c.is_ascii());
+        self.fields.set_destination_addr(&self.destination_addr);
+    }
+
+    fn update_registered_delivery(&mut self) {
+        self.registered_delivery.retain(|c| c.is_ascii_digit());
+        self.fields
+            .set_registered_delivery(&self.registered_delivery);
+    }
+
+    fn update_qos_time_to_live(&mut self) {
+        self.qos_time_to_live.retain(|c| c.is_ascii_digit());
+        self.fields.set_qos_time_to_live(&self.qos_time_to_live);
+    }
+
+    fn update_message_payload(&mut self) {
+        self.fields
+            .set_message_payload(self.data_coding, &self.message_payload);
+    }
+
+    fn build_data_sm(&self) -> AppResult<DataSm> {
+        let mut tlvs = vec![
+            Tlv::new(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(self.fields.message_payload.clone()?),
+            )),
+            Tlv::new(MessageSubmissionRequestTlvValue::PayloadType(
+                self.payload_type.into(),
+            )),
+        ];
+
+        if let Some(seconds) = self.fields.qos_time_to_live.clone()? {
+            tlvs.push(Tlv::new(MessageSubmissionRequestTlvValue::QosTimeToLive(
+                seconds,
+            )));
+        }
+
+        tlvs.extend(self.tlvs.tlvs()?);
+
+        let data_sm = DataSm::builder()
+            .service_type(ServiceType::new(self.fields.service_type.clone()?))
+            .source_addr_ton(self.source_addr_ton.into())
+            .source_addr_npi(self.source_addr_npi.into())
+            .source_addr(self.fields.source_addr.clone()?)
+            .dest_addr_ton(self.dest_addr_ton.into())
+            .dest_addr_npi(self.dest_addr_npi.into())
+            .destination_addr(self.fields.destination_addr.clone()?)
+            .registered_delivery(RegisteredDelivery::from(
+                self.fields.registered_delivery.clone()?,
+            ))
+            .data_coding(self.data_coding.into())
+            .tlvs(tlvs)
+            .build();
+
+        Ok(data_sm)
+    }
+
+    fn on_send_button_clicked(&mut self) {
+        if let Ok(data_sm) = self.build_data_sm() {
+            *self.response.lock() = None;
+
+            self.actions
+                .data_sm(data_sm, self.response.clone(), self.loading.clone());
+        }
+    }
+
+    fn counters_message(&self) -> Option<String> {
+        let byte_count = self.fields.message_payload.as_ref().ok()?.len();
+        let char_count = self.message_payload.chars().count();
+
+        Some(format!("{char_count} Characters {byte_count} Bytes"))
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        let loading = self.loading.load(Ordering::Relaxed);
+
+        ui.vertical_centered(|ui| {
+            let display_err = |ui: &mut egui::Ui, err: &AppUiError| {
+                ui.allocate_space(egui::vec2(0.0, 0.0));
+                ui.colored_label(FUSION_RED, err.display_message());
+                ui.end_row();
+            };
+
+            egui::Grid::new("data_sm_grid")
+                .num_columns(2)
+                .spacing([12.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.label("Service Type");
+                    ui.add(egui::TextEdit::singleline(&mut self.service_type).char_limit(5))
+                        .on_hover_text("Max 5 ASCII characters, e.g. USSD or WAP")
+                        .changed()
+                        .then(|| self.update_service_type());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.service_type {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Registered Delivery");
+                    ui.add(egui::TextEdit::singleline(&mut self.registered_delivery).char_limit(3))
+                        .on_hover_text("Unsigned 8-bit integer")
+                        .changed()
+                        .then(|| self.update_registered_delivery());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.registered_delivery {
+                        display_err(ui, err);
+                    }
+                });
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.add_space(12.0);
+
+            egui::Grid::new("data_sm_addr_grid")
+                .num_columns(6)
+                .spacing([16.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.add(
+                        AddressInput::new(
+                            "data_sm_source_addr",
+                            "Source Address",
+                            &mut self.source_addr_ton,
+                            &mut self.source_addr_npi,
+                            &mut self.source_addr,
+                        )
+                        .char_limit(64),
+                    )
+                    .changed()
+                    .then(|| self.update_source_addr());
+
+                    ui.end_row();
+
+                    ui.add(
+                        AddressInput::new(
+                            "data_sm_dest_addr",
+                            "Destination Address",
+                            &mut self.dest_addr_ton,
+                            &mut self.dest_addr_npi,
+                            &mut self.destination_addr,
+                        )
+                        .char_limit(64),
+                    )
+                    .changed()
+                    .then(|| self.update_destination_addr());
+
+                    ui.end_row();
+                });
+
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.heading("Optional Parameters");
+                ui.add(Separator::default().horizontal().spacing(8.0));
+            });
+
+            ui.add_space(12.0);
+
+            egui::Grid::new("data_sm_tlvs_grid")
+                .num_columns(2)
+                .spacing([12.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.label("Payload Type");
+                    ui.add(ComboBox::new(
+                        "data_sm_payload_type",
+                        &mut self.payload_type,
+                        PayloadType::VARIANTS,
+                    ));
+                    ui.end_row();
+
+                    ui.label("QoS Time To Live");
+                    ui.add(egui::TextEdit::singleline(&mut self.qos_time_to_live).char_limit(10))
+                        .on_hover_text("Seconds, unsigned 32-bit integer, empty to omit")
+                        .changed()
+                        .then(|| self.update_qos_time_to_live());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.qos_time_to_live {
+                        display_err(ui, err);
+                    }
+                });
+
+            ui.add_space(12.0);
+
+            self.tlvs.ui(ui);
+
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.heading("Message Payload");
+                ui.add(Separator::default().horizontal().spacing(8.0));
+            });
+
+            ui.add_space(12.0);
+
+            ui.vertical(|ui| {
+                egui::Grid::new("data_sm_data_coding_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 10.0])
+                    .striped(false)
+                    .show(ui, |ui| {
+                        ui.label("Data Coding");
+                        ui.add(ComboBox::new(
+                            "data_sm_data_coding",
+                            &mut self.data_coding,
+                            DataCoding::FIXED,
+                        ))
+                        .changed()
+                        .then(|| self.update_message_payload());
+
+                        ui.end_row();
+                    });
+
+                ui.add_space(10.0);
+
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.message_payload)
+                        .desired_width(ui.available_width()),
+                )
+                .changed()
+                .then(|| self.update_message_payload());
+
+                ui.add_space(10.0);
+
+                match (&self.fields.message_payload, self.counters_message()) {
+                    (Err(err), _) => {
+                        ui.colored_label(FUSION_RED, err.display_message());
+                    }
+                    (Ok(_), Some(counters_message)) => {
+                        ui.label(counters_message);
+                    }
+                    (Ok(_), None) => {}
+                }
+            });
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.add_enabled_ui(
+                        self.bound
+                            && !loading
+                            && self.fields.all_fields_valid()
+                            && self.tlvs.all_rows_valid(),
+                        |ui| {
+                            ui.add_sized(
+                                [140.0, 32.0],
+                                egui::Button::new(
+                                    RichText::new("Send Data SM").color(Color32::WHITE).strong(),
+                                )
+                                .fill(HIGH_BLUE),
+                            )
+                            .clicked()
+                            .then(|| {
+                                self.on_send_button_clicked();
+                            });
+                        },
+                    );
+
+                    if loading {
+                        ui.spinner();
+                    }
+                });
+            });
+
+            if let Some(response) = self.response.lock().as_ref() {
+                ui.add_space(12.0);
+
+                if let Some(Pdu::DataSmResp(data_sm_resp)) =
+                    response_ui(ui, response).and_then(Command::pdu)
+                {
+                    ui.colored_label(
+                        REPTILE_GREEN,
+                        format!("Accepted with Message ID {}", data_sm_resp.message_id()),
+                    );
+                }
+            }
+        })
+        .response
+    }
+}
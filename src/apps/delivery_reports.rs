@@ -0,0 +1,366 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use eframe::egui::{self, RichText};
+use egui_virtual_list::VirtualList;
+use rusmpp::{Command, CommandStatus, Pdu};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    colors::{FUSION_RED, REPTILE_GREEN},
+    receipt::DeliveryReceipt,
+    state::EventsHolder,
+    store::{Progress, Snapshot},
+    values::{Event, Record},
+};
+
+/// A submitted message, identified by the `message_id` of its `SubmitSmResp`
+struct DeliveryReport {
+    /// Id of the `SubmitSmResp` record, the report is dropped once it is evicted
+    id: u64,
+    message_id: String,
+    destination_addr: String,
+    receipts: usize,
+    last_receipt: Option<DeliveryReceipt>,
+    /// Local time the `SubmitSm` was sent at
+    submitted_at: DateTime<Utc>,
+    /// Local time the first final receipt was received at
+    done_at: Option<DateTime<Utc>>,
+}
+
+impl DeliveryReport {
+    fn state(&self) -> String {
+        self.last_receipt
+            .as_ref()
+            .and_then(DeliveryReceipt::state)
+            .unwrap_or_else(|| String::from("Submitted"))
+    }
+
+    fn is_final(&self) -> bool {
+        self.last_receipt
+            .as_ref()
+            .is_some_and(DeliveryReceipt::is_final)
+    }
+
+    /// Time between sending the `SubmitSm` and receiving its final receipt, measured locally.
+    ///
+    /// The MC reports `submit date` and `done date` with minute resolution only.
+    fn latency(&self) -> Option<String> {
+        self.done_at
+            .map(|done_at| format_delta(done_at - self.submitted_at))
+    }
+}
+
+fn format_delta(delta: TimeDelta) -> String {
+    let millis = delta.num_milliseconds().max(0);
+
+    match millis / 1000 {
+        _ if millis < 1000 => format!("{millis}ms"),
+        secs if secs < 60 => format!("{}.{:03}s", secs, millis % 1000),
+        secs if secs < 3600 => format!("{}m {}s", secs / 60, secs % 60),
+        secs => format!("{}h {}m", secs / 3600, (secs % 3600) / 60),
+    }
+}
+
+/// A receipt that could not be matched to a submitted message yet
+struct UnmatchedReceipt {
+    /// Id of the `DeliverSm` record
+    id: u64,
+    time: DateTime<Utc>,
+    receipt: DeliveryReceipt,
+}
+
+/// Matches receipts to the messages acknowledged by a `SubmitSmResp`.
+///
+/// Reports and unmatched receipts follow the records of the store, they are dropped once their record is evicted.
+#[derive(Default)]
+struct ReceiptMatcher {
+    progress: Progress,
+    /// Ordered by record id
+    reports: Vec<DeliveryReport>,
+    /// Record id of the report by normalized message id
+    by_message_id: HashMap<String, u64>,
+    /// Ordered by record id
+    unmatched: Vec<UnmatchedReceipt>,
+}
+
+impl ReceiptMatcher {
+    fn update(&mut self, snapshot: &Snapshot) {
+        // Forget about evicted records.
+        let first = snapshot.first_id().unwrap_or(u64::MAX);
+
+        let evicted = self.reports.partition_point(|report| report.id < first);
+
+        for report in self.reports.drain(..evicted) {
+            let message_id = normalize(&report.message_id);
+
+            if self.by_message_id.get(&message_id) == Some(&report.id) {
+                self.by_message_id.remove(&message_id);
+            }
+        }
+
+        let evicted = self
+            .unmatched
+            .partition_point(|unmatched| unmatched.id < first);
+
+        self.unmatched.drain(..evicted);
+
+        for record in self.progress.advance(snapshot) {
+            if let Event::Received(command) = &record.event {
+                self.on_received(snapshot, record, command);
+            }
+        }
+    }
+
+    fn on_received(&mut self, snapshot: &Snapshot, record: &Record, command: &Command) {
+        match command.pdu() {
+            Some(Pdu::SubmitSmResp(submit_sm_resp)) => {
+                if command.status() != CommandStatus::EsmeRok {
+                    return;
+                }
+
+                let Some(request) = record.request.and_then(|request| snapshot.get(request.id))
+                else {
+                    return;
+                };
+
+                let Some(Pdu::SubmitSm(submit_sm)) = request.event.command().and_then(Command::pdu)
+                else {
+                    return;
+                };
+
+                let message_id = submit_sm_resp.message_id().to_string();
+
+                self.by_message_id.insert(normalize(&message_id), record.id);
+
+                self.reports.push(DeliveryReport {
+                    id: record.id,
+                    message_id,
+                    destination_addr: submit_sm.destination_addr.to_string(),
+                    receipts: 0,
+                    last_receipt: None,
+                    submitted_at: request.time,
+                    done_at: None,
+                });
+
+                // The receipt may have been received before the response.
+                let unmatched = std::mem::take(&mut self.unmatched);
+
+                for unmatched in unmatched {
+                    self.on_receipt(unmatched);
+                }
+            }
+            Some(Pdu::DeliverSm(deliver_sm)) => {
+                let Some(receipt) = DeliveryReceipt::from_deliver_sm(deliver_sm) else {
+                    return;
+                };
+
+                self.on_receipt(UnmatchedReceipt {
+                    id: record.id,
+                    time: record.time,
+                    receipt,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn on_receipt(&mut self, unmatched: UnmatchedReceipt) {
+        let Some(index) = unmatched.receipt.message_id().and_then(|id| self.find(id)) else {
+            self.unmatched.push(unmatched);
+
+            return;
+        };
+
+        let report = &mut self.reports[index];
+
+        if unmatched.receipt.is_final() && report.done_at.is_none() {
+            report.done_at = Some(unmatched.time);
+        }
+
+        report.receipts += 1;
+        report.last_receipt = Some(unmatched.receipt);
+    }
+
+    /// Finds the index of a report by message id.
+    ///
+    /// Some MCs return the message id in hex in the `SubmitSmResp` and in decimal in the receipt, or the other way around.
+    /// The conversion is only used without an exact match, and only if it points to a single report.
+    fn find(&self, message_id: &str) -> Option<usize> {
+        let message_id = normalize(message_id);
+
+        if let Some(id) = self.by_message_id.get(&message_id) {
+            return self.index(*id);
+        }
+
+        let as_hex = message_id
+            .parse::<u64>()
+            .ok()
+            .map(|decimal| format!("{decimal:x}"));
+
+        let as_decimal = u64::from_str_radix(&message_id, 16)
+            .ok()
+            .map(|hex| hex.to_string());
+
+        let mut candidates = [as_hex, as_decimal]
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.by_message_id.get(&id).copied());
+
+        match (candidates.next(), candidates.next()) {
+            (Some(id), None) => self.index(id),
+            _ => None,
+        }
+    }
+
+    fn index(&self, id: u64) -> Option<usize> {
+        self.reports
+            .binary_search_by_key(&id, |report| report.id)
+            .ok()
+    }
+}
+
+fn normalize(message_id: &str) -> String {
+    let message_id = message_id.trim().to_ascii_lowercase();
+    let trimmed = message_id.trim_start_matches('0');
+
+    if trimmed.is_empty() {
+        message_id
+    } else {
+        trimmed.to_string()
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeDeliveryReportsApp {}
+
+pub struct DeliveryReportsApp {
+    events_holder: EventsHolder,
+    snapshot: Snapshot,
+    receipts: ReceiptMatcher,
+    list: VirtualList,
+    unmatched_list: VirtualList,
+}
+
+/// Columns of the reports with their width
+const COLUMNS: [(&str, f32); 8] = [
+    ("Message ID", 160.0),
+    ("Destination", 120.0),
+    ("State", 90.0),
+    ("Error", 50.0),
+    ("Submit Date", 100.0),
+    ("Done Date", 100.0),
+    ("Latency", 80.0),
+    ("Receipts", 60.0),
+];
+
+impl DeliveryReportsApp {
+    pub fn new_default(events_holder: EventsHolder) -> Self {
+        Self {
+            events_holder,
+            snapshot: Snapshot::default(),
+            receipts: ReceiptMatcher::default(),
+            list: VirtualList::new(),
+            unmatched_list: VirtualList::new(),
+        }
+    }
+
+    pub fn from_serde(events_holder: EventsHolder, _serde: SerdeDeliveryReportsApp) -> Self {
+        Self::new_default(events_holder)
+    }
+
+    pub fn to_serde(&self) -> SerdeDeliveryReportsApp {
+        SerdeDeliveryReportsApp {}
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        self.events_holder.sync(&mut self.snapshot);
+        self.receipts.update(&self.snapshot);
+
+        ui.set_width(ui.available_width());
+
+        let Self {
+            receipts,
+            list,
+            unmatched_list,
+            ..
+        } = self;
+
+        if !receipts.unmatched.is_empty() {
+            egui::CollapsingHeader::new(format!(
+                "Unmatched Receipts ({})",
+                receipts.unmatched.len()
+            ))
+            .id_salt("delivery_reports_unmatched")
+            .show(ui, |ui| {
+                let len = receipts.unmatched.len();
+
+                unmatched_list.ui_custom_layout(ui, len, |ui, start_index| {
+                    let receipt = &receipts.unmatched[len - 1 - start_index].receipt;
+
+                    ui.label(format!(
+                        "{}: {}",
+                        receipt.message_id().unwrap_or("-"),
+                        receipt.state().as_deref().unwrap_or("-")
+                    ));
+
+                    1
+                });
+            });
+
+            ui.add_space(12.0);
+        }
+
+        Self::row_ui(
+            ui,
+            COLUMNS.map(|(header, _)| RichText::new(header).strong()),
+        );
+
+        ui.separator();
+
+        let len = receipts.reports.len();
+
+        list.ui_custom_layout(ui, len, |ui, start_index| {
+            let report = &receipts.reports[len - 1 - start_index];
+            let receipt = report.last_receipt.as_ref();
+
+            let state = RichText::new(report.state());
+            let state = if !report.is_final() {
+                state
+            } else if receipt.is_some_and(DeliveryReceipt::is_delivered) {
+                state.color(REPTILE_GREEN)
+            } else {
+                state.color(FUSION_RED)
+            };
+
+            let or_dash = |value: Option<&str>| RichText::new(value.unwrap_or("-"));
+
+            Self::row_ui(
+                ui,
+                [
+                    RichText::new(&report.message_id),
+                    RichText::new(&report.destination_addr),
+                    state,
+                    or_dash(receipt.and_then(|r| r.err.as_deref())),
+                    or_dash(receipt.and_then(|r| r.submit_date.as_deref())),
+                    or_dash(receipt.and_then(|r| r.done_date.as_deref())),
+                    or_dash(report.latency().as_deref()),
+                    RichText::new(report.receipts.to_string()),
+                ],
+            );
+
+            1
+        });
+    }
+
+    fn row_ui(ui: &mut egui::Ui, cells: [RichText; COLUMNS.len()]) {
+        ui.horizontal(|ui| {
+            for (cell, (_, width)) in cells.into_iter().zip(COLUMNS) {
+                ui.add_sized(
+                    [width, ui.spacing().interact_size.y],
+                    egui::Label::new(cell).truncate(),
+                );
+            }
+        });
+    }
+}
@@ -1,57 +1,713 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+use eframe::egui::{self, RichText, collapsing_header::CollapsingState};
 use egui_virtual_list::VirtualList;
+use parking_lot::Mutex;
+use rusmpp::{Command, CommandStatus, Pdu};
 use serde::{Deserialize, Serialize};
+use strum::VariantArray;
+
+use crate::{
+    actions::ActionsChannel,
+    apps::selection::{MessageTarget, Selection},
+    colors::{FUSION_RED, HIGH_BLUE},
+    export::{self, ExportFormat},
+    import,
+    inspect::{Inspect, Node},
+    state::EventsHolder,
+    store::{DEFAULT_CAPACITY, MAX_CAPACITY, MIN_CAPACITY, Progress, Snapshot},
+    values::Record,
+    widgets::{ComboBox, HexDump, NodeTree},
+    wire,
+};
+
+mod filter;
+use filter::{Filter, StatusFilter};
+
+mod pairing;
+use pairing::{Link, Pairing};
+
+mod replay;
+use replay::Replay;
+
+/// A log loaded from a file, shown instead of the live events
+struct Imported {
+    name: String,
+    snapshot: Snapshot,
+}
+
+/// Ids of the records matching a filter
+#[derive(Default)]
+struct View {
+    progress: Progress,
+    /// Filter the ids were collected with
+    filter: Filter,
+    ids: VecDeque<u64>,
+    /// Command ids seen so far with their names
+    command_ids: BTreeMap<u32, String>,
+}
+
+impl View {
+    /// Collects the records pushed since the last update, or all of them if the filter changed.
+    ///
+    /// Returns `true` if the view was rebuilt.
+    fn update(&mut self, snapshot: &Snapshot, filter: &Filter) -> bool {
+        let rebuild = self.filter != *filter;
+
+        if rebuild {
+            self.progress.reset();
+            self.filter = filter.clone();
+            self.ids.clear();
+        }
+
+        // Drop the ids of evicted records.
+        let first = snapshot.first_id().unwrap_or(u64::MAX);
+        let evicted = self.ids.partition_point(|id| *id < first);
+
+        self.ids.drain(..evicted);
+
+        let matcher = filter.matcher();
+
+        for record in self.progress.advance(snapshot) {
+            if let Some(command) = record.event.command() {
+                self.command_ids
+                    .entry(u32::from(command.id()))
+                    .or_insert_with(|| format!("{:?}", command.id()));
+            }
+
+            if matcher.matches(record) {
+                self.ids.push_back(record.id);
+            }
+        }
+
+        rebuild || evicted > 0
+    }
 
-use crate::state::EventsHolder;
+    fn contains(&self, id: u64) -> bool {
+        self.ids.binary_search(&id).is_ok()
+    }
+}
 
 #[derive(Clone, Serialize, Deserialize)]
-pub struct SerdeLogsApp {}
+pub struct SerdeLogsApp {
+    #[serde(default)]
+    filter: Filter,
+    #[serde(default = "default_capacity")]
+    capacity: usize,
+}
+
+fn default_capacity() -> usize {
+    DEFAULT_CAPACITY
+}
 
 pub struct LogsApp {
     events_holder: EventsHolder,
+    actions: ActionsChannel,
+    /// Message picked for the tabs that operate on submitted messages
+    selection: Selection,
+    bound: bool,
+    /// Live events, synced with the store every frame
+    snapshot: Snapshot,
+    /// Boxed, as it is a snapshot of its own and usually absent
+    imported: Option<Box<Imported>>,
+    /// Replay of the imported log
+    replay: Option<Replay>,
+    /// Filled by the file dialog, which may complete after the frame that opened it
+    pending_import: Arc<Mutex<Option<Result<Imported, String>>>>,
+    import_error: Option<String>,
     list: VirtualList,
+    filter: Filter,
+    view: View,
+    pairing: Pairing,
+    /// Id of the record to scroll to, once it is laid out by the list
+    jump_to: Option<u64>,
+    /// Id of the last record jumped to
+    selected: Option<u64>,
+    /// Export only the records matching the filter
+    export_filtered: bool,
+    export_error: Option<String>,
+    store_error: Option<String>,
 }
 
 impl LogsApp {
-    pub fn new_default(events_holder: EventsHolder) -> Self {
+    fn new_from_values(
+        events_holder: EventsHolder,
+        actions: ActionsChannel,
+        selection: Selection,
+        filter: Filter,
+        capacity: usize,
+    ) -> Self {
+        events_holder.set_capacity(capacity);
+
         Self {
             events_holder,
+            actions,
+            selection,
+            bound: false,
+            snapshot: Snapshot::default(),
+            imported: None,
+            replay: None,
+            pending_import: Arc::new(Mutex::new(None)),
+            import_error: None,
             list: VirtualList::new(),
+            filter,
+            view: View::default(),
+            pairing: Pairing::default(),
+            jump_to: None,
+            selected: None,
+            export_filtered: true,
+            export_error: None,
+            store_error: None,
         }
     }
 
-    pub fn from_serde(events_holder: EventsHolder, _serde: SerdeLogsApp) -> Self {
-        Self {
+    pub fn new_default(
+        events_holder: EventsHolder,
+        actions: ActionsChannel,
+        selection: Selection,
+    ) -> Self {
+        Self::new_from_values(
             events_holder,
-            list: VirtualList::new(),
-        }
+            actions,
+            selection,
+            Filter::default(),
+            DEFAULT_CAPACITY,
+        )
+    }
+
+    pub fn from_serde(
+        events_holder: EventsHolder,
+        actions: ActionsChannel,
+        selection: Selection,
+        serde: SerdeLogsApp,
+    ) -> Self {
+        Self::new_from_values(
+            events_holder,
+            actions,
+            selection,
+            serde.filter,
+            serde.capacity,
+        )
     }
 
     pub fn to_serde(&self) -> SerdeLogsApp {
-        SerdeLogsApp {}
+        SerdeLogsApp {
+            filter: self.filter.clone(),
+            capacity: self.events_holder.capacity(),
+        }
+    }
+
+    pub fn set_bound(&mut self, bound: bool) {
+        self.bound = bound;
+    }
+
+    /// Switches between the live events and an imported log.
+    fn set_imported(&mut self, imported: Option<Box<Imported>>) {
+        self.replay = imported
+            .as_ref()
+            .map(|imported| Replay::new(self.actions.clone(), &imported.snapshot));
+        self.imported = imported;
+        self.view = View::default();
+        self.pairing = Pairing::default();
+        self.jump_to = None;
+        self.selected = None;
+        self.list.reset();
+    }
+
+    fn on_import_button_clicked(&mut self, ctx: egui::Context) {
+        let pending_import = self.pending_import.clone();
+
+        import::open(move |name, bytes| {
+            let imported = bytes
+                .and_then(|bytes| import::parse(&bytes))
+                .map(|records| Imported {
+                    name,
+                    snapshot: Snapshot::from_records(records),
+                })
+                .map_err(|err| format!("Import failed: {err:#}"));
+
+            *pending_import.lock() = Some(imported);
+
+            ctx.request_repaint();
+        });
     }
 
     pub fn ui(&mut self, ui: &mut egui::Ui) {
-        let events = self.events_holder.events();
-        let len = events.len();
+        let pending_import = self.pending_import.lock().take();
+
+        match pending_import {
+            Some(Ok(imported)) => {
+                self.import_error = None;
+                self.set_imported(Some(Box::new(imported)));
+            }
+            Some(Err(err)) => self.import_error = Some(err),
+            None => {}
+        }
+
+        self.events_holder.sync(&mut self.snapshot);
+
+        let snapshot = match &self.imported {
+            Some(imported) => &imported.snapshot,
+            None => &self.snapshot,
+        };
+
+        // Imported records are not waiting for anything anymore, so time stops at the last one.
+        let uptime = match &self.imported {
+            Some(_) => snapshot
+                .last()
+                .map(|record| record.uptime)
+                .unwrap_or_default(),
+            None => self.events_holder.uptime(),
+        };
+
+        self.pairing.update(snapshot, uptime);
+
+        if self.view.update(snapshot, &self.filter) {
+            self.list.reset();
+        }
+
+        // Unanswered requests are flagged once the timeout expires, even if nothing else happens.
+        if self.imported.is_none() && self.pairing.is_waiting() {
+            ui.ctx().request_repaint_after(Duration::from_secs(1));
+        }
 
         ui.set_width(ui.available_width());
 
-        self.list.ui_custom_layout(ui, len, |ui, start_index| {
-            let index = len - 1 - start_index;
+        let mut import_clicked = false;
+        let mut back_to_live = false;
+
+        let export = ui
+            .horizontal_wrapped(|ui| {
+                Self::filter_ui(
+                    ui,
+                    &mut self.filter,
+                    &self.view.command_ids,
+                    self.view.ids.len(),
+                    snapshot.len(),
+                );
+
+                ui.separator();
+
+                let export = Self::export_ui(ui, &mut self.export_filtered, &self.export_error);
+
+                Self::store_ui(ui, &self.events_holder, &mut self.store_error);
+
+                if ui.button("Import").clicked() {
+                    import_clicked = true;
+                }
+
+                if let Some(error) = &self.import_error {
+                    ui.label(RichText::new(error).color(FUSION_RED));
+                }
+
+                export
+            })
+            .inner;
 
-            if let Some(event) = events.get(index) {
-                ui.push_id(index, |ui| {
-                    egui::Frame::group(ui.style())
+        if let (Some(imported), Some(replay)) = (&self.imported, &mut self.replay) {
+            ui.add_space(8.0);
+
+            ui.horizontal(|ui| {
+                ui.label(
+                    RichText::new(format!(
+                        "Viewing {} ({} events, read-only)",
+                        imported.name,
+                        imported.snapshot.len()
+                    ))
+                    .color(HIGH_BLUE),
+                );
+
+                if ui.button("Back to Live Events").clicked() {
+                    back_to_live = true;
+                }
+            });
+
+            replay.ui(ui, snapshot, self.bound);
+        }
+
+        let export = export.map(|format| {
+            let bytes = if self.export_filtered {
+                format.encode(self.view.ids.iter().filter_map(|id| snapshot.get(*id)))
+            } else {
+                format.encode(snapshot.iter())
+            };
+
+            (format, bytes)
+        });
+
+        ui.add_space(8.0);
+
+        let Self {
+            list,
+            view,
+            pairing,
+            jump_to,
+            selected,
+            ..
+        } = self;
+
+        let len = view.ids.len();
+
+        // Range of laid out ids, used to scroll towards a record that is not laid out yet
+        let mut laid_out: Option<(u64, u64)> = None;
+        // Record picked for the tabs that operate on submitted messages
+        let mut select = None;
+
+        list.ui_custom_layout(ui, len, |ui, start_index| {
+            let id = view.ids[len - 1 - start_index];
+
+            laid_out = Some(match laid_out {
+                Some((min, max)) => (min.min(id), max.max(id)),
+                None => (id, id),
+            });
+
+            if let Some(record) = snapshot.get(id) {
+                ui.push_id(id, |ui| {
+                    let stroke = if *selected == Some(id) {
+                        egui::Stroke::new(1.0, HIGH_BLUE)
+                    } else {
+                        ui.visuals().widgets.noninteractive.bg_stroke
+                    };
+
+                    let response = egui::Frame::group(ui.style())
                         .inner_margin(egui::Margin::same(8))
                         .corner_radius(egui::CornerRadius::same(6))
+                        .stroke(stroke)
                         .show(ui, |ui| {
                             ui.set_width(ui.available_width());
-                            ui.label(format!("{event:?}"));
-                        });
+
+                            Self::record_ui(ui, record, view, pairing, jump_to, &mut select);
+                        })
+                        .response;
+
+                    if *jump_to == Some(id) {
+                        response.scroll_to_me(Some(egui::Align::Center));
+
+                        *selected = Some(id);
+                        *jump_to = None;
+                    }
                 });
             }
 
             1
         });
+
+        // Newest records are laid out first, so higher ids are further up.
+        if let (Some(target), Some((min, max))) = (*jump_to, laid_out) {
+            let height = ui.clip_rect().height();
+
+            if target > max {
+                ui.scroll_with_delta(egui::vec2(0.0, height));
+            } else if target < min {
+                ui.scroll_with_delta(egui::vec2(0.0, -height));
+            }
+
+            ui.ctx().request_repaint();
+        }
+
+        if let Some(target) = select.and_then(|id| message_target(snapshot, id)) {
+            self.selection.set(target);
+        }
+
+        if let Some((format, bytes)) = export {
+            self.export_error = export::save(&export::file_name(format), &bytes)
+                .err()
+                .map(|err| format!("Export failed: {err}"));
+        }
+
+        if back_to_live {
+            self.set_imported(None);
+        }
+
+        if import_clicked {
+            self.on_import_button_clicked(ui.ctx().clone());
+        }
+    }
+
+    fn record_ui(
+        ui: &mut egui::Ui,
+        record: &Record,
+        view: &View,
+        pairing: &Pairing,
+        jump_to: &mut Option<u64>,
+        select: &mut Option<u64>,
+    ) {
+        let Some(command) = record.event.command() else {
+            ui.horizontal(|ui| {
+                Self::stamp_ui(ui, record);
+                ui.label(record.event.summary());
+            });
+
+            return;
+        };
+
+        CollapsingState::load_with_default_open(
+            ui.ctx(),
+            ui.make_persistent_id("logs_event"),
+            false,
+        )
+        .show_header(ui, |ui| {
+            Self::stamp_ui(ui, record);
+            ui.label(record.event.summary());
+
+            match pairing.link(record.id) {
+                Some(link) => Self::link_ui(ui, link, view, jump_to),
+                None if pairing.is_unanswered(record.id) => {
+                    ui.label(RichText::new("No response").color(FUSION_RED));
+                }
+                None => {}
+            }
+
+            if is_successful_submit_sm_resp(record) {
+                let button = ui
+                    .add(egui::Button::new("Select").small())
+                    .on_hover_text("Use this message in the Query, Cancel and Replace Sm tabs");
+
+                if button.clicked() {
+                    *select = Some(record.id);
+                }
+            }
+        })
+        .body(|ui| Self::command_ui(ui, command));
+    }
+
+    fn filter_ui(
+        ui: &mut egui::Ui,
+        filter: &mut Filter,
+        command_ids: &BTreeMap<u32, String>,
+        shown: usize,
+        total: usize,
+    ) {
+        ui.checkbox(&mut filter.sent, "Sent");
+        ui.checkbox(&mut filter.received, "Received");
+        ui.checkbox(&mut filter.errors, "Errors");
+        ui.checkbox(&mut filter.lifecycle, "Lifecycle");
+
+        ui.menu_button("Commands", |ui| {
+            if command_ids.is_empty() {
+                ui.weak("No commands yet");
+            }
+
+            for (id, name) in command_ids {
+                let mut shown = !filter.hidden_command_ids.contains(id);
+
+                if ui.checkbox(&mut shown, name).changed() {
+                    if shown {
+                        filter.hidden_command_ids.remove(id);
+                    } else {
+                        filter.hidden_command_ids.insert(*id);
+                    }
+                }
+            }
+        });
+
+        ui.add(
+            ComboBox::new(
+                "logs_status_filter",
+                &mut filter.status,
+                StatusFilter::VARIANTS,
+            )
+            .width(90.0),
+        );
+
+        ui.add(
+            egui::TextEdit::singleline(&mut filter.search)
+                .hint_text("Address, message id or text")
+                .desired_width(200.0),
+        );
+
+        if filter.is_active() && ui.button("Clear").clicked() {
+            *filter = Filter::default();
+        }
+
+        ui.weak(format!("{shown} of {total}"));
+    }
+
+    fn store_ui(ui: &mut egui::Ui, events_holder: &EventsHolder, error: &mut Option<String>) {
+        ui.menu_button("Store", |ui| {
+            let mut capacity = events_holder.capacity();
+
+            ui.horizontal(|ui| {
+                ui.label("Capacity");
+
+                let response = ui.add(
+                    egui::DragValue::new(&mut capacity)
+                        .range(MIN_CAPACITY..=MAX_CAPACITY)
+                        .speed(100),
+                );
+
+                if response.changed() {
+                    events_holder.set_capacity(capacity);
+                }
+            });
+
+            ui.label("Oldest events are evicted once the capacity is reached.");
+
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::spill_ui(ui, events_holder, error);
+
+            if let Some(error) = error {
+                ui.label(RichText::new(error.as_str()).color(FUSION_RED));
+            }
+        });
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn spill_ui(ui: &mut egui::Ui, events_holder: &EventsHolder, error: &mut Option<String>) {
+        ui.separator();
+
+        let status = events_holder.spill_status();
+        let mut spill = status.is_some();
+
+        if ui
+            .checkbox(&mut spill, "Spill evicted events to file")
+            .changed()
+        {
+            let path = spill
+                .then(|| {
+                    rfd::FileDialog::new()
+                        .set_file_name("rusmppc-spill.jsonl")
+                        .save_file()
+                })
+                .flatten();
+
+            *error = events_holder
+                .set_spill(path)
+                .err()
+                .map(|err| format!("Failed to create spill file: {err}"));
+        }
+
+        if let Some((path, spill_error)) = status {
+            ui.label(path.display().to_string());
+
+            if spill_error.is_some() {
+                *error = spill_error;
+            }
+        }
+    }
+
+    fn export_ui(
+        ui: &mut egui::Ui,
+        filtered: &mut bool,
+        error: &Option<String>,
+    ) -> Option<ExportFormat> {
+        let format = ui
+            .menu_button("Export", |ui| {
+                ui.checkbox(filtered, "Only filtered events");
+
+                ui.separator();
+
+                let mut format = None;
+
+                for variant in ExportFormat::VARIANTS {
+                    if ui.button(<&'static str>::from(*variant)).clicked() {
+                        format = Some(*variant);
+                    }
+                }
+
+                format
+            })
+            .inner
+            .flatten();
+
+        if let Some(error) = error {
+            ui.label(RichText::new(error).color(FUSION_RED));
+        }
+
+        format
+    }
+
+    fn stamp_ui(ui: &mut egui::Ui, record: &Record) {
+        ui.label(
+            RichText::new(format!(
+                "#{} {} {}",
+                record.id,
+                record.local_time_str(),
+                record.uptime_str()
+            ))
+            .monospace()
+            .weak(),
+        );
+    }
+
+    fn link_ui(ui: &mut egui::Ui, link: Link, view: &View, jump_to: &mut Option<u64>) {
+        ui.weak(format!("{:.1} ms", link.round_trip.as_secs_f64() * 1000.0));
+
+        let text = if link.is_request {
+            "Go to response"
+        } else {
+            "Go to request"
+        };
+
+        let button = ui
+            .add_enabled(view.contains(link.peer), egui::Button::new(text).small())
+            .on_disabled_hover_text("Hidden by the filter");
+
+        if button.clicked() {
+            *jump_to = Some(link.peer);
+        }
+    }
+
+    fn command_ui(ui: &mut egui::Ui, command: &Command) {
+        let nodes = command.inspect();
+        let mut hovered = None;
+
+        ui.add(NodeTree::new("logs_event_tree", &nodes).hovered(&mut hovered));
+
+        egui::CollapsingHeader::new("hex")
+            .id_salt("logs_event_hex")
+            .show(ui, |ui| {
+                let mut fields = Vec::new();
+
+                for node in &nodes {
+                    node.visit_ranges(&mut |node: &Node, range| {
+                        if node.children.is_empty() {
+                            fields.push(range.clone());
+                        }
+                    });
+                }
+
+                ui.add(
+                    HexDump::new(&wire::encode(command))
+                        .fields(fields)
+                        .highlight(hovered),
+                );
+            });
+    }
+}
+
+fn is_successful_submit_sm_resp(record: &Record) -> bool {
+    record.event.command().is_some_and(|command| {
+        command.status() == CommandStatus::EsmeRok
+            && matches!(command.pdu(), Some(Pdu::SubmitSmResp(_)))
+    })
+}
+
+/// The message acknowledged by a `SubmitSmResp`, with the source address of its paired `SubmitSm`.
+fn message_target(snapshot: &Snapshot, id: u64) -> Option<MessageTarget> {
+    let record = snapshot.get(id)?;
+
+    let Some(Pdu::SubmitSmResp(submit_sm_resp)) = record.event.command()?.pdu() else {
+        return None;
+    };
+
+    let request = record
+        .request
+        .and_then(|request| snapshot.get(request.id))
+        .and_then(|record| record.event.command())
+        .and_then(|command| match command.pdu() {
+            Some(Pdu::SubmitSm(submit_sm)) => Some(submit_sm),
+            _ => None,
+        });
+
+    Some(MessageTarget {
+        message_id: submit_sm_resp.message_id().to_string(),
+        ..request
+            .map(MessageTarget::from_submit_sm)
+            .unwrap_or_default()
+    })
 }
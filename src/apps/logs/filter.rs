@@ -0,0 +1,256 @@
+use std::{borrow::Cow, collections::BTreeSet};
+
+use rusmpp::{
+    Command, CommandStatus, Pdu,
+    tlvs::{Tlv, TlvValue},
+    values::{DataCoding, DestAddress, EsmClass},
+};
+use serde::{Deserialize, Serialize};
+use strum::VariantArray;
+
+use crate::{
+    inspect::decode_payload,
+    values::{Event, Record},
+};
+
+#[derive(VariantArray, Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum StatusFilter {
+    #[default]
+    Any,
+    Ok,
+    Error,
+}
+
+impl ::core::convert::From<StatusFilter> for &'static str {
+    #[inline]
+    fn from(x: StatusFilter) -> &'static str {
+        match x {
+            StatusFilter::Any => "Any Status",
+            StatusFilter::Ok => "OK",
+            StatusFilter::Error => "Error",
+        }
+    }
+}
+
+/// Which records are shown in the log
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Filter {
+    pub sent: bool,
+    pub received: bool,
+    pub errors: bool,
+    /// Connected, Disconnected, Closed and Bound
+    pub lifecycle: bool,
+    /// Command ids as `u32`
+    pub hidden_command_ids: BTreeSet<u32>,
+    pub status: StatusFilter,
+    pub search: String,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self {
+            sent: true,
+            received: true,
+            errors: true,
+            lifecycle: true,
+            hidden_command_ids: BTreeSet::new(),
+            status: StatusFilter::Any,
+            search: String::new(),
+        }
+    }
+}
+
+impl Filter {
+    pub fn is_active(&self) -> bool {
+        *self != Self::default()
+    }
+
+    /// Prepares the filter for matching many records.
+    pub fn matcher(&self) -> Matcher<'_> {
+        Matcher {
+            filter: self,
+            query: self.search.to_lowercase(),
+        }
+    }
+}
+
+/// A [`Filter`] with the search query lowercased once
+pub struct Matcher<'a> {
+    filter: &'a Filter,
+    query: String,
+}
+
+impl Matcher<'_> {
+    pub fn matches(&self, record: &Record) -> bool {
+        let filter = self.filter;
+
+        let shown = match &record.event {
+            Event::Sent(_) => filter.sent,
+            Event::Received(_) => filter.received,
+            Event::Error(_) => filter.errors,
+            Event::Connected | Event::Disconnected | Event::Closed | Event::Bound => {
+                filter.lifecycle
+            }
+        };
+
+        if !shown {
+            return false;
+        }
+
+        let Some(command) = record.event.command() else {
+            // Only commands have a status and searchable fields.
+            return filter.status == StatusFilter::Any
+                && (self.query.is_empty() || self.contains(&record.event.summary()));
+        };
+
+        if filter.hidden_command_ids.contains(&u32::from(command.id())) {
+            return false;
+        }
+
+        let status_matches = match filter.status {
+            StatusFilter::Any => true,
+            StatusFilter::Ok => command.status() == CommandStatus::EsmeRok,
+            StatusFilter::Error => command.status() != CommandStatus::EsmeRok,
+        };
+
+        status_matches
+            && (self.query.is_empty() || searchable(command).iter().any(|text| self.contains(text)))
+    }
+
+    fn contains(&self, text: &str) -> bool {
+        text.to_lowercase().contains(&self.query)
+    }
+}
+
+/// Addresses, message ids and message text of the command, which are matched by the search
+fn searchable(command: &Command) -> Vec<Cow<'_, str>> {
+    let mut fields = Vec::new();
+
+    let Some(pdu) = command.pdu() else {
+        return fields;
+    };
+
+    match pdu {
+        Pdu::SubmitSm(pdu) => {
+            fields.extend([
+                text(pdu.source_addr.as_bytes()),
+                text(pdu.destination_addr.as_bytes()),
+            ]);
+            fields.extend(payload(
+                pdu.esm_class,
+                pdu.data_coding,
+                pdu.short_message().as_bytes(),
+            ));
+            fields.extend(tlvs(pdu.tlvs(), pdu.esm_class, pdu.data_coding));
+        }
+        Pdu::DeliverSm(pdu) => {
+            fields.extend([
+                text(pdu.source_addr.as_bytes()),
+                text(pdu.destination_addr.as_bytes()),
+            ]);
+            fields.extend(payload(
+                pdu.esm_class,
+                pdu.data_coding,
+                pdu.short_message().as_bytes(),
+            ));
+            fields.extend(tlvs(pdu.tlvs(), pdu.esm_class, pdu.data_coding));
+        }
+        Pdu::DataSm(pdu) => {
+            fields.extend([
+                text(pdu.source_addr.as_bytes()),
+                text(pdu.destination_addr.as_bytes()),
+            ]);
+            fields.extend(tlvs(pdu.tlvs(), pdu.esm_class, pdu.data_coding));
+        }
+        Pdu::SubmitMulti(pdu) => {
+            fields.push(text(pdu.source_addr.as_bytes()));
+            fields.extend(
+                pdu.dest_address()
+                    .iter()
+                    .map(|dest_address| match dest_address {
+                        DestAddress::SmeAddress(sme_address) => {
+                            text(sme_address.destination_addr.as_bytes())
+                        }
+                        DestAddress::DistributionListName(name) => text(name.dl_name.as_bytes()),
+                    }),
+            );
+            fields.extend(payload(
+                pdu.esm_class,
+                pdu.data_coding,
+                pdu.short_message().as_bytes(),
+            ));
+            fields.extend(tlvs(pdu.tlvs(), pdu.esm_class, pdu.data_coding));
+        }
+        Pdu::SubmitSmResp(pdu) => fields.push(text(pdu.message_id().as_bytes())),
+        Pdu::DeliverSmResp(pdu) => fields.push(text(pdu.message_id().as_bytes())),
+        Pdu::DataSmResp(pdu) => fields.push(text(pdu.message_id().as_bytes())),
+        Pdu::SubmitMultiResp(pdu) => {
+            fields.push(text(pdu.message_id.as_bytes()));
+            fields.extend(
+                pdu.unsuccess_sme()
+                    .iter()
+                    .map(|sme| text(sme.destination_addr.as_bytes())),
+            );
+        }
+        Pdu::QuerySm(pdu) => fields.extend([
+            text(pdu.message_id.as_bytes()),
+            text(pdu.source_addr.as_bytes()),
+        ]),
+        Pdu::QuerySmResp(pdu) => fields.push(text(pdu.message_id.as_bytes())),
+        Pdu::CancelSm(pdu) => fields.extend([
+            text(pdu.message_id.as_bytes()),
+            text(pdu.source_addr.as_bytes()),
+            text(pdu.destination_addr.as_bytes()),
+        ]),
+        Pdu::ReplaceSm(pdu) => fields.extend([
+            text(pdu.message_id.as_bytes()),
+            text(pdu.source_addr.as_bytes()),
+        ]),
+        Pdu::BroadcastSm(pdu) => fields.extend([
+            text(pdu.message_id.as_bytes()),
+            text(pdu.source_addr.as_bytes()),
+        ]),
+        Pdu::BroadcastSmResp(pdu) => fields.push(text(pdu.message_id.as_bytes())),
+        Pdu::QueryBroadcastSm(pdu) => fields.extend([
+            text(pdu.message_id.as_bytes()),
+            text(pdu.source_addr.as_bytes()),
+        ]),
+        Pdu::QueryBroadcastSmResp(pdu) => fields.push(text(pdu.message_id.as_bytes())),
+        Pdu::CancelBroadcastSm(pdu) => fields.extend([
+            text(pdu.message_id.as_bytes()),
+            text(pdu.source_addr.as_bytes()),
+        ]),
+        Pdu::AlertNotification(pdu) => fields.extend([
+            text(pdu.source_addr.as_bytes()),
+            text(pdu.esme_addr.as_bytes()),
+        ]),
+        _ => {}
+    }
+
+    fields
+}
+
+fn text(bytes: &[u8]) -> Cow<'_, str> {
+    String::from_utf8_lossy(bytes)
+}
+
+fn payload(
+    esm_class: EsmClass,
+    data_coding: DataCoding,
+    bytes: &[u8],
+) -> Option<Cow<'static, str>> {
+    decode_payload(esm_class, data_coding, bytes).map(Cow::Owned)
+}
+
+/// `receipted_message_id` and the decoded `message_payload`
+fn tlvs(
+    tlvs: &[Tlv],
+    esm_class: EsmClass,
+    data_coding: DataCoding,
+) -> impl Iterator<Item = Cow<'_, str>> {
+    tlvs.iter().filter_map(move |tlv| match tlv.value() {
+        Some(TlvValue::ReceiptedMessageId(id)) => Some(text(id.as_bytes())),
+        Some(TlvValue::MessagePayload(value)) => payload(esm_class, data_coding, value.as_bytes()),
+        _ => None,
+    })
+}
@@ -0,0 +1,119 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    time::Duration,
+};
+
+use crate::{
+    correlation::{RESPONSE_TIMEOUT, is_response},
+    store::{Progress, Snapshot},
+    values::Event,
+};
+
+/// The other half of a request/response pair
+#[derive(Debug, Clone, Copy)]
+pub struct Link {
+    /// Id of the paired record
+    pub peer: u64,
+    pub is_request: bool,
+    /// Time between the request and the response
+    pub round_trip: Duration,
+}
+
+/// Links requests and responses both ways, from the [`Request`](crate::correlation::Request) of each response.
+///
+/// Also flags the sent requests that got no response within [`RESPONSE_TIMEOUT`] or before the session ended.
+#[derive(Default)]
+pub struct Pairing {
+    progress: Progress,
+    /// Id of the oldest record, links to older records are dropped
+    first: Option<u64>,
+    links: HashMap<u64, Link>,
+    /// Uptime of the sent requests still waiting for their response by id
+    waiting: BTreeMap<u64, Duration>,
+    unanswered: HashSet<u64>,
+}
+
+impl Pairing {
+    /// Processes the new records and flags requests that are waiting longer than [`RESPONSE_TIMEOUT`] at `uptime`.
+    pub fn update(&mut self, snapshot: &Snapshot, uptime: Duration) {
+        // Forget about evicted records.
+        let first = snapshot.first_id();
+
+        if first != self.first {
+            let first = first.unwrap_or(u64::MAX);
+
+            self.waiting.retain(|id, _| *id >= first);
+            self.unanswered.retain(|id| *id >= first);
+            self.links.retain(|id, _| *id >= first);
+        }
+
+        self.first = first;
+
+        for record in self.progress.advance(snapshot) {
+            if let Some(request) = record.request {
+                self.pair(request.id, record.id, request.round_trip);
+
+                continue;
+            }
+
+            match &record.event {
+                Event::Sent(command) if !is_response(command) => {
+                    self.waiting.insert(record.id, record.uptime);
+                }
+                Event::Connected | Event::Disconnected => self.expire(|_| true),
+                _ => {}
+            }
+        }
+
+        self.expire(|sent| uptime.saturating_sub(sent) > RESPONSE_TIMEOUT);
+    }
+
+    fn pair(&mut self, request: u64, response: u64, round_trip: Duration) {
+        self.waiting.remove(&request);
+
+        self.links.insert(
+            request,
+            Link {
+                peer: response,
+                is_request: true,
+                round_trip,
+            },
+        );
+
+        self.links.insert(
+            response,
+            Link {
+                peer: request,
+                is_request: false,
+                round_trip,
+            },
+        );
+    }
+
+    /// Stops waiting for the matching requests and flags them as unanswered.
+    fn expire(&mut self, mut expired: impl FnMut(Duration) -> bool) {
+        self.waiting.retain(|id, sent| {
+            if !expired(*sent) {
+                return true;
+            }
+
+            self.unanswered.insert(*id);
+
+            false
+        });
+    }
+
+    pub fn link(&self, id: u64) -> Option<Link> {
+        self.links.get(&id).copied()
+    }
+
+    /// Whether any sent request is still waiting for its response.
+    pub fn is_waiting(&self) -> bool {
+        !self.waiting.is_empty()
+    }
+
+    /// Whether the record is a sent request that got no response in time.
+    pub fn is_unanswered(&self, id: u64) -> bool {
+        self.unanswered.contains(&id)
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    collections::BTreeMap,
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use eframe::egui::{self, RichText};
+use rusmpp::{Pdu, pdus::SubmitSm, types::COctetString};
+use strum::VariantArray;
+
+use crate::{
+    actions::ActionsChannel,
+    colors::FUSION_RED,
+    result::{AppResult, AppUiError},
+    store::Snapshot,
+    values::Event,
+    widgets::ComboBox,
+};
+
+#[derive(VariantArray, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum Pacing {
+    #[default]
+    Original,
+    AsFastAsPossible,
+}
+
+impl ::core::convert::From<Pacing> for &'static str {
+    #[inline]
+    fn from(x: Pacing) -> &'static str {
+        match x {
+            Pacing::Original => "Original",
+            Pacing::AsFastAsPossible => "As Fast As Possible",
+        }
+    }
+}
+
+/// Re-sends the `SubmitSm`s sent in an imported log
+pub struct Replay {
+    actions: ActionsChannel,
+    pacing: Pacing,
+    /// Replacement by original destination address
+    rewrites: BTreeMap<String, String>,
+    count: usize,
+    loading: Arc<AtomicBool>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Replay {
+    pub fn new(actions: ActionsChannel, snapshot: &Snapshot) -> Self {
+        let rewrites = submit_sms(snapshot)
+            .map(|(_, sm)| {
+                let destination_addr = sm.destination_addr.to_string();
+
+                (destination_addr.clone(), destination_addr)
+            })
+            .collect();
+
+        Self {
+            actions,
+            pacing: Pacing::default(),
+            rewrites,
+            count: submit_sms(snapshot).count(),
+            loading: Arc::new(AtomicBool::new(false)),
+            cancel: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn validate_rewrites(&self) -> AppResult<()> {
+        self.rewrites.values().try_for_each(|rewrite| {
+            COctetString::<1, 21>::from_str(rewrite)
+                .map(|_| ())
+                .map_err(|_| AppUiError::invalid_destination_addr())
+        })
+    }
+
+    /// The messages to send, with their destination addresses rewritten
+    fn build_sms(&self, snapshot: &Snapshot) -> AppResult<Vec<(Duration, SubmitSm)>> {
+        let first = submit_sms(snapshot)
+            .next()
+            .map(|(uptime, _)| uptime)
+            .unwrap_or_default();
+
+        submit_sms(snapshot)
+            .map(|(uptime, sm)| {
+                let mut sm = sm.clone();
+
+                if let Some(rewrite) = self.rewrites.get(&sm.destination_addr.to_string()) {
+                    sm.destination_addr = COctetString::from_str(rewrite)
+                        .map_err(|_| AppUiError::invalid_destination_addr())?;
+                }
+
+                let at = match self.pacing {
+                    Pacing::Original => uptime.saturating_sub(first),
+                    Pacing::AsFastAsPossible => Duration::ZERO,
+                };
+
+                Ok((at, sm))
+            })
+            .collect()
+    }
+
+    fn on_replay_button_clicked(&self, snapshot: &Snapshot) {
+        if let Ok(sms) = self.build_sms(snapshot) {
+            self.cancel.store(false, Ordering::Relaxed);
+            self.actions
+                .replay(sms, self.loading.clone(), self.cancel.clone());
+        }
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui, snapshot: &Snapshot, bound: bool) {
+        let loading = self.loading.load(Ordering::Relaxed);
+        let valid = self.validate_rewrites();
+
+        egui::CollapsingHeader::new(format!("Replay {} SubmitSm", self.count))
+            .id_salt("logs_replay")
+            .show(ui, |ui| {
+                ui.add_enabled_ui(!loading, |ui| {
+                    egui::Grid::new("logs_replay_grid")
+                        .num_columns(2)
+                        .spacing([12.0, 6.0])
+                        .show(ui, |ui| {
+                            ui.label("Pacing");
+                            ui.add(
+                                ComboBox::new(
+                                    "logs_replay_pacing",
+                                    &mut self.pacing,
+                                    Pacing::VARIANTS,
+                                )
+                                .width(160.0),
+                            );
+                            ui.end_row();
+
+                            for (original, rewrite) in &mut self.rewrites {
+                                ui.label(original.as_str());
+                                ui.add(egui::TextEdit::singleline(rewrite).char_limit(20));
+                                ui.end_row();
+                            }
+                        });
+                });
+
+                if let Err(err) = &valid {
+                    ui.colored_label(FUSION_RED, err.display_message());
+                }
+
+                ui.horizontal(|ui| {
+                    let enabled = bound && !loading && self.count > 0 && valid.is_ok();
+
+                    let button = ui
+                        .add_enabled(enabled, egui::Button::new(RichText::new("Replay").strong()))
+                        .on_disabled_hover_text("Bind to a peer to replay");
+
+                    if button.clicked() {
+                        self.on_replay_button_clicked(snapshot);
+                    }
+
+                    if loading {
+                        ui.spinner();
+
+                        if ui.button("Cancel").clicked() {
+                            self.cancel.store(true, Ordering::Relaxed);
+                        }
+                    }
+                });
+            });
+    }
+}
+
+/// Sent `SubmitSm`s with their uptime
+fn submit_sms(snapshot: &Snapshot) -> impl Iterator<Item = (Duration, &SubmitSm)> {
+    snapshot.iter().filter_map(|record| match &record.event {
+        Event::Sent(command) => match command.pdu() {
+            Some(Pdu::SubmitSm(sm)) => Some((record.uptime, sm)),
+            _ => None,
+        },
+        _ => None,
+    })
+}
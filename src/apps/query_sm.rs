@@ -0,0 +1,332 @@
+use std::{
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use eframe::egui::{self, Color32, RichText};
+use parking_lot::Mutex;
+use rusmpp::{
+    Command, Pdu,
+    pdus::{QuerySm, QuerySmResp},
+    types::COctetString,
+    values::MessageState,
+};
+use serde::{Deserialize, Serialize};
+use strum::VariantArray;
+
+use crate::{
+    actions::{ActionsChannel, ResponseSlot},
+    apps::{response::response_ui, selection::Selection},
+    colors::{FUSION_RED, HIGH_BLUE, REPTILE_GREEN},
+    result::{AppResult, AppUiError},
+    values::{Npi, Ton},
+    widgets::ComboBox,
+};
+
+struct RusmppFields {
+    message_id: AppResult<COctetString<1, 65>>,
+    source_addr: AppResult<COctetString<1, 21>>,
+}
+
+impl RusmppFields {
+    fn new(message_id: &str, source_addr: &str) -> Self {
+        let mut fields = Self {
+            message_id: Err(AppUiError::invalid_message_id()),
+            source_addr: Err(AppUiError::invalid_source_addr()),
+        };
+
+        fields.set_message_id(message_id);
+        fields.set_source_addr(source_addr);
+
+        fields
+    }
+
+    fn set_message_id(&mut self, message_id: &str) {
+        self.message_id = COctetString::from_str(message_id)
+            .ok()
+            .filter(|_| !message_id.is_empty())
+            .ok_or_else(AppUiError::invalid_message_id);
+    }
+
+    fn set_source_addr(&mut self, source_addr: &str) {
+        self.source_addr =
+            COctetString::from_str(source_addr).map_err(|_| AppUiError::invalid_source_addr());
+    }
+
+    fn all_fields_valid(&self) -> bool {
+        matches!((&self.message_id, &self.source_addr), (Ok(_), Ok(_)))
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeQuerySmApp {
+    message_id: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+}
+
+pub struct QuerySmApp {
+    actions: ActionsChannel,
+    selection: Selection,
+    message_id: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+    fields: RusmppFields,
+    /// Response to the last query
+    response: ResponseSlot,
+    bound: bool,
+    loading: Arc<AtomicBool>,
+}
+
+impl QuerySmApp {
+    pub fn new_from_values(
+        actions: ActionsChannel,
+        selection: Selection,
+        message_id: String,
+        source_addr_ton: Ton,
+        source_addr_npi: Npi,
+        source_addr: String,
+    ) -> Self {
+        let fields = RusmppFields::new(&message_id, &source_addr);
+
+        Self {
+            actions,
+            selection,
+            message_id,
+            source_addr_ton,
+            source_addr_npi,
+            source_addr,
+            fields,
+            response: Arc::new(Mutex::new(None)),
+            bound: false,
+            loading: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn new_default(actions: ActionsChannel, selection: Selection) -> Self {
+        Self::new_from_values(
+            actions,
+            selection,
+            String::new(),
+            Ton::default(),
+            Npi::default(),
+            String::new(),
+        )
+    }
+
+    pub fn from_serde(
+        actions: ActionsChannel,
+        selection: Selection,
+        serde_app: SerdeQuerySmApp,
+    ) -> Self {
+        Self::new_from_values(
+            actions,
+            selection,
+            serde_app.message_id,
+            serde_app.source_addr_ton,
+            serde_app.source_addr_npi,
+            serde_app.source_addr,
+        )
+    }
+
+    pub fn to_serde(&self) -> SerdeQuerySmApp {
+        SerdeQuerySmApp {
+            message_id: self.message_id.clone(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.clone(),
+        }
+    }
+
+    pub fn set_bound(&mut self, bound: bool) {
+        self.bound = bound;
+    }
+
+    fn update_message_id(&mut self) {
+        self.message_id.retain(|c| c.is_ascii());
+        self.fields.set_message_id(&self.message_id);
+    }
+
+    fn update_source_addr(&mut self) {
+        self.source_addr.retain(|c| c.is_ascii());
+        self.fields.set_source_addr(&self.source_addr);
+    }
+
+    fn build_query_sm(&self) -> AppResult<QuerySm> {
+        Ok(QuerySm::new(
+            self.fields.message_id.clone()?,
+            self.source_addr_ton.into(),
+            self.source_addr_npi.into(),
+            self.fields.source_addr.clone()?,
+        ))
+    }
+
+    fn on_prefill_button_clicked(&mut self) {
+        let Some(target) = self.selection.get() else {
+            return;
+        };
+
+        self.message_id = target.message_id;
+        self.source_addr_ton = target.source_addr_ton;
+        self.source_addr_npi = target.source_addr_npi;
+        self.source_addr = target.source_addr;
+
+        self.update_message_id();
+        self.update_source_addr();
+    }
+
+    fn on_query_button_clicked(&mut self) {
+        if let Ok(query_sm) = self.build_query_sm() {
+            *self.response.lock() = None;
+
+            self.actions
+                .query_sm(query_sm, self.response.clone(), self.loading.clone());
+        }
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        let loading = self.loading.load(Ordering::Relaxed);
+
+        ui.vertical_centered(|ui| {
+            let display_err = |ui: &mut egui::Ui, err: &AppUiError| {
+                ui.allocate_space(egui::vec2(0.0, 0.0));
+                ui.colored_label(FUSION_RED, err.display_message());
+                ui.end_row();
+            };
+
+            egui::Grid::new("query_sm_grid")
+                .num_columns(2)
+                .spacing([12.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.label("Message ID");
+                    ui.horizontal(|ui| {
+                        ui.add(egui::TextEdit::singleline(&mut self.message_id).char_limit(64))
+                            .on_hover_text("Max 64 ASCII characters")
+                            .changed()
+                            .then(|| self.update_message_id());
+
+                        ui.add_enabled(self.selection.is_some(), egui::Button::new("From Logs"))
+                            .on_hover_text("Use the message selected in the logs")
+                            .on_disabled_hover_text("Select a SubmitSmResp in the logs first")
+                            .clicked()
+                            .then(|| self.on_prefill_button_clicked());
+                    });
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.message_id {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Source Address TON");
+                    ui.add(ComboBox::new(
+                        "query_sm_source_addr_ton",
+                        &mut self.source_addr_ton,
+                        Ton::VARIANTS,
+                    ));
+                    ui.end_row();
+
+                    ui.label("Source Address NPI");
+                    ui.add(ComboBox::new(
+                        "query_sm_source_addr_npi",
+                        &mut self.source_addr_npi,
+                        Npi::VARIANTS,
+                    ));
+                    ui.end_row();
+
+                    ui.label("Source Address");
+                    ui.add(egui::TextEdit::singleline(&mut self.source_addr).char_limit(20))
+                        .on_hover_text("Max 20 ASCII characters")
+                        .changed()
+                        .then(|| self.update_source_addr());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.source_addr {
+                        display_err(ui, err);
+                    }
+                });
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.add_enabled_ui(
+                        self.bound && !loading && self.fields.all_fields_valid(),
+                        |ui| {
+                            ui.add_sized(
+                                [140.0, 32.0],
+                                egui::Button::new(
+                                    RichText::new("Query SMS").color(Color32::WHITE).strong(),
+                                )
+                                .fill(HIGH_BLUE),
+                            )
+                            .clicked()
+                            .then(|| {
+                                self.on_query_button_clicked();
+                            });
+                        },
+                    );
+
+                    if loading {
+                        ui.spinner();
+                    }
+                });
+            });
+
+            if let Some(response) = self.response.lock().as_ref() {
+                ui.add_space(12.0);
+
+                if let Some(Pdu::QuerySmResp(query_sm_resp)) =
+                    response_ui(ui, response).and_then(Command::pdu)
+                {
+                    query_sm_resp_ui(ui, query_sm_resp);
+                }
+            }
+        })
+        .response
+    }
+}
+
+fn query_sm_resp_ui(ui: &mut egui::Ui, query_sm_resp: &QuerySmResp) {
+    let final_date = query_sm_resp.final_date.to_string();
+
+    let state = RichText::new(format!("{:?}", query_sm_resp.message_state));
+    let state = match query_sm_resp.message_state {
+        MessageState::Delivered => state.color(REPTILE_GREEN),
+        MessageState::Scheduled | MessageState::Enroute | MessageState::Accepted => state,
+        _ => state.color(FUSION_RED),
+    };
+
+    egui::Grid::new("query_sm_resp_grid")
+        .num_columns(2)
+        .spacing([12.0, 8.0])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("Message ID");
+            ui.label(query_sm_resp.message_id.to_string());
+            ui.end_row();
+
+            ui.label("Message State");
+            ui.label(state);
+            ui.end_row();
+
+            ui.label("Final Date");
+            ui.label(if final_date.is_empty() {
+                "-"
+            } else {
+                &final_date
+            });
+            ui.end_row();
+
+            ui.label("Error Code");
+            ui.label(query_sm_resp.error_code.to_string());
+            ui.end_row();
+        });
+}
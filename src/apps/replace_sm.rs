@@ -0,0 +1,558 @@
+use std::{
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use eframe::egui::{self, Color32, RichText, Separator};
+use parking_lot::Mutex;
+use rusmpp::{
+    pdus::ReplaceSm,
+    types::{COctetString, EmptyOrFullCOctetString, OctetString},
+    values::RegisteredDelivery,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    actions::{ActionsChannel, ResponseSlot},
+    apps::{
+        response::response_ui,
+        selection::{MessageTarget, Selection},
+        submit_sm::encoder::Encoder,
+    },
+    colors::{FUSION_RED, HIGH_BLUE, REPTILE_GREEN},
+    result::{AppResult, AppUiError},
+    values::{DataCoding, Npi, Ton},
+    widgets::{AddressInput, ComboBox},
+};
+
+/// Largest short message a `ReplaceSm` can carry
+const MAX_SHORT_MESSAGE_SIZE: usize = 255;
+
+struct RusmppFields {
+    message_id: AppResult<COctetString<1, 65>>,
+    source_addr: AppResult<COctetString<1, 21>>,
+    schedule_delivery_time: AppResult<EmptyOrFullCOctetString<17>>,
+    validity_period: AppResult<EmptyOrFullCOctetString<17>>,
+    registered_delivery: AppResult<u8>,
+    sm_default_msg_id: AppResult<u8>,
+    short_message: AppResult<OctetString<0, 255>>,
+}
+
+impl RusmppFields {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        message_id: &str,
+        source_addr: &str,
+        schedule_delivery_time: &str,
+        validity_period: &str,
+        registered_delivery: &str,
+        sm_default_msg_id: &str,
+        data_coding: DataCoding,
+        short_message: &str,
+    ) -> Self {
+        let mut fields = Self {
+            message_id: Err(AppUiError::invalid_message_id()),
+            source_addr: COctetString::from_str(source_addr)
+                .map_err(|_| AppUiError::invalid_source_addr()),
+            schedule_delivery_time: EmptyOrFullCOctetString::from_str(schedule_delivery_time)
+                .map_err(|_| AppUiError::invalid_schedule_delivery_time()),
+            validity_period: EmptyOrFullCOctetString::from_str(validity_period)
+                .map_err(|_| AppUiError::invalid_validity_period()),
+            registered_delivery: registered_delivery
+                .parse::<u8>()
+                .map_err(|_| AppUiError::invalid_registered_delivery()),
+            sm_default_msg_id: sm_default_msg_id
+                .parse::<u8>()
+                .map_err(|_| AppUiError::invalid_sm_default_msg_id()),
+            short_message: Err(AppUiError::invalid_short_message()),
+        };
+
+        fields.set_message_id(message_id);
+        fields.set_short_message(data_coding, short_message);
+
+        fields
+    }
+
+    fn set_message_id(&mut self, message_id: &str) {
+        self.message_id = COctetString::from_str(message_id)
+            .ok()
+            .filter(|_| !message_id.is_empty())
+            .ok_or_else(AppUiError::invalid_message_id);
+    }
+
+    fn set_source_addr(&mut self, source_addr: &str) {
+        self.source_addr =
+            COctetString::from_str(source_addr).map_err(|_| AppUiError::invalid_source_addr());
+    }
+
+    fn set_schedule_delivery_time(&mut self, schedule_delivery_time: &str) {
+        self.schedule_delivery_time = EmptyOrFullCOctetString::from_str(schedule_delivery_time)
+            .map_err(|_| AppUiError::invalid_schedule_delivery_time());
+    }
+
+    fn set_validity_period(&mut self, validity_period: &str) {
+        self.validity_period = EmptyOrFullCOctetString::from_str(validity_period)
+            .map_err(|_| AppUiError::invalid_validity_period());
+    }
+
+    fn set_registered_delivery(&mut self, registered_delivery: &str) {
+        self.registered_delivery = registered_delivery
+            .parse::<u8>()
+            .map_err(|_| AppUiError::invalid_registered_delivery());
+    }
+
+    fn set_sm_default_msg_id(&mut self, sm_default_msg_id: &str) {
+        self.sm_default_msg_id = sm_default_msg_id
+            .parse::<u8>()
+            .map_err(|_| AppUiError::invalid_sm_default_msg_id());
+    }
+
+    /// The message is replaced in place, so it must fit in a single short message.
+    fn set_short_message(&mut self, data_coding: DataCoding, short_message: &str) {
+        self.short_message = Encoder::new(data_coding)
+            .encode_single(short_message, MAX_SHORT_MESSAGE_SIZE)
+            .map_err(AppUiError::MultiPart)
+            .and_then(|bytes| {
+                OctetString::new(bytes).map_err(|_| AppUiError::invalid_short_message())
+            });
+    }
+
+    fn all_fields_valid(&self) -> bool {
+        matches!(
+            (
+                &self.message_id,
+                &self.source_addr,
+                &self.schedule_delivery_time,
+                &self.validity_period,
+                &self.registered_delivery,
+                &self.sm_default_msg_id,
+                &self.short_message,
+            ),
+            (Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_))
+        )
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeReplaceSmApp {
+    message_id: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+    schedule_delivery_time: String,
+    validity_period: String,
+    registered_delivery: String,
+    sm_default_msg_id: String,
+    data_coding: DataCoding,
+    short_message: String,
+}
+
+pub struct ReplaceSmApp {
+    actions: ActionsChannel,
+    selection: Selection,
+    message_id: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+    schedule_delivery_time: String,
+    validity_period: String,
+    registered_delivery: String,
+    sm_default_msg_id: String,
+    data_coding: DataCoding,
+    short_message: String,
+    fields: RusmppFields,
+    /// Response to the last replace
+    response: ResponseSlot,
+    bound: bool,
+    loading: Arc<AtomicBool>,
+}
+
+impl ReplaceSmApp {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_from_values(
+        actions: ActionsChannel,
+        selection: Selection,
+        message_id: String,
+        source_addr_ton: Ton,
+        source_addr_npi: Npi,
+        source_addr: String,
+        schedule_delivery_time: String,
+        validity_period: String,
+        registered_delivery: String,
+        sm_default_msg_id: String,
+        data_coding: DataCoding,
+        short_message: String,
+    ) -> Self {
+        let fields = RusmppFields::new(
+            &message_id,
+            &source_addr,
+            &schedule_delivery_time,
+            &validity_period,
+            &registered_delivery,
+            &sm_default_msg_id,
+            data_coding,
+            &short_message,
+        );
+
+        Self {
+            actions,
+            selection,
+            message_id,
+            source_addr_ton,
+            source_addr_npi,
+            source_addr,
+            schedule_delivery_time,
+            validity_period,
+            registered_delivery,
+            sm_default_msg_id,
+            data_coding,
+            short_message,
+            fields,
+            response: Arc::new(Mutex::new(None)),
+            bound: false,
+            loading: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn new_default(actions: ActionsChannel, selection: Selection) -> Self {
+        Self::new_from_values(
+            actions,
+            selection,
+            String::new(),
+            Ton::default(),
+            Npi::default(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::from("0"),
+            String::from("0"),
+            DataCoding::default(),
+            String::from("Hello again Rusmppc!"),
+        )
+    }
+
+    pub fn from_serde(
+        actions: ActionsChannel,
+        selection: Selection,
+        serde_app: SerdeReplaceSmApp,
+    ) -> Self {
+        Self::new_from_values(
+            actions,
+            selection,
+            serde_app.message_id,
+            serde_app.source_addr_ton,
+            serde_app.source_addr_npi,
+            serde_app.source_addr,
+            serde_app.schedule_delivery_time,
+            serde_app.validity_period,
+            serde_app.registered_delivery,
+            serde_app.sm_default_msg_id,
+            serde_app.data_coding,
+            serde_app.short_message,
+        )
+    }
+
+    pub fn to_serde(&self) -> SerdeReplaceSmApp {
+        SerdeReplaceSmApp {
+            message_id: self.message_id.clone(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.clone(),
+            schedule_delivery_time: self.schedule_delivery_time.clone(),
+            validity_period: self.validity_period.clone(),
+            registered_delivery: self.registered_delivery.clone(),
+            sm_default_msg_id: self.sm_default_msg_id.clone(),
+            data_coding: self.data_coding,
+            short_message: self.short_message.clone(),
+        }
+    }
+
+    pub fn set_bound(&mut self, bound: bool) {
+        self.bound = bound;
+    }
+
+    fn update_message_id(&mut self) {
+        self.message_id.retain(|c| c.is_ascii());
+        self.fields.set_message_id(&self.message_id);
+    }
+
+    fn update_source_addr(&mut self) {
+        self.source_addr.retain(|c| c.is_ascii());
+        self.fields.set_source_addr(&self.source_addr);
+    }
+
+    fn update_schedule_delivery_time(&mut self) {
+        self.schedule_delivery_time.retain(|c| c.is_ascii());
+        self.fields
+            .set_schedule_delivery_time(&self.schedule_delivery_time);
+    }
+
+    fn update_validity_period(&mut self) {
+        self.validity_period.retain(|c| c.is_ascii());
+        self.fields.set_validity_period(&self.validity_period);
+    }
+
+    fn update_registered_delivery(&mut self) {
+        self.registered_delivery.retain(|c| c.is_ascii_digit());
+        self.fields
+            .set_registered_delivery(&self.registered_delivery);
+    }
+
+    fn update_sm_default_msg_id(&mut self) {
+        self.sm_default_msg_id.retain(|c| c.is_ascii_digit());
+        self.fields.set_sm_default_msg_id(&self.sm_default_msg_id);
+    }
+
+    fn update_short_message(&mut self) {
+        self.fields
+            .set_short_message(self.data_coding, &self.short_message);
+    }
+
+    fn set_target(&mut self, target: MessageTarget) {
+        self.message_id = target.message_id;
+        self.source_addr_ton = target.source_addr_ton;
+        self.source_addr_npi = target.source_addr_npi;
+        self.source_addr = target.source_addr;
+
+        self.update_message_id();
+        self.update_source_addr();
+    }
+
+    fn build_replace_sm(&self) -> AppResult<ReplaceSm> {
+        let replace_sm = ReplaceSm::builder()
+            .message_id(self.fields.message_id.clone()?)
+            .source_addr_ton(self.source_addr_ton.into())
+            .source_addr_npi(self.source_addr_npi.into())
+            .source_addr(self.fields.source_addr.clone()?)
+            .schedule_delivery_time(self.fields.schedule_delivery_time.clone()?)
+            .validity_period(self.fields.validity_period.clone()?)
+            .registered_delivery(RegisteredDelivery::from(
+                self.fields.registered_delivery.clone()?,
+            ))
+            .sm_default_msg_id(self.fields.sm_default_msg_id.clone()?)
+            .short_message(self.fields.short_message.clone()?)
+            .build();
+
+        Ok(replace_sm)
+    }
+
+    fn on_replace_button_clicked(&mut self) {
+        if let Ok(replace_sm) = self.build_replace_sm() {
+            *self.response.lock() = None;
+
+            self.actions
+                .replace_sm(replace_sm, self.response.clone(), self.loading.clone());
+        }
+    }
+
+    fn counters_message(&self) -> Option<String> {
+        let byte_count = self.fields.short_message.as_ref().ok()?.len();
+        let char_count = self.short_message.chars().count();
+
+        Some(format!(
+            "{char_count} Characters {byte_count}/{MAX_SHORT_MESSAGE_SIZE} Bytes"
+        ))
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        let loading = self.loading.load(Ordering::Relaxed);
+
+        ui.vertical_centered(|ui| {
+            let display_err = |ui: &mut egui::Ui, err: &AppUiError| {
+                ui.allocate_space(egui::vec2(0.0, 0.0));
+                ui.colored_label(FUSION_RED, err.display_message());
+                ui.end_row();
+            };
+
+            let mut target = None;
+
+            egui::Grid::new("replace_sm_grid")
+                .num_columns(2)
+                .spacing([12.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.label("Message ID");
+                    ui.horizontal(|ui| {
+                        ui.add(egui::TextEdit::singleline(&mut self.message_id).char_limit(64))
+                            .on_hover_text("Max 64 ASCII characters")
+                            .changed()
+                            .then(|| self.update_message_id());
+
+                        ui.add_enabled(self.selection.is_some(), egui::Button::new("From Logs"))
+                            .on_hover_text("Use the message selected in the logs")
+                            .on_disabled_hover_text("Select a SubmitSmResp in the logs first")
+                            .clicked()
+                            .then(|| target = self.selection.get());
+                    });
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.message_id {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Schedule Delivery Time");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.schedule_delivery_time).char_limit(16),
+                    )
+                    .on_hover_text("Empty to keep the original, or YYMMDDhhmmsstnnp")
+                    .changed()
+                    .then(|| self.update_schedule_delivery_time());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.schedule_delivery_time {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Validity Period");
+                    ui.add(egui::TextEdit::singleline(&mut self.validity_period).char_limit(16))
+                        .on_hover_text("Empty to keep the original, or YYMMDDhhmmsstnnp")
+                        .changed()
+                        .then(|| self.update_validity_period());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.validity_period {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("Registered Delivery");
+                    ui.add(egui::TextEdit::singleline(&mut self.registered_delivery).char_limit(3))
+                        .on_hover_text("Unsigned 8-bit integer")
+                        .changed()
+                        .then(|| self.update_registered_delivery());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.registered_delivery {
+                        display_err(ui, err);
+                    }
+
+                    ui.label("SM Default Msg ID");
+                    ui.add(egui::TextEdit::singleline(&mut self.sm_default_msg_id).char_limit(3))
+                        .on_hover_text("Unsigned 8-bit integer")
+                        .changed()
+                        .then(|| self.update_sm_default_msg_id());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.sm_default_msg_id {
+                        display_err(ui, err);
+                    }
+                });
+
+            if let Some(target) = target {
+                self.set_target(target);
+            }
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.add_space(12.0);
+
+            egui::Grid::new("replace_sm_addr_grid")
+                .num_columns(6)
+                .spacing([16.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.add(AddressInput::new(
+                        "replace_sm_source_addr",
+                        "Source Address",
+                        &mut self.source_addr_ton,
+                        &mut self.source_addr_npi,
+                        &mut self.source_addr,
+                    ))
+                    .changed()
+                    .then(|| self.update_source_addr());
+
+                    ui.end_row();
+                });
+
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.heading("Short Message");
+                ui.add(Separator::default().horizontal().spacing(8.0));
+            });
+
+            ui.add_space(12.0);
+
+            ui.vertical(|ui| {
+                egui::Grid::new("replace_sm_data_coding_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 10.0])
+                    .striped(false)
+                    .show(ui, |ui| {
+                        ui.label("Data Coding")
+                            .on_hover_text("Must match the data coding of the original message");
+                        ui.add(ComboBox::new(
+                            "replace_sm_data_coding",
+                            &mut self.data_coding,
+                            DataCoding::FIXED,
+                        ))
+                        .changed()
+                        .then(|| self.update_short_message());
+
+                        ui.end_row();
+                    });
+
+                ui.add_space(10.0);
+
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.short_message)
+                        .desired_width(ui.available_width()),
+                )
+                .changed()
+                .then(|| self.update_short_message());
+
+                ui.add_space(10.0);
+
+                match (&self.fields.short_message, self.counters_message()) {
+                    (Err(err), _) => {
+                        ui.colored_label(FUSION_RED, err.display_message());
+                    }
+                    (Ok(_), Some(counters_message)) => {
+                        ui.label(counters_message);
+                    }
+                    (Ok(_), None) => {}
+                }
+            });
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.add_enabled_ui(
+                        self.bound && !loading && self.fields.all_fields_valid(),
+                        |ui| {
+                            ui.add_sized(
+                                [140.0, 32.0],
+                                egui::Button::new(
+                                    RichText::new("Replace SMS").color(Color32::WHITE).strong(),
+                                )
+                                .fill(HIGH_BLUE),
+                            )
+                            .clicked()
+                            .then(|| {
+                                self.on_replace_button_clicked();
+                            });
+                        },
+                    );
+
+                    if loading {
+                        ui.spinner();
+                    }
+                });
+            });
+
+            if let Some(response) = self.response.lock().as_ref() {
+                ui.add_space(12.0);
+
+                if response_ui(ui, response).is_some() {
+                    ui.colored_label(REPTILE_GREEN, "Replaced");
+                }
+            }
+        })
+        .response
+    }
+}
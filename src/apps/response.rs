@@ -0,0 +1,27 @@
+use eframe::egui;
+use rusmpp::{Command, CommandStatus};
+
+use crate::colors::FUSION_RED;
+
+/// Shows why a request failed, returns the response if it was successful.
+pub fn response_ui<'a>(
+    ui: &mut egui::Ui,
+    response: &'a Result<Command, String>,
+) -> Option<&'a Command> {
+    let command = match response {
+        Ok(command) => command,
+        Err(err) => {
+            ui.colored_label(FUSION_RED, err);
+
+            return None;
+        }
+    };
+
+    if command.status() != CommandStatus::EsmeRok {
+        ui.colored_label(FUSION_RED, format!("{:?}", command.status()));
+
+        return None;
+    }
+
+    Some(command)
+}
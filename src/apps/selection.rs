@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use rusmpp::pdus::SubmitSm;
+
+use crate::values::{Npi, Ton};
+
+/// A submitted message, identified by its `message_id` and source address
+#[derive(Clone, Default)]
+pub struct MessageTarget {
+    pub message_id: String,
+    pub source_addr_ton: Ton,
+    pub source_addr_npi: Npi,
+    pub source_addr: String,
+    pub dest_addr_ton: Ton,
+    pub dest_addr_npi: Npi,
+    pub destination_addr: String,
+}
+
+impl MessageTarget {
+    /// Target of a sent `SubmitSm`, until its `SubmitSmResp` provides the `message_id`.
+    pub fn from_submit_sm(submit_sm: &SubmitSm) -> Self {
+        Self {
+            message_id: String::new(),
+            source_addr_ton: submit_sm.source_addr_ton.into(),
+            source_addr_npi: submit_sm.source_addr_npi.into(),
+            source_addr: submit_sm.source_addr.to_string(),
+            dest_addr_ton: submit_sm.dest_addr_ton.into(),
+            dest_addr_npi: submit_sm.dest_addr_npi.into(),
+            destination_addr: submit_sm.destination_addr.to_string(),
+        }
+    }
+}
+
+/// Message selected in the logs, shared with the tabs that operate on submitted messages
+#[derive(Clone, Default)]
+pub struct Selection {
+    target: Arc<RwLock<Option<MessageTarget>>>,
+}
+
+impl Selection {
+    pub fn set(&self, target: MessageTarget) {
+        *self.target.write() = Some(target);
+    }
+
+    pub fn get(&self) -> Option<MessageTarget> {
+        self.target.read().clone()
+    }
+
+    pub fn is_some(&self) -> bool {
+        self.target.read().is_some()
+    }
+}
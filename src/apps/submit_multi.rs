@@ -0,0 +1,689 @@
+use std::{
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use eframe::egui::{self, Color32, RichText, Separator};
+use parking_lot::Mutex;
+use rusmpp::{
+    CommandStatus, Pdu,
+    pdus::SubmitMulti,
+    types::COctetString,
+    values::{DestAddress, DistributionListName, ServiceType, SmeAddress, UnsuccessSme},
+};
+use serde::{Deserialize, Serialize};
+use strum::VariantArray;
+
+use crate::{
+    actions::{ActionsChannel, ResponsesSlot},
+    apps::{
+        response::response_ui,
+        submit_sm::encoder::{Encoder, Parts, Reference},
+    },
+    colors::{FUSION_RED, HIGH_BLUE, REPTILE_GREEN},
+    result::{AppResult, AppUiError, MultiPartError},
+    values::{DataCoding, DestFlag, EsmClass, GsmFeatures, Npi, Ton},
+    widgets::{AddressInput, ComboBox},
+};
+
+/// Most destinations a `SubmitMulti` can carry
+const MAX_DESTINATIONS: usize = 254;
+
+/// An SME address or a distribution list name, as edited in the destinations list
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Destination {
+    flag: DestFlag,
+    ton: Ton,
+    npi: Npi,
+    address: String,
+}
+
+impl Destination {
+    fn dest_address(&self) -> AppResult<DestAddress> {
+        match self.flag {
+            DestFlag::SmeAddress => COctetString::from_str(&self.address)
+                .map(|address| {
+                    DestAddress::SmeAddress(SmeAddress::new(
+                        self.ton.into(),
+                        self.npi.into(),
+                        address,
+                    ))
+                })
+                .map_err(|_| AppUiError::invalid_destination_addr()),
+            DestFlag::DistributionList => COctetString::from_str(&self.address)
+                .ok()
+                .filter(|_| !self.address.is_empty())
+                .map(|name| DestAddress::DistributionListName(DistributionListName::new(name)))
+                .ok_or_else(AppUiError::invalid_dl_name),
+        }
+    }
+
+    fn matches(&self, unsuccess_sme: &UnsuccessSme) -> bool {
+        self.flag == DestFlag::SmeAddress
+            && Ton::from(unsuccess_sme.dest_addr_ton) == self.ton
+            && Npi::from(unsuccess_sme.dest_addr_npi) == self.npi
+            && unsuccess_sme.destination_addr.to_string() == self.address
+    }
+}
+
+struct RusmppFields {
+    service_type: AppResult<COctetString<1, 6>>,
+    source_addr: AppResult<COctetString<1, 21>>,
+    number_of_dests: AppResult<usize>,
+    dest_address: Vec<AppResult<DestAddress>>,
+    parts: AppResult<Parts>,
+}
+
+impl RusmppFields {
+    fn new(
+        service_type: &str,
+        source_addr: &str,
+        destinations: &[Destination],
+        encoder: Encoder,
+        short_message: &str,
+    ) -> Self {
+        let mut fields = Self {
+            service_type: COctetString::from_str(service_type)
+                .map_err(|_| AppUiError::invalid_service_type()),
+            source_addr: COctetString::from_str(source_addr)
+                .map_err(|_| AppUiError::invalid_source_addr()),
+            number_of_dests: Err(AppUiError::invalid_number_of_dests()),
+            dest_address: Vec::new(),
+            parts: Err(AppUiError::MultiPart(MultiPartError::EmptyMessage)),
+        };
+
+        fields.set_destinations(destinations);
+        fields.set_short_message(encoder, short_message);
+
+        fields
+    }
+
+    fn set_service_type(&mut self, service_type: &str) {
+        self.service_type =
+            COctetString::from_str(service_type).map_err(|_| AppUiError::invalid_service_type());
+    }
+
+    fn set_source_addr(&mut self, source_addr: &str) {
+        self.source_addr =
+            COctetString::from_str(source_addr).map_err(|_| AppUiError::invalid_source_addr());
+    }
+
+    fn set_destinations(&mut self, destinations: &[Destination]) {
+        self.number_of_dests = Some(destinations.len())
+            .filter(|count| (1..=MAX_DESTINATIONS).contains(count))
+            .ok_or_else(AppUiError::invalid_number_of_dests);
+
+        self.dest_address = destinations.iter().map(Destination::dest_address).collect();
+    }
+
+    /// The reference of the concatenation UDH is picked when submitting, the parts are only counted here.
+    fn set_short_message(&mut self, encoder: Encoder, short_message: &str) {
+        self.parts = encoder
+            .split(short_message, Reference::U8(0))
+            .map_err(AppUiError::MultiPart);
+    }
+
+    fn all_fields_valid(&self) -> bool {
+        matches!(
+            (
+                &self.service_type,
+                &self.source_addr,
+                &self.number_of_dests,
+                &self.parts,
+            ),
+            (Ok(_), Ok(_), Ok(_), Ok(_))
+        ) && self.dest_address.iter().all(Result::is_ok)
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SerdeSubmitMultiApp {
+    service_type: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+    destinations: Vec<Destination>,
+    data_coding: DataCoding,
+    short_message: String,
+}
+
+pub struct SubmitMultiApp {
+    actions: ActionsChannel,
+    service_type: String,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: String,
+    destinations: Vec<Destination>,
+    data_coding: DataCoding,
+    short_message: String,
+    fields: RusmppFields,
+    reference: u8,
+    /// Destinations and number of parts of the last submit, the list may have been edited since
+    sent: Vec<Destination>,
+    sent_parts: usize,
+    /// Responses to the parts of the last submit
+    responses: ResponsesSlot,
+    bound: bool,
+    loading: Arc<AtomicBool>,
+}
+
+impl SubmitMultiApp {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_from_values(
+        actions: ActionsChannel,
+        service_type: String,
+        source_addr_ton: Ton,
+        source_addr_npi: Npi,
+        source_addr: String,
+        destinations: Vec<Destination>,
+        data_coding: DataCoding,
+        short_message: String,
+    ) -> Self {
+        let fields = RusmppFields::new(
+            &service_type,
+            &source_addr,
+            &destinations,
+            Encoder::new(data_coding),
+            &short_message,
+        );
+
+        Self {
+            actions,
+            service_type,
+            source_addr_ton,
+            source_addr_npi,
+            source_addr,
+            destinations,
+            data_coding,
+            short_message,
+            fields,
+            reference: 0,
+            sent: Vec::new(),
+            sent_parts: 0,
+            responses: Arc::new(Mutex::new(Vec::new())),
+            bound: false,
+            loading: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn new_default(actions: ActionsChannel) -> Self {
+        Self::new_from_values(
+            actions,
+            String::new(),
+            Ton::default(),
+            Npi::default(),
+            String::new(),
+            vec![Destination::default()],
+            DataCoding::default(),
+            String::from("Hello everyone from Rusmppc!"),
+        )
+    }
+
+    pub fn from_serde(actions: ActionsChannel, serde_app: SerdeSubmitMultiApp) -> Self {
+        Self::new_from_values(
+            actions,
+            serde_app.service_type,
+            serde_app.source_addr_ton,
+            serde_app.source_addr_npi,
+            serde_app.source_addr,
+            serde_app.destinations,
+            serde_app.data_coding,
+            serde_app.short_message,
+        )
+    }
+
+    pub fn to_serde(&self) -> SerdeSubmitMultiApp {
+        SerdeSubmitMultiApp {
+            service_type: self.service_type.clone(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.clone(),
+            destinations: self.destinations.clone(),
+            data_coding: self.data_coding,
+            short_message: self.short_message.clone(),
+        }
+    }
+
+    pub fn set_bound(&mut self, bound: bool) {
+        self.bound = bound;
+    }
+
+    pub fn encoder(&self) -> Encoder {
+        Encoder::new(self.data_coding)
+    }
+
+    fn update_service_type(&mut self) {
+        self.service_type.retain(|c| c.is_ascii());
+        self.fields.set_service_type(&self.service_type);
+    }
+
+    fn update_source_addr(&mut self) {
+        self.source_addr.retain(|c| c.is_ascii());
+        self.fields.set_source_addr(&self.source_addr);
+    }
+
+    fn update_destinations(&mut self) {
+        for destination in &mut self.destinations {
+            destination.address.retain(|c| c.is_ascii());
+        }
+
+        self.fields.set_destinations(&self.destinations);
+    }
+
+    fn update_short_message(&mut self) {
+        self.fields
+            .set_short_message(self.encoder(), &self.short_message);
+    }
+
+    fn increment_reference(&mut self) {
+        self.reference = self.reference.wrapping_add(1);
+    }
+
+    fn build_submit_multis(&self) -> AppResult<Vec<SubmitMulti>> {
+        let service_type = ServiceType::new(self.fields.service_type.clone()?);
+        let source_addr = self.fields.source_addr.clone()?;
+        let dest_address = self
+            .fields
+            .dest_address
+            .iter()
+            .cloned()
+            .collect::<AppResult<Vec<_>>>()?;
+
+        let parts = self
+            .encoder()
+            .split(&self.short_message, Reference::U8(self.reference))
+            .map_err(AppUiError::MultiPart)?;
+
+        let esm_class = if parts.udhi {
+            EsmClass {
+                gsm_features: GsmFeatures::UdhiIndicator,
+                ..EsmClass::default()
+            }
+        } else {
+            EsmClass::default()
+        };
+
+        let sms = parts
+            .short_messages
+            .into_iter()
+            .map(|short_message| {
+                SubmitMulti::builder()
+                    .service_type(service_type.clone())
+                    .source_addr_ton(self.source_addr_ton.into())
+                    .source_addr_npi(self.source_addr_npi.into())
+                    .source_addr(source_addr.clone())
+                    .dest_address(dest_address.clone())
+                    .esm_class(esm_class.into())
+                    .data_coding(parts.data_coding)
+                    .short_message(short_message)
+                    .build()
+            })
+            .collect();
+
+        Ok(sms)
+    }
+
+    fn on_submit_button_clicked(&mut self) {
+        if let Ok(sms) = self.build_submit_multis() {
+            self.sent = self.destinations.clone();
+            self.sent_parts = sms.len();
+            self.responses.lock().clear();
+
+            self.actions
+                .submit_multi(sms, self.responses.clone(), self.loading.clone());
+            self.increment_reference();
+        }
+    }
+
+    fn counters_message(&self) -> Option<String> {
+        let parts = self.fields.parts.as_ref().ok()?;
+        let byte_count: usize = parts.short_messages.iter().map(|sm| sm.len()).sum();
+        let char_count = self.short_message.chars().count();
+
+        Some(format!(
+            "{} SMS {char_count} Characters {byte_count} Bytes",
+            parts.short_messages.len()
+        ))
+    }
+
+    fn destinations_ui(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        let mut remove = None;
+
+        egui::Grid::new("submit_multi_destinations_grid")
+            .num_columns(8)
+            .spacing([16.0, 10.0])
+            .striped(false)
+            .show(ui, |ui| {
+                let can_remove = self.destinations.len() > 1;
+
+                for (index, destination) in self.destinations.iter_mut().enumerate() {
+                    let flag_id = format!("submit_multi_dest_flag_{index}");
+
+                    changed |= ui
+                        .add(
+                            ComboBox::new(&flag_id, &mut destination.flag, DestFlag::VARIANTS)
+                                .width(120.0),
+                        )
+                        .changed();
+
+                    match destination.flag {
+                        DestFlag::SmeAddress => {
+                            let id_salt = format!("submit_multi_dest_addr_{index}");
+
+                            changed |= ui
+                                .add(AddressInput::new(
+                                    &id_salt,
+                                    "Address",
+                                    &mut destination.ton,
+                                    &mut destination.npi,
+                                    &mut destination.address,
+                                ))
+                                .changed();
+                        }
+                        DestFlag::DistributionList => {
+                            // Keeps the name and remove button aligned with the address cells.
+                            for _ in 0..4 {
+                                ui.label("");
+                            }
+
+                            ui.label("Name");
+                            changed |= ui
+                                .add(
+                                    egui::TextEdit::singleline(&mut destination.address)
+                                        .char_limit(20),
+                                )
+                                .on_hover_text("Max 20 ASCII characters")
+                                .changed();
+                        }
+                    }
+
+                    ui.add_enabled(can_remove, egui::Button::new("Remove"))
+                        .clicked()
+                        .then(|| remove = Some(index));
+                    ui.end_row();
+
+                    if let Some(Err(err)) = self.fields.dest_address.get(index) {
+                        ui.allocate_space(egui::vec2(0.0, 0.0));
+                        ui.colored_label(FUSION_RED, err.display_message());
+                        ui.end_row();
+                    }
+                }
+            });
+
+        if let Some(index) = remove {
+            self.destinations.remove(index);
+            changed = true;
+        }
+
+        ui.add_space(8.0);
+
+        ui.add_enabled(
+            self.destinations.len() < MAX_DESTINATIONS,
+            egui::Button::new("Add Destination"),
+        )
+        .on_disabled_hover_text(format!("At most {MAX_DESTINATIONS} destinations"))
+        .clicked()
+        .then(|| {
+            self.destinations.push(Destination::default());
+            changed = true;
+        });
+
+        if let Err(err) = &self.fields.number_of_dests {
+            ui.colored_label(FUSION_RED, err.display_message());
+        }
+
+        if changed {
+            self.update_destinations();
+        }
+    }
+
+    fn results_ui(&self, ui: &mut egui::Ui, loading: bool) {
+        let responses = self.responses.lock();
+
+        let mut unsuccess_sme = Vec::new();
+        let mut failed = false;
+
+        for response in responses.iter() {
+            match response_ui(ui, response).and_then(|command| command.pdu()) {
+                Some(Pdu::SubmitMultiResp(submit_multi_resp)) => {
+                    unsuccess_sme.extend(submit_multi_resp.unsuccess_sme());
+                }
+                Some(_) => {}
+                None => failed = true,
+            }
+        }
+
+        let pending = loading || responses.len() < self.sent_parts;
+
+        egui::Grid::new("submit_multi_results_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for destination in &self.sent {
+                    match destination.flag {
+                        DestFlag::SmeAddress => ui.label(&destination.address),
+                        DestFlag::DistributionList => {
+                            ui.label(format!("{} (Distribution List)", destination.address))
+                        }
+                    };
+
+                    let unsuccess = unsuccess_sme
+                        .iter()
+                        .find(|unsuccess_sme| destination.matches(unsuccess_sme));
+
+                    match unsuccess {
+                        Some(unsuccess_sme) => {
+                            ui.colored_label(
+                                FUSION_RED,
+                                format!("{:?}", unsuccess_sme.error_status_code),
+                            );
+                        }
+                        None if pending => {
+                            ui.label("Pending");
+                        }
+                        None if failed => {
+                            ui.colored_label(FUSION_RED, "Failed");
+                        }
+                        None => {
+                            ui.colored_label(REPTILE_GREEN, "Ok");
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+
+        // Members of a distribution list are only known from the response.
+        let others = unsuccess_sme
+            .iter()
+            .filter(|unsuccess_sme| {
+                !self
+                    .sent
+                    .iter()
+                    .any(|destination| destination.matches(unsuccess_sme))
+            })
+            .collect::<Vec<_>>();
+
+        if others.is_empty() {
+            return;
+        }
+
+        ui.add_space(12.0);
+        ui.label("Other unsuccessful SMEs");
+
+        egui::Grid::new("submit_multi_other_results_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for unsuccess_sme in others {
+                    ui.label(unsuccess_sme.destination_addr.to_string());
+
+                    let status = unsuccess_sme.error_status_code;
+                    let color = if status == CommandStatus::EsmeRok {
+                        REPTILE_GREEN
+                    } else {
+                        FUSION_RED
+                    };
+
+                    ui.colored_label(color, format!("{status:?}"));
+                    ui.end_row();
+                }
+            });
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        let loading = self.loading.load(Ordering::Relaxed);
+
+        ui.vertical_centered(|ui| {
+            let display_err = |ui: &mut egui::Ui, err: &AppUiError| {
+                ui.allocate_space(egui::vec2(0.0, 0.0));
+                ui.colored_label(FUSION_RED, err.display_message());
+                ui.end_row();
+            };
+
+            egui::Grid::new("submit_multi_grid")
+                .num_columns(2)
+                .spacing([12.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.label("Service Type");
+                    ui.add(egui::TextEdit::singleline(&mut self.service_type).char_limit(5))
+                        .on_hover_text("Max 5 ASCII characters")
+                        .changed()
+                        .then(|| self.update_service_type());
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.service_type {
+                        display_err(ui, err);
+                    }
+                });
+
+            ui.add_space(12.0);
+            ui.separator();
+            ui.add_space(12.0);
+
+            egui::Grid::new("submit_multi_source_addr_grid")
+                .num_columns(6)
+                .spacing([16.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.add(AddressInput::new(
+                        "submit_multi_source_addr",
+                        "Source Address",
+                        &mut self.source_addr_ton,
+                        &mut self.source_addr_npi,
+                        &mut self.source_addr,
+                    ))
+                    .changed()
+                    .then(|| self.update_source_addr());
+
+                    ui.end_row();
+
+                    if let Err(err) = &self.fields.source_addr {
+                        display_err(ui, err);
+                    }
+                });
+
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.heading("Destinations");
+                ui.add(Separator::default().horizontal().spacing(8.0));
+            });
+
+            ui.add_space(12.0);
+
+            self.destinations_ui(ui);
+
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.heading("Short Message");
+                ui.add(Separator::default().horizontal().spacing(8.0));
+            });
+
+            ui.add_space(12.0);
+
+            ui.vertical(|ui| {
+                egui::Grid::new("submit_multi_data_coding_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 10.0])
+                    .striped(false)
+                    .show(ui, |ui| {
+                        ui.label("Data Coding");
+                        ui.add(ComboBox::new(
+                            "submit_multi_data_coding",
+                            &mut self.data_coding,
+                            DataCoding::FIXED,
+                        ))
+                        .changed()
+                        .then(|| self.update_short_message());
+
+                        ui.end_row();
+                    });
+
+                ui.add_space(10.0);
+
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.short_message)
+                        .desired_width(ui.available_width()),
+                )
+                .changed()
+                .then(|| self.update_short_message());
+
+                ui.add_space(10.0);
+
+                match (&self.fields.parts, self.counters_message()) {
+                    (Err(err), _) => {
+                        ui.colored_label(FUSION_RED, err.display_message());
+                    }
+                    (Ok(_), Some(counters_message)) => {
+                        ui.label(counters_message);
+                    }
+                    (Ok(_), None) => {}
+                }
+            });
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.add_enabled_ui(
+                        self.bound && !loading && self.fields.all_fields_valid(),
+                        |ui| {
+                            ui.add_sized(
+                                [140.0, 32.0],
+                                egui::Button::new(
+                                    RichText::new("Submit Multi").color(Color32::WHITE).strong(),
+                                )
+                                .fill(HIGH_BLUE),
+                            )
+                            .clicked()
+                            .then(|| {
+                                self.on_submit_button_clicked();
+                            });
+                        },
+                    );
+
+                    if loading {
+                        ui.spinner();
+                    }
+                });
+            });
+
+            if !self.sent.is_empty() {
+                ui.add_space(12.0);
+
+                self.results_ui(ui, loading);
+            }
+        })
+        .response
+    }
+}
@@ -1,32 +1,57 @@
 use std::str::FromStr;
 
-use eframe::egui::{self, Color32, RichText, Separator};
-use encoder::Encoder;
+use eframe::egui::{self, Color32, RichText, Separator, TextFormat, text::LayoutJob};
+use encoder::{Encoder, Reference};
 use rusmpp::{
-    extra::{
-        concatenation::SubmitSmMultipartExt,
-        encoding::{gsm7bit::Gsm7BitUnpacked, latin1::Latin1, ucs2::Ucs2},
-    },
+    extra::concatenation::{MultipartError, SubmitSmMultipartExt},
     pdus::SubmitSm,
-    types::COctetString,
-    values::{EsmClass as RusmppEsmClass, ServiceType},
+    tlvs::{MessagePayload, MessageSubmissionRequestTlvValue, Tlv, TlvTag, TlvValue},
+    types::{AnyOctetString, COctetString, EmptyOrFullCOctetString, OctetString},
+    values::{
+        DataCoding as RusmppDataCoding, EsmClass as RusmppEsmClass,
+        RegisteredDelivery as RusmppRegisteredDelivery, ServiceType,
+    },
 };
 use serde::{Deserialize, Serialize};
 use strum::VariantArray;
 
 use crate::{
     actions::ActionsChannel,
+    apps::tlv_table::TlvTable,
     colors::{FUSION_RED, HIGH_BLUE},
+    gsm7::Alphabet,
+    inspect::{hex_str, split_udh},
     result::{AppResult, AppUiError, MultiPartError},
     values::{
-        Ansi41CbsPriorityFlag, Ansi41Specific, Ansi136PriorityFlag, DataCoding, EsmClass,
-        GsmCbsPriorityFlag, GsmFeatures, GsmSmsPriorityFlag, Is95PriorityFlag, MessageType,
-        MessagingMode, Npi, Ton,
+        Ansi41CbsPriorityFlag, Ansi41Specific, Ansi136PriorityFlag, Concatenation, DataCoding,
+        EsmClass, GsmCbsPriorityFlag, GsmFeatures, GsmSmsPriorityFlag, IntermediateNotification,
+        Is95PriorityFlag, LockingShift, MCDeliveryReceipt, MessageType, MessagingMode, Npi,
+        RegisteredDelivery, SingleShift, SmeOriginatedAcknowledgement, SmppTime, TlvEntry, Ton,
     },
-    widgets::ComboBox,
+    widgets::{AddressInput, ComboBox, SmppTimeInput},
 };
 
-mod encoder;
+pub mod encoder;
+
+/// Set by the message payload and SAR concatenation
+const RESERVED_TLV_TAGS: &[TlvTag] = &[
+    TlvTag::MessagePayload,
+    TlvTag::SarMsgRefNum,
+    TlvTag::SarTotalSegments,
+    TlvTag::SarSegmentSeqnum,
+];
+
+/// One built short message, for the preview
+struct Segment {
+    text: String,
+    /// Hex of the user data header, if any
+    udh: Option<String>,
+    payload_len: usize,
+    /// Room left after the payload, in the units of `payload_len`
+    remaining: usize,
+    /// Characters that took an escape sequence
+    escaped: Vec<char>,
+}
 
 struct RusmppFields {
     service_type: AppResult<COctetString<1, 6>>,
@@ -36,6 +61,9 @@ struct RusmppFields {
     sm_default_msg_id: AppResult<u8>,
     priority_flag: AppResult<u8>,
     priority_flag_types: PriorityFlagTypes,
+    schedule_delivery_time: AppResult<EmptyOrFullCOctetString<17>>,
+    validity_period: AppResult<EmptyOrFullCOctetString<17>>,
+    data_coding_override: AppResult<Option<u8>>,
     submit_sms: AppResult<Vec<SubmitSm>>,
 }
 
@@ -74,6 +102,7 @@ impl PriorityFlagTypes {
 }
 
 impl RusmppFields {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         service_type: &str,
         source_addr: &str,
@@ -81,6 +110,9 @@ impl RusmppFields {
         protocol_id: &str,
         sm_default_msg_id: &str,
         priority_flag: &str,
+        schedule_delivery_time: &SmppTime,
+        validity_period: &SmppTime,
+        data_coding_override: &str,
     ) -> Self {
         let mut fields = Self {
             service_type: COctetString::from_str(service_type)
@@ -99,6 +131,17 @@ impl RusmppFields {
                 .parse::<u8>()
                 .map_err(|_| AppUiError::invalid_priority_flag()),
             priority_flag_types: PriorityFlagTypes::default(),
+            schedule_delivery_time: encode_smpp_time(
+                "Schedule Delivery Time",
+                schedule_delivery_time,
+                AppUiError::invalid_schedule_delivery_time,
+            ),
+            validity_period: encode_smpp_time(
+                "Validity Period",
+                validity_period,
+                AppUiError::invalid_validity_period,
+            ),
+            data_coding_override: parse_data_coding_override(data_coding_override),
             submit_sms: Ok(Vec::new()),
         };
 
@@ -142,6 +185,26 @@ impl RusmppFields {
         self.update_priority_flag_types();
     }
 
+    fn set_schedule_delivery_time(&mut self, schedule_delivery_time: &SmppTime) {
+        self.schedule_delivery_time = encode_smpp_time(
+            "Schedule Delivery Time",
+            schedule_delivery_time,
+            AppUiError::invalid_schedule_delivery_time,
+        );
+    }
+
+    fn set_validity_period(&mut self, validity_period: &SmppTime) {
+        self.validity_period = encode_smpp_time(
+            "Validity Period",
+            validity_period,
+            AppUiError::invalid_validity_period,
+        );
+    }
+
+    fn set_data_coding_override(&mut self, data_coding_override: &str) {
+        self.data_coding_override = parse_data_coding_override(data_coding_override);
+    }
+
     fn update_priority_flag_types(&mut self) {
         self.priority_flag_types = self
             .priority_flag
@@ -171,13 +234,116 @@ impl RusmppFields {
                 &self.protocol_id,
                 &self.sm_default_msg_id,
                 &self.priority_flag,
+                &self.schedule_delivery_time,
+                &self.validity_period,
+                &self.data_coding_override,
                 self.sms_valid_and_not_empty()
             ),
-            (Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), true)
+            (
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                Ok(_),
+                true
+            )
         )
     }
 }
 
+/// Lays out `text` with the characters matching `highlighted` on a red background.
+///
+/// `highlighted` gets the position of each character along with it.
+fn highlighted_job(
+    ui: &egui::Ui,
+    text: &str,
+    highlighted: impl Fn(usize, char) -> bool,
+) -> LayoutJob {
+    let font_id = egui::TextStyle::Body.resolve(ui.style());
+    let color = ui.visuals().text_color();
+
+    let format = |background: Color32| TextFormat {
+        font_id: font_id.clone(),
+        color,
+        background,
+        ..Default::default()
+    };
+
+    let mut job = LayoutJob::default();
+
+    for (position, c) in text.chars().enumerate() {
+        let background = if highlighted(position, c) {
+            FUSION_RED.gamma_multiply(0.5)
+        } else {
+            Color32::TRANSPARENT
+        };
+
+        job.append(c.encode_utf8(&mut [0; 4]), 0.0, format(background));
+    }
+
+    job
+}
+
+/// The distinct characters of `text` outside of `alphabet`, with their code point
+fn non_gsm7_chars(alphabet: Alphabet, text: &str) -> String {
+    let mut chars = Vec::new();
+
+    for c in text.chars() {
+        if alphabet.encode_char(c).is_none() && !chars.contains(&c) {
+            chars.push(c);
+        }
+    }
+
+    chars
+        .iter()
+        .map(|c| format!("{c} (U+{:04X})", *c as u32))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Empty means the data coding of the selected encoding
+fn parse_data_coding_override(data_coding_override: &str) -> AppResult<Option<u8>> {
+    if data_coding_override.is_empty() {
+        return Ok(None);
+    }
+
+    data_coding_override
+        .parse::<u8>()
+        .map(Some)
+        .map_err(|_| AppUiError::invalid_data_coding_override())
+}
+
+fn encode_smpp_time(
+    field: &'static str,
+    time: &SmppTime,
+    invalid: fn() -> AppUiError,
+) -> AppResult<EmptyOrFullCOctetString<17>> {
+    let encoded = time
+        .encode()
+        .map_err(|err| AppUiError::invalid_smpp_time(field, err))?;
+
+    EmptyOrFullCOctetString::from_str(&encoded).map_err(|_| invalid())
+}
+
+/// Grid row previewing the encoded time, or what an empty one means
+fn encoded_time_preview(ui: &mut egui::Ui, encoded: &EmptyOrFullCOctetString<17>, empty: &str) {
+    ui.allocate_space(egui::vec2(0.0, 0.0));
+
+    let encoded = encoded.to_string();
+
+    if encoded.is_empty() {
+        ui.weak(empty);
+    } else {
+        ui.monospace(encoded);
+    }
+
+    ui.end_row();
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct SerdeSubmitSmApp {
     short_message: String,
@@ -189,11 +355,27 @@ pub struct SerdeSubmitSmApp {
     dest_addr_npi: Npi,
     destination_addr: String,
     data_coding: DataCoding,
+    #[serde(default)]
+    locking_shift: LockingShift,
+    #[serde(default)]
+    single_shift: SingleShift,
+    #[serde(default)]
+    concatenation: Concatenation,
+    #[serde(default)]
+    data_coding_override: String,
     esm_class: EsmClass,
     last_gsm_features: GsmFeatures,
+    #[serde(default)]
+    registered_delivery: RegisteredDelivery,
     protocol_id: String,
     sm_default_msg_id: String,
     priority_flag: String,
+    #[serde(default)]
+    schedule_delivery_time: SmppTime,
+    #[serde(default)]
+    validity_period: SmppTime,
+    #[serde(default)]
+    tlvs: Vec<TlvEntry>,
 }
 
 pub struct SubmitSmApp {
@@ -207,14 +389,26 @@ pub struct SubmitSmApp {
     dest_addr_npi: Npi,
     destination_addr: String,
     data_coding: DataCoding,
+    locking_shift: LockingShift,
+    single_shift: SingleShift,
+    concatenation: Concatenation,
+    /// Raw `data_coding` sent instead of the one of the selected encoding, empty for none
+    data_coding_override: String,
     esm_class: EsmClass,
     last_gsm_features: GsmFeatures,
+    registered_delivery: RegisteredDelivery,
     protocol_id: String,
     sm_default_msg_id: String,
     priority_flag: String,
-    reference: u8,
+    schedule_delivery_time: SmppTime,
+    validity_period: SmppTime,
+    tlvs: TlvTable,
+    /// Concatenation reference, only the low byte is used with an 8-bit UDH
+    reference: u16,
     fields: RusmppFields,
     bound: bool,
+    /// Data coding picked by [`DataCoding::Auto`] for the current message
+    auto_data_coding: DataCoding,
 }
 
 impl SubmitSmApp {
@@ -230,11 +424,19 @@ impl SubmitSmApp {
         dest_addr_npi: Npi,
         destination_addr: String,
         data_coding: DataCoding,
+        locking_shift: LockingShift,
+        single_shift: SingleShift,
+        concatenation: Concatenation,
+        data_coding_override: String,
         esm_class: EsmClass,
         last_gsm_features: GsmFeatures,
+        registered_delivery: RegisteredDelivery,
         protocol_id: String,
         sm_default_msg_id: String,
         priority_flag: String,
+        schedule_delivery_time: SmppTime,
+        validity_period: SmppTime,
+        tlvs: Vec<TlvEntry>,
     ) -> Self {
         let fields = RusmppFields::new(
             &service_type,
@@ -243,6 +445,9 @@ impl SubmitSmApp {
             &protocol_id,
             &sm_default_msg_id,
             &priority_flag,
+            &schedule_delivery_time,
+            &validity_period,
+            &data_coding_override,
         );
 
         let mut app = Self {
@@ -256,14 +461,23 @@ impl SubmitSmApp {
             dest_addr_npi,
             destination_addr,
             data_coding,
+            locking_shift,
+            single_shift,
+            concatenation,
+            data_coding_override,
             esm_class,
             last_gsm_features,
+            registered_delivery,
             protocol_id,
             sm_default_msg_id,
             priority_flag,
+            schedule_delivery_time,
+            validity_period,
+            tlvs: TlvTable::new("submit_sm_tlvs_grid", RESERVED_TLV_TAGS, tlvs),
             reference: 0,
             fields,
             bound: false,
+            auto_data_coding: DataCoding::default(),
         };
 
         app.update_short_message();
@@ -281,11 +495,19 @@ impl SubmitSmApp {
         let dest_addr_npi = Npi::default();
         let short_message = String::from("Hello Rusmppc!");
         let data_coding = DataCoding::default();
+        let locking_shift = LockingShift::default();
+        let single_shift = SingleShift::default();
+        let concatenation = Concatenation::default();
+        let data_coding_override = String::new();
         let esm_class = EsmClass::default();
         let last_gsm_features = GsmFeatures::default();
+        let registered_delivery = RegisteredDelivery::default();
         let protocol_id = String::from("0");
         let sm_default_msg_id = String::from("0");
         let priority_flag = String::from("0");
+        let schedule_delivery_time = SmppTime::default();
+        let validity_period = SmppTime::default();
+        let tlvs = Vec::new();
 
         Self::new_from_values(
             actions,
@@ -298,11 +520,19 @@ impl SubmitSmApp {
             dest_addr_npi,
             destination_addr,
             data_coding,
+            locking_shift,
+            single_shift,
+            concatenation,
+            data_coding_override,
             esm_class,
             last_gsm_features,
+            registered_delivery,
             protocol_id,
             sm_default_msg_id,
             priority_flag,
+            schedule_delivery_time,
+            validity_period,
+            tlvs,
         )
     }
 
@@ -318,11 +548,19 @@ impl SubmitSmApp {
             serde_app.dest_addr_npi,
             serde_app.destination_addr,
             serde_app.data_coding,
+            serde_app.locking_shift,
+            serde_app.single_shift,
+            serde_app.concatenation,
+            serde_app.data_coding_override,
             serde_app.esm_class,
             serde_app.last_gsm_features,
+            serde_app.registered_delivery,
             serde_app.protocol_id,
             serde_app.sm_default_msg_id,
             serde_app.priority_flag,
+            serde_app.schedule_delivery_time,
+            serde_app.validity_period,
+            serde_app.tlvs,
         )
     }
 
@@ -337,27 +575,56 @@ impl SubmitSmApp {
             dest_addr_npi: self.dest_addr_npi,
             destination_addr: self.destination_addr.clone(),
             data_coding: self.data_coding,
+            locking_shift: self.locking_shift,
+            single_shift: self.single_shift,
+            concatenation: self.concatenation,
+            data_coding_override: self.data_coding_override.clone(),
             esm_class: self.esm_class,
             last_gsm_features: self.last_gsm_features,
+            registered_delivery: self.registered_delivery,
             protocol_id: self.protocol_id.clone(),
             sm_default_msg_id: self.sm_default_msg_id.clone(),
             priority_flag: self.priority_flag.clone(),
+            schedule_delivery_time: self.schedule_delivery_time,
+            validity_period: self.validity_period,
+            tlvs: self.tlvs.entries().to_vec(),
         }
     }
 
-    /// Creates the appropriate encoder on the fly based on the selected data coding.
-    ///
-    /// This is done like this, because we may want provide configuration options for each encoder in the future.
-    /// For example, allowing the user to select different alphabets for GSM 7-bit encoding.
-    pub fn encoder(&self) -> Encoder {
+    fn alphabet(&self) -> Alphabet {
+        Alphabet::new(self.locking_shift.language(), self.single_shift.language())
+    }
+
+    fn resolved_data_coding(&self) -> DataCoding {
         match self.data_coding {
-            DataCoding::Gsm7BitUnpacked => Encoder::Gsm7BitUnpacked(Gsm7BitUnpacked::default()),
-            DataCoding::Latin1 => Encoder::Latin1(Latin1::default()),
-            DataCoding::Ucs2 => Encoder::Ucs2(Ucs2::default()),
+            DataCoding::Auto => self.auto_data_coding,
+            data_coding => data_coding,
         }
     }
 
+    /// Whether Auto had to leave the GSM 7-bit alphabet for the current message
+    fn auto_widened(&self) -> bool {
+        self.data_coding == DataCoding::Auto && self.auto_data_coding != DataCoding::Gsm7BitUnpacked
+    }
+
+    pub fn encoder(&self) -> Encoder {
+        Encoder::new(self.resolved_data_coding())
+            .alphabet(self.alphabet())
+            .data_coding_override(
+                self.fields
+                    .data_coding_override
+                    .as_ref()
+                    .ok()
+                    .copied()
+                    .flatten(),
+            )
+    }
+
     fn update_short_message(&mut self) {
+        if self.data_coding == DataCoding::Auto {
+            self.auto_data_coding = Encoder::auto(&self.short_message, self.alphabet());
+        }
+
         self.fields.submit_sms = self.build_submit_sms();
 
         if self.udhi_indicator_must_be_set() {
@@ -395,6 +662,27 @@ impl SubmitSmApp {
         self.update_short_message();
     }
 
+    fn update_data_coding_override(&mut self) {
+        self.data_coding_override.retain(|c| c.is_ascii_digit());
+        self.fields
+            .set_data_coding_override(&self.data_coding_override);
+
+        self.update_short_message();
+    }
+
+    fn update_schedule_delivery_time(&mut self) {
+        self.fields
+            .set_schedule_delivery_time(&self.schedule_delivery_time);
+
+        self.update_short_message();
+    }
+
+    fn update_validity_period(&mut self) {
+        self.fields.set_validity_period(&self.validity_period);
+
+        self.update_short_message();
+    }
+
     fn update_source_addr(&mut self) {
         self.source_addr.retain(|c| c.is_ascii());
         self.fields.set_source_addr(&self.source_addr);
@@ -409,7 +697,17 @@ impl SubmitSmApp {
         self.update_short_message();
     }
 
-    fn build_submit_sm(&self) -> AppResult<SubmitSm> {
+    /// Builds a `SubmitSm` from the form, the multipart builder replaces its content with each part.
+    fn build_submit_sm(
+        &self,
+        data_coding: RusmppDataCoding,
+        short_message: OctetString<0, 255>,
+        extra_tlvs: Vec<Tlv>,
+    ) -> AppResult<SubmitSm> {
+        let mut tlvs = self.tlvs.tlvs()?;
+
+        tlvs.extend(extra_tlvs);
+
         let submit_sm = SubmitSm::builder()
             .service_type(ServiceType::new(self.fields.service_type.clone()?))
             .source_addr_ton(self.source_addr_ton.into())
@@ -419,41 +717,265 @@ impl SubmitSmApp {
             .dest_addr_npi(self.dest_addr_npi.into())
             .destination_addr(self.fields.destination_addr.clone()?)
             .esm_class(self.esm_class.into())
+            .registered_delivery(self.registered_delivery.into())
             .protocol_id(self.fields.protocol_id.clone()?)
+            .schedule_delivery_time(self.fields.schedule_delivery_time.clone()?)
+            .validity_period(self.fields.validity_period.clone()?)
             .sm_default_msg_id(self.fields.sm_default_msg_id.clone()?)
+            .data_coding(data_coding)
+            .short_message(short_message)
+            .tlvs(tlvs)
             .build();
 
         Ok(submit_sm)
     }
 
     fn build_submit_sms(&self) -> AppResult<Vec<SubmitSm>> {
-        let submit_sm = self.build_submit_sm()?;
+        if self.short_message.is_empty() {
+            return Err(AppUiError::MultiPart(MultiPartError::EmptyMessage));
+        }
 
-        submit_sm
-            .multipart(&self.short_message)
-            .reference_u8(self.reference)
-            .encoder(self.encoder())
-            .build()
-            .map_err(|_| AppUiError::MultiPart(MultiPartError::Todo))
+        match self.concatenation {
+            Concatenation::Udh8Bit | Concatenation::Udh16Bit => self.build_udh_submit_sms(),
+            Concatenation::SarTlvs => self.build_sar_submit_sms(),
+            Concatenation::MessagePayload => self
+                .build_message_payload_submit_sm()
+                .map(|submit_sm| vec![submit_sm]),
+        }
+    }
+
+    fn build_udh_submit_sms(&self) -> AppResult<Vec<SubmitSm>> {
+        let encoder = self.encoder();
+
+        if encoder.uses_shift_tables() {
+            return self.build_shifted_submit_sms(encoder);
+        }
+
+        let submit_sm = self.build_submit_sm(
+            RusmppDataCoding::default(),
+            OctetString::default(),
+            Vec::new(),
+        )?;
+
+        let multipart = submit_sm.multipart(&self.short_message);
+
+        let multipart = match self.concatenation {
+            Concatenation::Udh16Bit => multipart.reference_u16(self.reference),
+            _ => multipart.reference_u8(self.reference as u8),
+        };
+
+        multipart.encoder(encoder).build().map_err(|err| {
+            AppUiError::MultiPart(match err {
+                MultipartError::Encode(err) => err,
+                MultipartError::TooManyParts(count) => MultiPartError::TooManySegments(count),
+            })
+        })
+    }
+
+    /// The multipart builder only knows the concatenation IE, the shift tables IEs need our own UDH.
+    fn build_shifted_submit_sms(&self, encoder: Encoder) -> AppResult<Vec<SubmitSm>> {
+        let reference = match self.concatenation {
+            Concatenation::Udh16Bit => Reference::U16(self.reference),
+            _ => Reference::U8(self.reference as u8),
+        };
+
+        let parts = encoder
+            .split(&self.short_message, reference)
+            .map_err(AppUiError::MultiPart)?;
+
+        parts
+            .short_messages
+            .into_iter()
+            .map(|short_message| self.build_submit_sm(parts.data_coding, short_message, Vec::new()))
+            .collect()
+    }
+
+    /// Bare segments, each flagged with the SAR TLVs if there is more than one.
+    fn build_sar_submit_sms(&self) -> AppResult<Vec<SubmitSm>> {
+        let (segments, data_coding) = self
+            .encoder()
+            .segments(&self.short_message)
+            .map_err(AppUiError::MultiPart)?;
+
+        let total = u8::try_from(segments.len())
+            .map_err(|_| AppUiError::MultiPart(MultiPartError::TooManySegments(segments.len())))?;
+
+        segments
+            .into_iter()
+            .zip(1..=total)
+            .map(|(segment, seqnum)| {
+                let short_message = OctetString::new(segment).map_err(|_| {
+                    AppUiError::MultiPart(MultiPartError::TooLong {
+                        max_message_size: 255,
+                    })
+                })?;
+
+                let sar_tlvs = if total > 1 {
+                    vec![
+                        Tlv::new(MessageSubmissionRequestTlvValue::SarMsgRefNum(
+                            self.reference,
+                        )),
+                        Tlv::new(MessageSubmissionRequestTlvValue::SarTotalSegments(total)),
+                        Tlv::new(MessageSubmissionRequestTlvValue::SarSegmentSeqnum(seqnum)),
+                    ]
+                } else {
+                    Vec::new()
+                };
+
+                self.build_submit_sm(data_coding, short_message, sar_tlvs)
+            })
+            .collect()
+    }
+
+    /// A single `SubmitSm` with an empty `short_message` and the whole message in `message_payload`.
+    fn build_message_payload_submit_sm(&self) -> AppResult<SubmitSm> {
+        let (payload, data_coding) = self
+            .encoder()
+            .payload(&self.short_message)
+            .map_err(AppUiError::MultiPart)?;
+
+        let payload = AnyOctetString::new(payload).map_err(|_| {
+            AppUiError::MultiPart(MultiPartError::TooLong {
+                max_message_size: u16::MAX as usize,
+            })
+        })?;
+
+        self.build_submit_sm(
+            data_coding,
+            OctetString::default(),
+            vec![Tlv::new(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(payload),
+            ))],
+        )
+    }
+
+    fn on_transliterate_button_clicked(&mut self) {
+        self.short_message = self.alphabet().transliterate(&self.short_message);
+
+        self.update_short_message();
     }
 
     fn increment_reference(&mut self) {
         self.reference = self.reference.wrapping_add(1);
     }
 
-    fn counters_message(&self) -> Option<String> {
-        let (sms_count, byte_count) = self.fields.submit_sms.as_ref().ok().map(|sms| {
-            let byte_count: usize = sms.iter().map(|sms| sms.short_message().len()).sum();
+    /// What each built `SubmitSm` carries, decoded back from its user data
+    fn segments(&self) -> Option<Vec<Segment>> {
+        let sms = self.fields.submit_sms.as_ref().ok()?;
+        let encoder = self.encoder();
+        let udhi = self.udhi_indicator_must_be_set();
+
+        let segments = sms
+            .iter()
+            .map(|submit_sm| {
+                let message_payload = submit_sm.tlvs().iter().find_map(|tlv| match tlv.value() {
+                    Some(TlvValue::MessagePayload(payload)) => Some(payload.as_bytes()),
+                    _ => None,
+                });
 
-            (sms.len(), byte_count)
-        })?;
+                let user_data = message_payload.unwrap_or(submit_sm.short_message().as_bytes());
+
+                let (udh, payload) = if udhi {
+                    split_udh(user_data)
+                } else {
+                    (None, user_data)
+                };
+
+                let udh_len = udh.map(<[u8]>::len).unwrap_or(0);
+
+                let capacity = match message_payload {
+                    Some(_) => (u16::MAX as usize).saturating_sub(udh_len),
+                    None => encoder.capacity(udh_len),
+                };
+
+                let text = encoder.decode(payload, udh_len);
+
+                Segment {
+                    escaped: encoder.escaped_chars(&text),
+                    text,
+                    udh: udh.map(hex_str),
+                    payload_len: payload.len(),
+                    remaining: capacity.saturating_sub(payload.len()),
+                }
+            })
+            .collect();
+
+        Some(segments)
+    }
+
+    fn counters_message(&self) -> Option<String> {
+        let segments = self.segments()?;
+        let byte_count: usize = segments.iter().map(|segment| segment.payload_len).sum();
+        let capacity: usize = segments
+            .iter()
+            .map(|segment| segment.payload_len + segment.remaining)
+            .sum();
         let char_count = self.short_message.chars().count();
 
         Some(format!(
-            "{sms_count} SMS {char_count} Characters {byte_count}/140 Bytes"
+            "{} SMS {char_count} Characters {byte_count}/{capacity} Bytes",
+            segments.len()
         ))
     }
 
+    fn segments_ui(&self, ui: &mut egui::Ui) {
+        let Some(segments) = self.segments() else {
+            return;
+        };
+
+        let total = segments.len();
+
+        egui::CollapsingHeader::new(format!("Segments ({total})"))
+            .id_salt("submit_sm_segments")
+            .show(ui, |ui| {
+                for (index, segment) in segments.iter().enumerate() {
+                    ui.strong(format!("Segment {}/{total}", index + 1));
+
+                    egui::Grid::new(format!("submit_sm_segment_{index}_grid"))
+                        .num_columns(2)
+                        .spacing([12.0, 6.0])
+                        .striped(false)
+                        .show(ui, |ui| {
+                            ui.label("Text");
+                            ui.monospace(format!("{:?}", segment.text));
+                            ui.end_row();
+
+                            ui.label("UDH");
+                            match &segment.udh {
+                                Some(udh) => ui.monospace(udh),
+                                None => ui.weak("None"),
+                            };
+                            ui.end_row();
+
+                            ui.label("Payload");
+                            ui.label(format!(
+                                "{} Bytes, {} remaining",
+                                segment.payload_len, segment.remaining
+                            ));
+                            ui.end_row();
+
+                            if !segment.escaped.is_empty() {
+                                ui.label("Escaped");
+                                ui.label(
+                                    segment
+                                        .escaped
+                                        .iter()
+                                        .map(char::to_string)
+                                        .collect::<Vec<_>>()
+                                        .join(" "),
+                                )
+                                .on_hover_text(
+                                    "Each takes two septets, the escape and the character",
+                                );
+                                ui.end_row();
+                            }
+                        });
+
+                    ui.add_space(6.0);
+                }
+            });
+    }
+
     pub fn set_bound(&mut self, bound: bool) {
         self.bound = bound;
     }
@@ -474,6 +996,14 @@ impl SubmitSmApp {
         format!("0x{:02X}", self.esm_class_value())
     }
 
+    fn registered_delivery_value(&self) -> u8 {
+        RusmppRegisteredDelivery::from(self.registered_delivery).into()
+    }
+
+    fn registered_delivery_value_str(&self) -> String {
+        format!("0x{:02X}", self.registered_delivery_value())
+    }
+
     fn set_udhi_indicator(&mut self) {
         self.esm_class.gsm_features = GsmFeatures::UdhiIndicator;
     }
@@ -487,10 +1017,11 @@ impl SubmitSmApp {
     }
 
     fn udhi_indicator_must_be_set(&self) -> bool {
-        self.fields
-            .submit_sms
-            .as_ref()
-            .is_ok_and(|sms| sms.len() > 1)
+        let Ok(sms) = &self.fields.submit_sms else {
+            return false;
+        };
+
+        (self.concatenation.uses_udh() && sms.len() > 1) || self.encoder().uses_shift_tables()
     }
 
     pub fn ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
@@ -559,6 +1090,38 @@ impl SubmitSmApp {
                     if let Err(err) = &self.fields.priority_flag {
                         display_err(ui, err);
                     }
+
+                    ui.label("Schedule Delivery Time");
+                    ui.add(SmppTimeInput::new(
+                        "submit_sm_schedule_delivery_time",
+                        &mut self.schedule_delivery_time,
+                    ))
+                    .changed()
+                    .then(|| {
+                        self.update_schedule_delivery_time();
+                    });
+                    ui.end_row();
+
+                    match &self.fields.schedule_delivery_time {
+                        Ok(encoded) => encoded_time_preview(ui, encoded, "Immediate delivery"),
+                        Err(err) => display_err(ui, err),
+                    }
+
+                    ui.label("Validity Period");
+                    ui.add(SmppTimeInput::new(
+                        "submit_sm_validity_period",
+                        &mut self.validity_period,
+                    ))
+                    .changed()
+                    .then(|| {
+                        self.update_validity_period();
+                    });
+                    ui.end_row();
+
+                    match &self.fields.validity_period {
+                        Ok(encoded) => encoded_time_preview(ui, encoded, "MC default validity"),
+                        Err(err) => display_err(ui, err),
+                    }
                 });
 
             ui.add_space(12.0);
@@ -570,44 +1133,27 @@ impl SubmitSmApp {
                 .spacing([16.0, 10.0])
                 .striped(false)
                 .show(ui, |ui| {
-                    // TON
-                    ui.label("Source Address TON");
-                    ui.add(ComboBox::new(
-                        "submit_sm_source_addr_ton",
+                    ui.add(AddressInput::new(
+                        "submit_sm_source_addr",
+                        "Source Address",
                         &mut self.source_addr_ton,
-                        Ton::VARIANTS,
-                    ));
-                    ui.label("Source Address NPI");
-                    ui.add(ComboBox::new(
-                        "submit_sm_source_addr_npi",
                         &mut self.source_addr_npi,
-                        Npi::VARIANTS,
-                    ));
-                    ui.label("Source Address");
-                    ui.add(egui::TextEdit::singleline(&mut self.source_addr).char_limit(20))
-                        .on_hover_text("Max 20 ASCII characters")
-                        .changed()
-                        .then(|| self.update_source_addr());
+                        &mut self.source_addr,
+                    ))
+                    .changed()
+                    .then(|| self.update_source_addr());
 
                     ui.end_row();
 
-                    ui.label("Destination Address TON");
-                    ui.add(ComboBox::new(
-                        "submit_sm_dest_addr_ton",
+                    ui.add(AddressInput::new(
+                        "submit_sm_dest_addr",
+                        "Destination Address",
                         &mut self.dest_addr_ton,
-                        Ton::VARIANTS,
-                    ));
-                    ui.label("Destination Address NPI");
-                    ui.add(ComboBox::new(
-                        "submit_sm_dest_addr_npi",
                         &mut self.dest_addr_npi,
-                        Npi::VARIANTS,
-                    ));
-                    ui.label("Destination Address");
-                    ui.add(egui::TextEdit::singleline(&mut self.destination_addr).char_limit(20))
-                        .on_hover_text("Max 20 ASCII characters")
-                        .changed()
-                        .then(|| self.update_destination_addr());
+                        &mut self.destination_addr,
+                    ))
+                    .changed()
+                    .then(|| self.update_destination_addr());
 
                     ui.end_row();
                 });
@@ -674,6 +1220,61 @@ impl SubmitSmApp {
 
             ui.add_space(12.0);
 
+            ui.horizontal(|ui| {
+                ui.heading(format!(
+                    "Registered Delivery: ({})",
+                    self.registered_delivery_value_str()
+                ));
+                ui.add(Separator::default().horizontal().spacing(8.0));
+            });
+
+            ui.add_space(12.0);
+
+            egui::Grid::new("registered_delivery_grid")
+                .num_columns(2)
+                .spacing([12.0, 10.0])
+                .striped(false)
+                .show(ui, |ui| {
+                    ui.label("MC Delivery Receipt");
+                    ui.add(ComboBox::new(
+                        "submit_sm_registered_delivery_mc_delivery_receipt",
+                        &mut self.registered_delivery.mc_delivery_receipt,
+                        MCDeliveryReceipt::VARIANTS,
+                    ))
+                    .on_hover_text("Final delivery outcomes the MC reports in a delivery receipt");
+
+                    ui.label("SME Acknowledgement");
+                    ui.add(ComboBox::new(
+                        "submit_sm_registered_delivery_sme_originated_acknowledgement",
+                        &mut self.registered_delivery.sme_originated_acknowledgement,
+                        SmeOriginatedAcknowledgement::VARIANTS,
+                    ));
+
+                    ui.end_row();
+
+                    ui.label("Intermediate Notification");
+                    ui.add(ComboBox::new(
+                        "submit_sm_registered_delivery_intermediate_notification",
+                        &mut self.registered_delivery.intermediate_notification,
+                        IntermediateNotification::VARIANTS,
+                    ));
+
+                    ui.end_row();
+                });
+
+            ui.add_space(12.0);
+
+            ui.horizontal(|ui| {
+                ui.heading("TLVs");
+                ui.add(Separator::default().horizontal().spacing(8.0));
+            });
+
+            ui.add_space(12.0);
+
+            self.tlvs.ui(ui).then(|| self.update_short_message());
+
+            ui.add_space(12.0);
+
             ui.horizontal(|ui| {
                 ui.heading("Short Message");
                 ui.add(Separator::default().horizontal().spacing(8.0));
@@ -688,39 +1289,158 @@ impl SubmitSmApp {
                     .striped(false)
                     .show(ui, |ui| {
                         ui.label("Data Coding");
-                        // TODO: make our ComboBox widget take a function for the .changed() callback
-                        egui::ComboBox::from_id_salt("submit_sm_data_coding")
-                            .width(100.0)
-                            .selected_text(<&'static str>::from(self.data_coding))
-                            .show_ui(ui, |ui| {
-                                for item in DataCoding::VARIANTS {
-                                    ui.selectable_value(
-                                        &mut self.data_coding,
-                                        *item,
-                                        <&'static str>::from(*item),
-                                    )
-                                    .changed()
-                                    .then(|| self.update_short_message());
-                                }
-                            });
+                        ui.add(ComboBox::new(
+                            "submit_sm_data_coding",
+                            &mut self.data_coding,
+                            DataCoding::VARIANTS,
+                        ))
+                        .changed()
+                        .then(|| self.update_short_message());
+
+                        ui.end_row();
+
+                        if self.data_coding == DataCoding::Auto {
+                            ui.allocate_space(egui::vec2(0.0, 0.0));
+                            ui.weak(format!(
+                                "Encoded as {}",
+                                <&'static str>::from(self.auto_data_coding)
+                            ));
+
+                            ui.end_row();
+                        }
+
+                        if self.data_coding.is_gsm7() {
+                            ui.label("Locking Shift");
+                            ui.add(ComboBox::new(
+                                "submit_sm_locking_shift",
+                                &mut self.locking_shift,
+                                LockingShift::VARIANTS,
+                            ))
+                            .on_hover_text("National table replacing the default alphabet")
+                            .changed()
+                            .then(|| self.update_short_message());
+
+                            ui.end_row();
+
+                            ui.label("Single Shift");
+                            ui.add(ComboBox::new(
+                                "submit_sm_single_shift",
+                                &mut self.single_shift,
+                                SingleShift::VARIANTS,
+                            ))
+                            .on_hover_text("National table replacing the extension table")
+                            .changed()
+                            .then(|| self.update_short_message());
+
+                            ui.end_row();
+                        }
+
+                        ui.label("Concatenation");
+                        ui.add(ComboBox::new(
+                            "submit_sm_concatenation",
+                            &mut self.concatenation,
+                            Concatenation::VARIANTS,
+                        ))
+                        .on_hover_text(self.concatenation.description())
+                        .changed()
+                        .then(|| self.update_short_message());
+
+                        ui.end_row();
+
+                        ui.label("Data Coding Override");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.data_coding_override)
+                                .char_limit(3)
+                                .hint_text("none"),
+                        )
+                        .on_hover_text(
+                            "Raw data_coding sent instead of the one of the selected encoding",
+                        )
+                        .changed()
+                        .then(|| self.update_data_coding_override());
 
                         ui.end_row();
+
+                        if let Err(err) = &self.fields.data_coding_override {
+                            display_err(ui, err);
+                        }
                     });
 
                 ui.add_space(10.0);
 
+                let hint_text = if self.data_coding.is_binary() {
+                    "hex octets"
+                } else {
+                    ""
+                };
+
+                let alphabet = self.alphabet();
+                let highlight = self.auto_widened();
+                let unencodable = match &self.fields.submit_sms {
+                    Err(AppUiError::MultiPart(MultiPartError::UnencodableCharacter {
+                        position,
+                        ..
+                    })) => Some(*position),
+                    _ => None,
+                };
+
+                let mut layouter = |ui: &egui::Ui, text: &dyn egui::TextBuffer, wrap_width: f32| {
+                    let highlighted = |position: usize, c: char| {
+                        Some(position) == unencodable
+                            || highlight && alphabet.encode_char(c).is_none()
+                    };
+                    let mut job = highlighted_job(ui, text.as_str(), highlighted);
+
+                    job.wrap.max_width = wrap_width;
+
+                    ui.fonts_mut(|fonts| fonts.layout_job(job))
+                };
+
                 ui.add(
                     egui::TextEdit::multiline(&mut self.short_message)
-                        .desired_width(ui.available_width()),
+                        .hint_text(hint_text)
+                        .desired_width(ui.available_width())
+                        .layouter(&mut layouter),
                 )
                 .changed()
                 .then(|| {
                     self.update_short_message();
                 });
 
-                if let Some(counters_message) = self.counters_message() {
+                if highlight {
                     ui.add_space(10.0);
-                    ui.label(counters_message);
+
+                    ui.horizontal_wrapped(|ui| {
+                        ui.colored_label(
+                            FUSION_RED,
+                            format!(
+                                "Not in the GSM 7-bit alphabet: {}",
+                                non_gsm7_chars(alphabet, &self.short_message)
+                            ),
+                        );
+
+                        ui.add_enabled(
+                            alphabet.has_lookalikes(&self.short_message),
+                            egui::Button::new("Replace with GSM-safe lookalikes"),
+                        )
+                        .clicked()
+                        .then(|| self.on_transliterate_button_clicked());
+                    });
+                }
+
+                match (&self.fields.submit_sms, self.counters_message()) {
+                    (Err(AppUiError::MultiPart(err)), _) => {
+                        ui.add_space(10.0);
+                        ui.colored_label(FUSION_RED, err.display_message());
+                    }
+                    (_, Some(counters_message)) => {
+                        ui.add_space(10.0);
+                        ui.label(counters_message);
+
+                        ui.add_space(6.0);
+                        self.segments_ui(ui);
+                    }
+                    _ => {}
                 }
             });
 
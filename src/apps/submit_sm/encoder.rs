@@ -1,17 +1,373 @@
 use rusmpp::{
     extra::{
         concatenation::{Concatenation, Concatenator},
-        encoding::{gsm7bit::Gsm7BitUnpacked, latin1::Latin1, ucs2::Ucs2},
+        encoding::{
+            gsm7bit::{Gsm7BitConcatenateError, Gsm7BitEncodeError, Gsm7BitUnpacked},
+            latin1::{Latin1, Latin1ConcatenateError, Latin1EncodeError},
+            ucs2::{Ucs2, Ucs2ConcatenateError, Ucs2EncodeError},
+        },
     },
+    types::OctetString,
+    values::DataCoding as RusmppDataCoding,
+};
+
+use crate::{
+    gsm7::{self, Alphabet},
+    inspect::{decode_text, hex_str},
+    iso8859,
+    result::MultiPartError,
     values::DataCoding,
 };
 
-use crate::result::MultiPartError;
+pub struct Encoder {
+    codec: Codec,
+    /// Raw `data_coding` sent instead of the one of the codec, for operator specific values
+    data_coding_override: Option<u8>,
+}
 
-pub enum Encoder {
+enum Codec {
+    /// Default alphabet, one septet per octet
     Gsm7BitUnpacked(Gsm7BitUnpacked),
+    /// Packed or with national shift tables, which we encode ourselves
+    Gsm7Bit {
+        alphabet: Alphabet,
+        packed: bool,
+    },
+    Ia5,
     Latin1(Latin1),
     Ucs2(Ucs2),
+    /// Hex text sent as is
+    Binary(RusmppDataCoding),
+    Cyrillic,
+    LatinHebrew,
+}
+
+/// Reference of the concatenation IE, shared by all the parts of a message
+#[derive(Clone, Copy)]
+pub enum Reference {
+    U8(u8),
+    U16(u16),
+}
+
+impl Reference {
+    /// Size of the IE, identifier and length included
+    const fn ie_len(self) -> usize {
+        match self {
+            Reference::U8(_) => 5,
+            Reference::U16(_) => 6,
+        }
+    }
+
+    fn ie(self, total: u8, sequence: u8) -> Vec<u8> {
+        match self {
+            Reference::U8(reference) => vec![0x00, 0x03, reference, total, sequence],
+            Reference::U16(reference) => {
+                let [high, low] = reference.to_be_bytes();
+
+                vec![0x08, 0x04, high, low, total, sequence]
+            }
+        }
+    }
+}
+
+/// The short messages of a message, concatenated with a UDH if it does not fit in one
+pub struct Parts {
+    pub data_coding: RusmppDataCoding,
+    pub short_messages: Vec<OctetString<0, 255>>,
+    /// Whether the short messages start with a UDH, which must be flagged in the `esm_class`
+    pub udhi: bool,
+}
+
+impl Encoder {
+    /// Creates the appropriate encoder on the fly based on the selected data coding.
+    ///
+    /// Per encoder options, like the GSM 7-bit shift tables, are set with the builder methods.
+    /// [`DataCoding::Auto`] must be resolved with [`Encoder::auto`] first, on its own it stands for the default alphabet.
+    pub fn new(data_coding: DataCoding) -> Self {
+        let codec = match data_coding {
+            DataCoding::Auto | DataCoding::Gsm7BitUnpacked => {
+                Codec::Gsm7BitUnpacked(Gsm7BitUnpacked::default())
+            }
+            DataCoding::Gsm7BitPacked => Codec::Gsm7Bit {
+                alphabet: Alphabet::default(),
+                packed: true,
+            },
+            DataCoding::Ia5 => Codec::Ia5,
+            DataCoding::Latin1 => Codec::Latin1(Latin1::default()),
+            DataCoding::Ucs2 => Codec::Ucs2(Ucs2::default()),
+            DataCoding::OctetUnspecified | DataCoding::OctetUnspecified2 => {
+                Codec::Binary(data_coding.into())
+            }
+            DataCoding::Cyrillic => Codec::Cyrillic,
+            DataCoding::LatinHebrew => Codec::LatinHebrew,
+        };
+
+        Self {
+            codec,
+            data_coding_override: None,
+        }
+    }
+
+    /// Picks the candidate of [`DataCoding::Auto`] with the fewest short messages, the cheapest on a tie.
+    ///
+    /// Falls back to UCS-2 if no candidate can encode the message.
+    pub fn auto(message: &str, alphabet: Alphabet) -> DataCoding {
+        DataCoding::AUTO_CANDIDATES
+            .into_iter()
+            .filter_map(|data_coding| {
+                let parts = Encoder::new(data_coding)
+                    .alphabet(alphabet)
+                    .split(message, Reference::U8(0))
+                    .ok()?;
+
+                Some((parts.short_messages.len(), data_coding))
+            })
+            .min_by_key(|(count, _)| *count)
+            .map(|(_, data_coding)| data_coding)
+            .unwrap_or(DataCoding::Ucs2)
+    }
+
+    /// National shift tables for GSM 7-bit, ignored by the other codecs.
+    pub fn alphabet(mut self, alphabet: Alphabet) -> Self {
+        self.codec = match self.codec {
+            Codec::Gsm7BitUnpacked(_) if alphabet != Alphabet::default() => Codec::Gsm7Bit {
+                alphabet,
+                packed: false,
+            },
+            Codec::Gsm7Bit { packed, .. } => Codec::Gsm7Bit { alphabet, packed },
+            codec => codec,
+        };
+
+        self
+    }
+
+    /// Sends `data_coding` as is, the message is still encoded with the selected codec.
+    pub fn data_coding_override(mut self, data_coding: Option<u8>) -> Self {
+        self.data_coding_override = data_coding;
+        self
+    }
+
+    /// Whether every short message starts with a UDH announcing national shift tables
+    pub fn uses_shift_tables(&self) -> bool {
+        !self.shift_ies().is_empty()
+    }
+
+    fn shift_ies(&self) -> Vec<u8> {
+        match &self.codec {
+            Codec::Gsm7Bit { alphabet, .. } => alphabet.udh_ies(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Encodes a message that must fit in a single short message of up to `max_message_size` octets.
+    pub fn encode_single(
+        &self,
+        message: &str,
+        max_message_size: usize,
+    ) -> Result<Vec<u8>, MultiPartError> {
+        match self.concatenate(message, max_message_size, 0)? {
+            (Concatenation::Single(bytes), _) => Ok(bytes),
+            (Concatenation::Concatenated(_), _) => {
+                Err(MultiPartError::TooLong { max_message_size })
+            }
+        }
+    }
+
+    /// Encodes a whole message for the `message_payload` TLV, which is never split.
+    pub fn payload(&self, message: &str) -> Result<(Vec<u8>, RusmppDataCoding), MultiPartError> {
+        let ies = self.shift_ies();
+        let header_size = udh_len(&ies);
+
+        match self.encode(message, u16::MAX as usize, header_size, header_size)? {
+            (Concatenation::Single(bytes), data_coding) => Ok((with_udh(&ies, bytes), data_coding)),
+            (Concatenation::Concatenated(_), _) => Err(MultiPartError::TooLong {
+                max_message_size: u16::MAX as usize,
+            }),
+        }
+    }
+
+    /// Splits a message into segments for concatenation outside of the short message.
+    ///
+    /// Segments only start with a UDH for the shift tables.
+    pub fn segments(
+        &self,
+        message: &str,
+    ) -> Result<(Vec<Vec<u8>>, RusmppDataCoding), MultiPartError> {
+        let ies = self.shift_ies();
+        let header_size = udh_len(&ies);
+
+        let (concatenation, data_coding) =
+            self.encode(message, self.max_message_size(), header_size, header_size)?;
+
+        let segments = match concatenation {
+            Concatenation::Single(bytes) => vec![with_udh(&ies, bytes)],
+            Concatenation::Concatenated(parts) => {
+                parts.into_iter().map(|part| with_udh(&ies, part)).collect()
+            }
+        };
+
+        Ok((segments, data_coding))
+    }
+
+    /// Octets available in a short message, septets for unpacked GSM 7-bit.
+    const fn max_message_size(&self) -> usize {
+        match self.codec {
+            Codec::Gsm7BitUnpacked(_) | Codec::Gsm7Bit { packed: false, .. } => 160,
+            _ => 140,
+        }
+    }
+
+    /// Room taken by a UDH of `octets`, its octets take more septets once packed.
+    const fn header_size(&self, octets: usize) -> usize {
+        match self.codec {
+            Codec::Gsm7BitUnpacked(_) | Codec::Gsm7Bit { packed: false, .. } => {
+                header_septets(octets)
+            }
+            _ => octets,
+        }
+    }
+
+    /// Room left for the message in a short message starting with a UDH of `udh_len` octets.
+    pub const fn capacity(&self, udh_len: usize) -> usize {
+        self.max_message_size()
+            .saturating_sub(self.header_size(udh_len))
+    }
+
+    /// Decodes an encoded part back to text, `udh_len` is the size of the UDH it follows.
+    ///
+    /// Binary parts are shown as hex.
+    pub fn decode(&self, bytes: &[u8], udh_len: usize) -> String {
+        let data_coding = match &self.codec {
+            Codec::Gsm7BitUnpacked(_) => return Alphabet::default().decode_unpacked(bytes),
+            Codec::Gsm7Bit {
+                alphabet,
+                packed: false,
+            } => return alphabet.decode_unpacked(bytes),
+            Codec::Gsm7Bit {
+                alphabet,
+                packed: true,
+            } => return alphabet.decode_unpacked(&gsm7::unpack(bytes, gsm7::fill_bits(udh_len))),
+            Codec::Binary(_) => return hex_str(bytes),
+            Codec::Ia5 => RusmppDataCoding::Ia5,
+            Codec::Latin1(_) => RusmppDataCoding::Latin1,
+            Codec::Ucs2(_) => RusmppDataCoding::Ucs2,
+            Codec::Cyrillic => RusmppDataCoding::Cyrillic,
+            Codec::LatinHebrew => RusmppDataCoding::LatinHebrew,
+        };
+
+        decode_text(data_coding, Alphabet::default(), bytes, udh_len).unwrap_or_default()
+    }
+
+    /// The distinct characters of `text` taking an escape sequence, only GSM 7-bit has them.
+    pub fn escaped_chars(&self, text: &str) -> Vec<char> {
+        let alphabet = match &self.codec {
+            Codec::Gsm7BitUnpacked(_) => Alphabet::default(),
+            Codec::Gsm7Bit { alphabet, .. } => *alphabet,
+            _ => return Vec::new(),
+        };
+
+        let mut escaped = Vec::new();
+
+        for c in text.chars() {
+            let is_escaped = alphabet
+                .encode_char(c)
+                .is_some_and(|septets| septets.first() == Some(&gsm7::ESCAPE));
+
+            if is_escaped && !escaped.contains(&c) {
+                escaped.push(c);
+            }
+        }
+
+        escaped
+    }
+
+    /// Like [`Concatenator::concatenate`], with header sizes in octets and a header for a single message as well.
+    ///
+    /// Only our own GSM 7-bit codec has a header without concatenation, the shift tables.
+    fn encode(
+        &self,
+        message: &str,
+        max_message_size: usize,
+        single_header_size: usize,
+        part_header_size: usize,
+    ) -> Result<(Concatenation, RusmppDataCoding), MultiPartError> {
+        match &self.codec {
+            Codec::Gsm7Bit { alphabet, packed } => Ok((
+                concatenate_septets(
+                    message,
+                    alphabet,
+                    *packed,
+                    max_message_size,
+                    single_header_size,
+                    part_header_size,
+                )?,
+                self.sent_data_coding(RusmppDataCoding::McSpecific),
+            )),
+            _ => self.concatenate(
+                message,
+                max_message_size,
+                self.header_size(part_header_size),
+            ),
+        }
+    }
+
+    fn sent_data_coding(&self, data_coding: RusmppDataCoding) -> RusmppDataCoding {
+        self.data_coding_override
+            .map(RusmppDataCoding::from)
+            .unwrap_or(data_coding)
+    }
+
+    /// Splits a message into short messages, prefixing each part with a concatenation UDH.
+    ///
+    /// The UDH also announces the shift tables, even for a single short message.
+    pub fn split(&self, message: &str, reference: Reference) -> Result<Parts, MultiPartError> {
+        let ies = self.shift_ies();
+        let single_header_size = udh_len(&ies);
+        let part_header_size = 1 + ies.len() + reference.ie_len();
+
+        let (concatenation, data_coding) = self.encode(
+            message,
+            self.max_message_size(),
+            single_header_size,
+            part_header_size,
+        )?;
+
+        let (short_messages, udhi) = match concatenation {
+            Concatenation::Single(bytes) => (vec![with_udh(&ies, bytes)], !ies.is_empty()),
+            Concatenation::Concatenated(parts) => {
+                let total = u8::try_from(parts.len())
+                    .map_err(|_| MultiPartError::TooManySegments(parts.len()))?;
+
+                let parts = parts
+                    .into_iter()
+                    .zip(1..=total)
+                    .map(|(part, sequence)| {
+                        let mut part_ies = reference.ie(total, sequence);
+
+                        part_ies.extend(&ies);
+
+                        with_udh(&part_ies, part)
+                    })
+                    .collect();
+
+                (parts, true)
+            }
+        };
+
+        let short_messages = short_messages
+            .into_iter()
+            .map(OctetString::new)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| MultiPartError::TooLong {
+                max_message_size: 255,
+            })?;
+
+        Ok(Parts {
+            data_coding,
+            short_messages,
+            udhi,
+        })
+    }
 }
 
 impl Concatenator for Encoder {
@@ -22,17 +378,235 @@ impl Concatenator for Encoder {
         message: &str,
         max_message_size: usize,
         part_header_size: usize,
-    ) -> Result<(Concatenation, DataCoding), Self::Error> {
-        match self {
-            Encoder::Gsm7BitUnpacked(encoder) => encoder
+    ) -> Result<(Concatenation, RusmppDataCoding), Self::Error> {
+        let (concatenation, data_coding) = match &self.codec {
+            Codec::Gsm7BitUnpacked(encoder) => encoder
                 .concatenate(message, max_message_size, part_header_size)
-                .map_err(|_| MultiPartError::Todo),
-            Encoder::Latin1(encoder) => encoder
+                .map_err(|err| match err {
+                    Gsm7BitConcatenateError::Encode(Gsm7BitEncodeError::UnencodableCharacter(
+                        character,
+                    )) => MultiPartError::unencodable_character(message, character),
+                    Gsm7BitConcatenateError::PartCapacityExceeded {
+                        part_header_size,
+                        max_message_size,
+                    } => MultiPartError::HeaderOverflow {
+                        part_header_size,
+                        max_message_size,
+                    },
+                })?,
+            Codec::Latin1(encoder) => encoder
                 .concatenate(message, max_message_size, part_header_size)
-                .map_err(|_| MultiPartError::Todo),
-            Encoder::Ucs2(encoder) => encoder
+                .map_err(|err| match err {
+                    Latin1ConcatenateError::Encode(Latin1EncodeError::UnencodableCharacter(
+                        character,
+                    )) => MultiPartError::unencodable_character(message, character),
+                    Latin1ConcatenateError::PartCapacityExceeded {
+                        part_header_size,
+                        max_message_size,
+                    } => MultiPartError::HeaderOverflow {
+                        part_header_size,
+                        max_message_size,
+                    },
+                })?,
+            Codec::Ucs2(encoder) => encoder
                 .concatenate(message, max_message_size, part_header_size)
-                .map_err(|_| MultiPartError::Todo),
+                .map_err(|err| match err {
+                    Ucs2ConcatenateError::Encode(Ucs2EncodeError::UnencodableCharacter(
+                        character,
+                    )) => MultiPartError::unencodable_character(message, character),
+                    Ucs2ConcatenateError::PartCapacityExceeded {
+                        part_header_size,
+                        max_message_size,
+                    } => MultiPartError::HeaderOverflow {
+                        part_header_size,
+                        max_message_size,
+                    },
+                })?,
+            Codec::Gsm7Bit { alphabet, packed } => (
+                concatenate_septets(
+                    message,
+                    alphabet,
+                    *packed,
+                    max_message_size,
+                    0,
+                    part_header_size,
+                )?,
+                RusmppDataCoding::McSpecific,
+            ),
+            Codec::Ia5 => (
+                concatenate_octets(
+                    encode_chars(message, |c| c.is_ascii().then_some(c as u8))?,
+                    max_message_size,
+                    part_header_size,
+                )?,
+                RusmppDataCoding::Ia5,
+            ),
+            Codec::Binary(data_coding) => (
+                concatenate_octets(
+                    crate::hex::decode(message).ok_or(MultiPartError::InvalidHex)?,
+                    max_message_size,
+                    part_header_size,
+                )?,
+                *data_coding,
+            ),
+            Codec::Cyrillic => (
+                concatenate_octets(
+                    encode_chars(message, iso8859::cyrillic_octet)?,
+                    max_message_size,
+                    part_header_size,
+                )?,
+                RusmppDataCoding::Cyrillic,
+            ),
+            Codec::LatinHebrew => (
+                concatenate_octets(
+                    encode_chars(message, iso8859::hebrew_octet)?,
+                    max_message_size,
+                    part_header_size,
+                )?,
+                RusmppDataCoding::LatinHebrew,
+            ),
+        };
+
+        Ok((concatenation, self.sent_data_coding(data_coding)))
+    }
+}
+
+/// Size of a UDH made of `ies`, length octet included, zero without IEs.
+const fn udh_len(ies: &[u8]) -> usize {
+    if ies.is_empty() { 0 } else { 1 + ies.len() }
+}
+
+/// Prefixes `payload` with a UDH made of `ies`, if any.
+fn with_udh(ies: &[u8], payload: Vec<u8>) -> Vec<u8> {
+    if ies.is_empty() {
+        return payload;
+    }
+
+    let mut bytes = Vec::with_capacity(1 + ies.len() + payload.len());
+
+    bytes.push(ies.len() as u8);
+    bytes.extend(ies);
+    bytes.extend(payload);
+    bytes
+}
+
+/// Septets taken by a UDH of `octets` in unpacked GSM 7-bit.
+const fn header_septets(octets: usize) -> usize {
+    (octets * 8).div_ceil(7)
+}
+
+/// Encodes a single octet charset.
+fn encode_chars(
+    message: &str,
+    octet: impl Fn(char) -> Option<u8>,
+) -> Result<Vec<u8>, MultiPartError> {
+    message
+        .chars()
+        .enumerate()
+        .map(|(position, character)| {
+            octet(character).ok_or(MultiPartError::UnencodableCharacter {
+                position,
+                character,
+            })
+        })
+        .collect()
+}
+
+/// Splits octets into parts leaving room for the header, any octet boundary will do.
+fn concatenate_octets(
+    octets: Vec<u8>,
+    max_message_size: usize,
+    part_header_size: usize,
+) -> Result<Concatenation, MultiPartError> {
+    if octets.len() <= max_message_size {
+        return Ok(Concatenation::Single(octets));
+    }
+
+    let capacity = max_message_size
+        .checked_sub(part_header_size)
+        .filter(|capacity| *capacity > 0)
+        .ok_or(MultiPartError::HeaderOverflow {
+            part_header_size,
+            max_message_size,
+        })?;
+
+    Ok(Concatenation::Concatenated(
+        octets.chunks(capacity).map(<[u8]>::to_vec).collect(),
+    ))
+}
+
+/// Encodes GSM 7-bit septets, header sizes are in octets.
+///
+/// Packed parts are padded with fill bits so that the first septet starts on a septet boundary after the header.
+/// An escape sequence is never split between two parts.
+fn concatenate_septets(
+    message: &str,
+    alphabet: &Alphabet,
+    packed: bool,
+    max_message_size: usize,
+    single_header_size: usize,
+    part_header_size: usize,
+) -> Result<Concatenation, MultiPartError> {
+    let chars = message
+        .chars()
+        .enumerate()
+        .map(|(position, character)| {
+            alphabet
+                .encode_char(character)
+                .ok_or(MultiPartError::UnencodableCharacter {
+                    position,
+                    character,
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let capacity = |header_size: usize| {
+        if packed {
+            max_message_size
+                .checked_sub(header_size)
+                .map(|octets| (octets * 8).saturating_sub(gsm7::fill_bits(header_size)) / 7)
+        } else {
+            max_message_size.checked_sub(header_septets(header_size))
+        }
+    };
+
+    let encode = |septets: &[u8], header_size: usize| {
+        if packed {
+            gsm7::pack(septets, gsm7::fill_bits(header_size))
+        } else {
+            septets.to_vec()
         }
+    };
+
+    let septet_count: usize = chars.iter().map(Vec::len).sum();
+
+    if capacity(single_header_size).is_some_and(|capacity| septet_count <= capacity) {
+        return Ok(Concatenation::Single(encode(
+            &chars.concat(),
+            single_header_size,
+        )));
     }
+
+    let capacity = capacity(part_header_size)
+        .filter(|capacity| *capacity >= 2)
+        .ok_or(MultiPartError::HeaderOverflow {
+            part_header_size,
+            max_message_size,
+        })?;
+
+    let mut parts = Vec::new();
+    let mut part: Vec<u8> = Vec::with_capacity(capacity);
+
+    for septets in chars {
+        if part.len() + septets.len() > capacity {
+            parts.push(encode(&part, part_header_size));
+            part.clear();
+        }
+
+        part.extend(septets);
+    }
+
+    parts.push(encode(&part, part_header_size));
+
+    Ok(Concatenation::Concatenated(parts))
 }
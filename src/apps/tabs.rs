@@ -1,10 +1,16 @@
 use egui::{Stroke, WidgetText};
 use egui_dock::{DockArea, DockState, NodeIndex, Style};
 use serde::{Deserialize, Serialize};
+use strum::VariantArray;
 
 use crate::{
     actions::ActionsChannel,
-    apps::{BindApp, LogsApp, SerdeBindApp, SerdeLogsApp, SerdeSubmitSmApp, SubmitSmApp},
+    apps::{
+        BindApp, BroadcastSmApp, CancelSmApp, DataSmApp, DeliveryReportsApp, LogsApp, QuerySmApp,
+        ReplaceSmApp, Selection, SerdeBindApp, SerdeBroadcastSmApp, SerdeCancelSmApp,
+        SerdeDataSmApp, SerdeDeliveryReportsApp, SerdeLogsApp, SerdeQuerySmApp, SerdeReplaceSmApp,
+        SerdeSubmitMultiApp, SerdeSubmitSmApp, SubmitMultiApp, SubmitSmApp,
+    },
     state::EventsHolder,
 };
 
@@ -12,29 +18,133 @@ use crate::{
 enum SerdeTab {
     Bind(SerdeBindApp),
     SubmitSm(SerdeSubmitSmApp),
+    SubmitMulti(SerdeSubmitMultiApp),
+    DataSm(SerdeDataSmApp),
+    BroadcastSm(SerdeBroadcastSmApp),
+    QuerySm(SerdeQuerySmApp),
+    CancelSm(SerdeCancelSmApp),
+    ReplaceSm(SerdeReplaceSmApp),
     Logs(SerdeLogsApp),
+    DeliveryReports(SerdeDeliveryReportsApp),
+}
+
+/// Every kind of tab the dock can hold.
+///
+/// Used to add tabs introduced after a layout was saved.
+#[derive(VariantArray, Clone, Copy, PartialEq, Eq)]
+enum TabKind {
+    Bind,
+    SubmitSm,
+    SubmitMulti,
+    DataSm,
+    BroadcastSm,
+    QuerySm,
+    CancelSm,
+    ReplaceSm,
+    Logs,
+    DeliveryReports,
 }
 
 pub enum Tab {
     Bind(BindApp),
     SubmitSm(SubmitSmApp),
+    SubmitMulti(SubmitMultiApp),
+    DataSm(DataSmApp),
+    BroadcastSm(BroadcastSmApp),
+    QuerySm(QuerySmApp),
+    CancelSm(CancelSmApp),
+    ReplaceSm(ReplaceSmApp),
     Logs(LogsApp),
+    DeliveryReports(DeliveryReportsApp),
 }
 
 impl Tab {
+    fn new_default(
+        kind: TabKind,
+        actions: ActionsChannel,
+        events_holder: EventsHolder,
+        selection: Selection,
+    ) -> Self {
+        match kind {
+            TabKind::Bind => Tab::Bind(BindApp::new_default(actions)),
+            TabKind::SubmitSm => Tab::SubmitSm(SubmitSmApp::new_default(actions)),
+            TabKind::SubmitMulti => Tab::SubmitMulti(SubmitMultiApp::new_default(actions)),
+            TabKind::DataSm => Tab::DataSm(DataSmApp::new_default(actions)),
+            TabKind::BroadcastSm => Tab::BroadcastSm(BroadcastSmApp::new_default(actions)),
+            TabKind::QuerySm => Tab::QuerySm(QuerySmApp::new_default(actions, selection)),
+            TabKind::CancelSm => Tab::CancelSm(CancelSmApp::new_default(actions, selection)),
+            TabKind::ReplaceSm => Tab::ReplaceSm(ReplaceSmApp::new_default(actions, selection)),
+            TabKind::Logs => Tab::Logs(LogsApp::new_default(events_holder, actions, selection)),
+            TabKind::DeliveryReports => {
+                Tab::DeliveryReports(DeliveryReportsApp::new_default(events_holder))
+            }
+        }
+    }
+
+    const fn kind(&self) -> TabKind {
+        match self {
+            Tab::Bind(_) => TabKind::Bind,
+            Tab::SubmitSm(_) => TabKind::SubmitSm,
+            Tab::SubmitMulti(_) => TabKind::SubmitMulti,
+            Tab::DataSm(_) => TabKind::DataSm,
+            Tab::BroadcastSm(_) => TabKind::BroadcastSm,
+            Tab::QuerySm(_) => TabKind::QuerySm,
+            Tab::CancelSm(_) => TabKind::CancelSm,
+            Tab::ReplaceSm(_) => TabKind::ReplaceSm,
+            Tab::Logs(_) => TabKind::Logs,
+            Tab::DeliveryReports(_) => TabKind::DeliveryReports,
+        }
+    }
+
     const fn title(&self) -> &str {
         match self {
             Tab::Bind(_) => "Bind",
             Tab::SubmitSm(_) => "Submit Sm",
+            Tab::SubmitMulti(_) => "Submit Multi",
+            Tab::DataSm(_) => "Data Sm",
+            Tab::BroadcastSm(_) => "Broadcast Sm",
+            Tab::QuerySm(_) => "Query Sm",
+            Tab::CancelSm(_) => "Cancel Sm",
+            Tab::ReplaceSm(_) => "Replace Sm",
             Tab::Logs(_) => "Logs",
+            Tab::DeliveryReports(_) => "Delivery Reports",
         }
     }
 
-    fn from_serde(actions: ActionsChannel, events_holder: EventsHolder, serde: SerdeTab) -> Self {
+    fn from_serde(
+        actions: ActionsChannel,
+        events_holder: EventsHolder,
+        selection: Selection,
+        serde: SerdeTab,
+    ) -> Self {
         match serde {
             SerdeTab::Bind(serde) => Tab::Bind(BindApp::from_serde(actions, serde)),
             SerdeTab::SubmitSm(serde) => Tab::SubmitSm(SubmitSmApp::from_serde(actions, serde)),
-            SerdeTab::Logs(serde) => Tab::Logs(LogsApp::from_serde(events_holder, serde)),
+            SerdeTab::SubmitMulti(serde) => {
+                Tab::SubmitMulti(SubmitMultiApp::from_serde(actions, serde))
+            }
+            SerdeTab::DataSm(serde) => Tab::DataSm(DataSmApp::from_serde(actions, serde)),
+            SerdeTab::BroadcastSm(serde) => {
+                Tab::BroadcastSm(BroadcastSmApp::from_serde(actions, serde))
+            }
+            SerdeTab::QuerySm(serde) => {
+                Tab::QuerySm(QuerySmApp::from_serde(actions, selection, serde))
+            }
+            SerdeTab::CancelSm(serde) => {
+                Tab::CancelSm(CancelSmApp::from_serde(actions, selection, serde))
+            }
+            SerdeTab::ReplaceSm(serde) => {
+                Tab::ReplaceSm(ReplaceSmApp::from_serde(actions, selection, serde))
+            }
+            SerdeTab::Logs(serde) => Tab::Logs(LogsApp::from_serde(
+                events_holder,
+                actions,
+                selection,
+                serde,
+            )),
+            SerdeTab::DeliveryReports(serde) => {
+                Tab::DeliveryReports(DeliveryReportsApp::from_serde(events_holder, serde))
+            }
         }
     }
 
@@ -42,15 +152,29 @@ impl Tab {
         match self {
             Tab::Bind(app) => SerdeTab::Bind(app.to_serde()),
             Tab::SubmitSm(app) => SerdeTab::SubmitSm(app.to_serde()),
+            Tab::SubmitMulti(app) => SerdeTab::SubmitMulti(app.to_serde()),
+            Tab::DataSm(app) => SerdeTab::DataSm(app.to_serde()),
+            Tab::BroadcastSm(app) => SerdeTab::BroadcastSm(app.to_serde()),
+            Tab::QuerySm(app) => SerdeTab::QuerySm(app.to_serde()),
+            Tab::CancelSm(app) => SerdeTab::CancelSm(app.to_serde()),
+            Tab::ReplaceSm(app) => SerdeTab::ReplaceSm(app.to_serde()),
             Tab::Logs(app) => SerdeTab::Logs(app.to_serde()),
+            Tab::DeliveryReports(app) => SerdeTab::DeliveryReports(app.to_serde()),
         }
     }
 
     fn set_bound(&mut self, bound: bool) {
         match self {
             Tab::SubmitSm(app) => app.set_bound(bound),
+            Tab::SubmitMulti(app) => app.set_bound(bound),
+            Tab::DataSm(app) => app.set_bound(bound),
+            Tab::BroadcastSm(app) => app.set_bound(bound),
+            Tab::QuerySm(app) => app.set_bound(bound),
+            Tab::CancelSm(app) => app.set_bound(bound),
+            Tab::ReplaceSm(app) => app.set_bound(bound),
             Tab::Bind(app) => app.set_bound(bound),
-            Tab::Logs(_) => {}
+            Tab::Logs(app) => app.set_bound(bound),
+            Tab::DeliveryReports(_) => {}
         }
     }
 
@@ -59,12 +183,33 @@ impl Tab {
             Tab::SubmitSm(app) => {
                 app.ui(ui);
             }
+            Tab::SubmitMulti(app) => {
+                app.ui(ui);
+            }
+            Tab::DataSm(app) => {
+                app.ui(ui);
+            }
+            Tab::BroadcastSm(app) => {
+                app.ui(ui);
+            }
+            Tab::QuerySm(app) => {
+                app.ui(ui);
+            }
+            Tab::CancelSm(app) => {
+                app.ui(ui);
+            }
+            Tab::ReplaceSm(app) => {
+                app.ui(ui);
+            }
             Tab::Bind(app) => {
                 app.ui(ui);
             }
             Tab::Logs(app) => {
                 app.ui(ui);
             }
+            Tab::DeliveryReports(app) => {
+                app.ui(ui);
+            }
         }
     }
 }
@@ -102,18 +247,37 @@ pub struct Tabs {
 
 impl Tabs {
     pub fn new_default(events_holder: EventsHolder, actions: ActionsChannel) -> Self {
-        let mut dock_state = DockState::new(vec![Tab::Bind(BindApp::new_default(actions.clone()))]);
+        let selection = Selection::default();
+
+        let tab = |kind| {
+            Tab::new_default(
+                kind,
+                actions.clone(),
+                events_holder.clone(),
+                selection.clone(),
+            )
+        };
+
+        let mut dock_state = DockState::new(vec![tab(TabKind::Bind)]);
 
         let [a, _] = dock_state.main_surface_mut().split_below(
             NodeIndex::root(),
             0.6,
-            vec![Tab::Logs(LogsApp::new_default(events_holder))],
+            vec![tab(TabKind::Logs), tab(TabKind::DeliveryReports)],
         );
 
         let [_, _] = dock_state.main_surface_mut().split_right(
             a,
             0.3,
-            vec![Tab::SubmitSm(SubmitSmApp::new_default(actions))],
+            vec![
+                tab(TabKind::SubmitSm),
+                tab(TabKind::SubmitMulti),
+                tab(TabKind::DataSm),
+                tab(TabKind::BroadcastSm),
+                tab(TabKind::QuerySm),
+                tab(TabKind::CancelSm),
+                tab(TabKind::ReplaceSm),
+            ],
         );
 
         Self { dock_state }
@@ -124,9 +288,37 @@ impl Tabs {
         actions: ActionsChannel,
         serde: SerdeTabs,
     ) -> Self {
-        let dock_state = serde
-            .dock_state
-            .map_tabs(|tab| Tab::from_serde(actions.clone(), events_holder.clone(), tab.clone()));
+        let selection = Selection::default();
+
+        let mut dock_state = serde.dock_state.map_tabs(|tab| {
+            Tab::from_serde(
+                actions.clone(),
+                events_holder.clone(),
+                selection.clone(),
+                tab.clone(),
+            )
+        });
+
+        // Layouts saved before a tab existed would otherwise never show it,
+        // since tabs can not be closed or opened by the user.
+        let missing = TabKind::VARIANTS
+            .iter()
+            .filter(|kind| {
+                !dock_state
+                    .iter_all_tabs()
+                    .any(|(_, tab)| tab.kind() == **kind)
+            })
+            .copied()
+            .collect::<Vec<_>>();
+
+        for kind in missing {
+            dock_state.push_to_focused_leaf(Tab::new_default(
+                kind,
+                actions.clone(),
+                events_holder.clone(),
+                selection.clone(),
+            ));
+        }
 
         Self { dock_state }
     }
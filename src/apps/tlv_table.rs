@@ -0,0 +1,196 @@
+use std::collections::HashSet;
+
+use eframe::egui;
+use rusmpp::tlvs::{Tlv, TlvTag};
+use strum::VariantArray;
+
+use crate::{
+    colors::FUSION_RED,
+    result::{AppResult, AppUiError},
+    values::{LanguageIndicator, PayloadType, PrivacyIndicator, TlvEntry, TlvKind},
+    widgets::{AddressInput, ComboBox},
+};
+
+/// Editable optional parameters of a PDU, validated row by row
+pub struct TlvTable {
+    id_salt: &'static str,
+    /// Tags the PDU sets from its own fields, rows can not repeat them
+    reserved: &'static [TlvTag],
+    entries: Vec<TlvEntry>,
+    tlvs: Vec<AppResult<Tlv>>,
+}
+
+impl TlvTable {
+    pub fn new(id_salt: &'static str, reserved: &'static [TlvTag], entries: Vec<TlvEntry>) -> Self {
+        let mut table = Self {
+            id_salt,
+            reserved,
+            entries,
+            tlvs: Vec::new(),
+        };
+
+        table.update();
+
+        table
+    }
+
+    pub fn entries(&self) -> &[TlvEntry] {
+        &self.entries
+    }
+
+    /// The built TLVs, or the error of the first invalid row
+    pub fn tlvs(&self) -> AppResult<Vec<Tlv>> {
+        self.tlvs.iter().cloned().collect()
+    }
+
+    pub fn all_rows_valid(&self) -> bool {
+        self.tlvs.iter().all(Result::is_ok)
+    }
+
+    /// Rebuilds every row, a tag already used by a previous row or reserved by the PDU is an error.
+    fn update(&mut self) {
+        let mut tags = self
+            .reserved
+            .iter()
+            .map(|tag| u16::from(*tag))
+            .collect::<HashSet<_>>();
+
+        self.tlvs = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let tlv = entry.build()?;
+
+                match entry.tag() {
+                    Some(tag) if !tags.insert(tag) => Err(AppUiError::duplicate_tlv()),
+                    _ => Ok(tlv),
+                }
+            })
+            .collect();
+    }
+
+    fn value_ui(ui: &mut egui::Ui, id_salt: &str, entry: &mut TlvEntry) -> egui::Response {
+        match entry.kind {
+            TlvKind::UserMessageReference | TlvKind::SourcePort | TlvKind::DestinationPort => {
+                let response = ui
+                    .add(egui::TextEdit::singleline(&mut entry.number).char_limit(5))
+                    .on_hover_text("Unsigned 16-bit integer");
+
+                if response.changed() {
+                    entry.number.retain(|c| c.is_ascii_digit());
+                }
+
+                response
+            }
+            TlvKind::PayloadType => ui.add(ComboBox::new(
+                &format!("{id_salt}_payload_type"),
+                &mut entry.payload_type,
+                PayloadType::VARIANTS,
+            )),
+            TlvKind::PrivacyIndicator => ui.add(ComboBox::new(
+                &format!("{id_salt}_privacy_indicator"),
+                &mut entry.privacy_indicator,
+                PrivacyIndicator::VARIANTS,
+            )),
+            TlvKind::LanguageIndicator => ui.add(ComboBox::new(
+                &format!("{id_salt}_language_indicator"),
+                &mut entry.language_indicator,
+                LanguageIndicator::VARIANTS,
+            )),
+            TlvKind::CallbackNum => {
+                ui.horizontal(|ui| {
+                    let response = ui.add(
+                        AddressInput::new(
+                            &format!("{id_salt}_callback_num"),
+                            "Callback",
+                            &mut entry.callback_num_ton,
+                            &mut entry.callback_num_npi,
+                            &mut entry.callback_num,
+                        )
+                        .char_limit(16),
+                    );
+
+                    if response.changed() {
+                        entry.callback_num.retain(|c| c.is_ascii());
+                    }
+
+                    response
+                })
+                .inner
+            }
+            TlvKind::Raw => {
+                ui.horizontal(|ui| {
+                    ui.label("Tag");
+                    let tag = ui
+                        .add(
+                            egui::TextEdit::singleline(&mut entry.tag)
+                                .char_limit(6)
+                                .desired_width(60.0),
+                        )
+                        .on_hover_text("Hex of any tag without its own kind, e.g. vendor specific");
+
+                    ui.label("Value");
+                    let value = ui
+                        .add(egui::TextEdit::singleline(&mut entry.value).hint_text("hex"))
+                        .on_hover_text("Hex octets");
+
+                    tag | value
+                })
+                .inner
+            }
+        }
+    }
+
+    /// Returns whether any row changed.
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = false;
+        let mut removed = None;
+
+        egui::Grid::new(self.id_salt)
+            .num_columns(3)
+            .spacing([12.0, 10.0])
+            .striped(false)
+            .show(ui, |ui| {
+                for (index, (entry, tlv)) in self.entries.iter_mut().zip(&self.tlvs).enumerate() {
+                    let id_salt = format!("{}_{index}", self.id_salt);
+
+                    changed |= ui
+                        .add(ComboBox::new(
+                            &format!("{id_salt}_kind"),
+                            &mut entry.kind,
+                            TlvKind::VARIANTS,
+                        ))
+                        .changed();
+
+                    changed |= Self::value_ui(ui, &id_salt, entry).changed();
+
+                    ui.button("Remove").clicked().then(|| {
+                        removed = Some(index);
+                    });
+                    ui.end_row();
+
+                    if let Err(err) = tlv {
+                        ui.allocate_space(egui::vec2(0.0, 0.0));
+                        ui.colored_label(FUSION_RED, err.display_message());
+                        ui.end_row();
+                    }
+                }
+            });
+
+        if let Some(index) = removed {
+            self.entries.remove(index);
+            changed = true;
+        }
+
+        ui.button("Add TLV").clicked().then(|| {
+            self.entries.push(TlvEntry::default());
+            changed = true;
+        });
+
+        if changed {
+            self.update();
+        }
+
+        changed
+    }
+}
@@ -1,15 +1,28 @@
-use std::{sync::atomic::Ordering, time::Duration};
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
 
 use futures::{Stream, StreamExt, TryFutureExt};
 use rusmpp::{
-    Pdu,
-    pdus::{BindReceiver, BindTransceiver, BindTransmitter},
+    Command, Pdu,
+    pdus::{BindReceiver, BindTransceiver, BindTransmitter, DataSmResp, DeliverSmResp, SubmitSm},
 };
-use rusmppc::{Client, InsightConnectionBuilder, InsightEvent};
+use rusmppc::{Client, InsightConnectionBuilder, InsightEvent, error::Error};
+use web_time::Instant;
 
 use crate::{
-    actions::{Action, BindAction, SubmitSmsAction, UnbindAction},
+    actions::{
+        Action, AutoResponse, BindAction, BroadcastSmAction, CancelBroadcastSmAction,
+        CancelSmAction, DataSmAction, QueryBroadcastSmAction, QuerySmAction, ReplaceSmAction,
+        ReplayAction, ResponseSlot, SharedAutoResponse, SubmitMultiAction, SubmitSmsAction,
+        UnbindAction,
+    },
     client::ClientExt,
+    correlation::RESPONSE_TIMEOUT,
     insight::InsightExt,
     result::AppActionError,
     runtime,
@@ -18,6 +31,19 @@ use crate::{
     values::{BindMode, Event},
 };
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::actions::ListenAction;
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::Either;
+
+/// How often a waiting replay or listen checks whether it was cancelled
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long to wait for an SMSC to connect and `Outbind` in listen mode
+#[cfg(not(target_arch = "wasm32"))]
+const LISTEN_TIMEOUT: Duration = Duration::from_secs(120);
+
 #[derive(Clone)]
 pub struct BackgroundApp {
     state: AppState,
@@ -52,13 +78,93 @@ impl BackgroundApp {
         self.state.outgoing_event_blink();
     }
 
-    async fn handle_events(&self, mut events: impl Stream<Item = InsightEvent> + Unpin) {
+    /// Responds to a received `DeliverSm` in the background, so that a configured delay does not block the event stream.
+    fn respond_to_deliver_sm(&self, client: Client, sequence_number: u32, response: AutoResponse) {
+        let this = self.clone();
+
+        runtime::spawn(async move {
+            runtime::sleep(response.delay).await;
+
+            match client
+                .deliver_sm_resp_mapped(
+                    response.status.into(),
+                    sequence_number,
+                    DeliverSmResp::default(),
+                )
+                .await
+            {
+                Ok(command) => {
+                    this.outgoing_event_blink();
+                    this.push_event(Event::Sent(command));
+                }
+                Err(err) => {
+                    this.push_event(Event::Error(AppActionError::DeliverSmResp(err)));
+                }
+            }
+
+            this.request_repaint();
+        });
+    }
+
+    /// Responds to a received `DataSm` like to a `DeliverSm`, SMPP 5.0 peers may deliver with either.
+    fn respond_to_data_sm(&self, client: Client, sequence_number: u32, response: AutoResponse) {
+        let this = self.clone();
+
+        runtime::spawn(async move {
+            runtime::sleep(response.delay).await;
+
+            match client
+                .data_sm_resp_mapped(
+                    response.status.into(),
+                    sequence_number,
+                    DataSmResp::default(),
+                )
+                .await
+            {
+                Ok(command) => {
+                    this.outgoing_event_blink();
+                    this.push_event(Event::Sent(command));
+                }
+                Err(err) => {
+                    this.push_event(Event::Error(AppActionError::DataSmResp(err)));
+                }
+            }
+
+            this.request_repaint();
+        });
+    }
+
+    async fn handle_events(
+        &self,
+        client: Client,
+        mut events: impl Stream<Item = InsightEvent> + Unpin,
+        deliver_sm_resp: SharedAutoResponse,
+    ) {
         while let Some(event) = events.next().await {
             match event {
                 InsightEvent::Incoming(command) => {
-                    // TODO: respond to DeliverSm
                     self.incoming_event_blink();
-                    self.push_event(Event::Received(command))
+
+                    let sequence_number = command.sequence_number();
+                    let is_deliver_sm = matches!(command.pdu(), Some(Pdu::DeliverSm(_)));
+                    let is_data_sm = matches!(command.pdu(), Some(Pdu::DataSm(_)));
+
+                    // The request is logged before a response can be.
+                    self.push_event(Event::Received(command));
+
+                    if is_deliver_sm {
+                        self.respond_to_deliver_sm(
+                            client.clone(),
+                            sequence_number,
+                            *deliver_sm_resp.lock(),
+                        );
+                    } else if is_data_sm {
+                        self.respond_to_data_sm(
+                            client.clone(),
+                            sequence_number,
+                            *deliver_sm_resp.lock(),
+                        );
+                    }
                 }
                 InsightEvent::Insight(insight) => {
                     if let Some(event) = insight.into_event() {
@@ -87,6 +193,7 @@ impl BackgroundApp {
     fn builder(&self, interval: u64) -> InsightConnectionBuilder {
         Client::builder()
             .enquire_link_interval(Duration::from_secs(interval))
+            .response_timeout(RESPONSE_TIMEOUT)
             .disable_interface_version_check()
             .events()
             .insights()
@@ -180,30 +287,87 @@ impl BackgroundApp {
                     BindMode::Rx => Pdu::from(BindReceiver::from(action.bind)),
                 };
 
-                match client
-                    .send_mapped(pdu)
-                    .and_then(|(command, response)| {
-                        self.outgoing_event_blink();
-                        self.push_event(Event::Sent(command));
-                        self.request_repaint();
+                if self.send_bind(&client, pdu).await {
+                    let this = self.clone();
+                    let deliver_sm_resp = action.deliver_sm_resp;
 
-                        response
-                    })
-                    .await
-                {
-                    Err(err) => {
-                        self.push_event(Event::Error(AppActionError::Bind(err)));
-                    }
-                    Ok(response) => {
-                        self.incoming_event_blink();
-                        self.push_event(Event::Received(response));
-                        self.push_event(Event::Bound);
-                        self.set_client(client);
+                    runtime::spawn(async move {
+                        this.handle_events(client, events, deliver_sm_resp).await
+                    });
+                }
+            }
+        }
 
-                        let this = self.clone();
+        action.loading.store(false, Ordering::Relaxed);
 
-                        runtime::spawn(async move { this.handle_events(events).await });
-                    }
+        self.request_repaint();
+    }
+
+    /// Sends the bind PDU on a connected session, returns whether it got bound.
+    async fn send_bind(&self, client: &Client, pdu: Pdu) -> bool {
+        match client
+            .send_mapped(pdu)
+            .and_then(|(command, response)| {
+                self.outgoing_event_blink();
+                self.push_event(Event::Sent(command));
+                self.request_repaint();
+
+                response
+            })
+            .await
+        {
+            Err(err) => {
+                self.push_event(Event::Error(AppActionError::Bind(err)));
+
+                false
+            }
+            Ok(response) => {
+                self.incoming_event_blink();
+                self.push_event(Event::Received(response));
+                self.push_event(Event::Bound);
+                self.set_client(client.clone());
+
+                true
+            }
+        }
+    }
+
+    /// Accepts a single connection from an SMSC and binds as a receiver once it sent an `Outbind`.
+    ///
+    /// Waiting can take long, so it runs in its own task and the action loop keeps going.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn listen(&self, action: ListenAction) {
+        if self.state.client().is_some() {
+            return;
+        }
+
+        action.loading.store(true, Ordering::Relaxed);
+
+        let this = self.clone();
+
+        runtime::spawn(async move { this.run_listen(action).await });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn run_listen(&self, action: ListenAction) {
+        match self
+            .accept_outbind(action.addr, action.interval, &action.cancel)
+            .await
+        {
+            Err(err) => {
+                self.push_event(Event::Error(err));
+            }
+            Ok(None) => {}
+            Ok(Some((client, events))) => {
+                let pdu = Pdu::from(BindReceiver::from(action.bind));
+
+                if self.send_bind(&client, pdu).await {
+                    let this = self.clone();
+                    let deliver_sm_resp = action.deliver_sm_resp;
+
+                    runtime::spawn(async move {
+                        this.handle_events(client, events, deliver_sm_resp).await
+                    });
                 }
             }
         }
@@ -213,6 +377,76 @@ impl BackgroundApp {
         self.request_repaint();
     }
 
+    /// Waits for an SMSC to connect and send an `Outbind`, logging everything received until then.
+    ///
+    /// Returns `None` if cancelled.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn accept_outbind(
+        &self,
+        addr: std::net::SocketAddr,
+        interval: u64,
+        cancel: &AtomicBool,
+    ) -> Result<Option<(Client, impl Stream<Item = InsightEvent> + 'static)>, AppActionError> {
+        let deadline = Instant::now() + LISTEN_TIMEOUT;
+
+        let listener = tokio::net::TcpListener::bind(addr)
+            .await
+            .map_err(|err| AppActionError::Listen(err.into()))?;
+
+        let Some(accepted) = until_stopped(listener.accept(), deadline, cancel).await? else {
+            return Ok(None);
+        };
+
+        let (stream, _) = accepted.map_err(|err| AppActionError::Listen(err.into()))?;
+
+        let (client, mut events) = self.builder(interval).connected(stream);
+
+        self.push_event(Event::Connected);
+        self.request_repaint();
+
+        let outbind = async {
+            while let Some(event) = events.next().await {
+                match event {
+                    InsightEvent::Incoming(command) => {
+                        let outbind = matches!(command.pdu(), Some(Pdu::Outbind(_)));
+
+                        self.incoming_event_blink();
+                        self.push_event(Event::Received(command));
+                        self.request_repaint();
+
+                        if outbind {
+                            return true;
+                        }
+                    }
+                    InsightEvent::Insight(insight) => {
+                        if let Some(event) = insight.into_event() {
+                            self.push_event(event)
+                        }
+                    }
+                    InsightEvent::Error(err) => {
+                        self.push_event(Event::Error(AppActionError::Background(err)))
+                    }
+                }
+            }
+
+            false
+        };
+
+        let result = match until_stopped(outbind, deadline, cancel).await {
+            Ok(Some(true)) => return Ok(Some((client, events))),
+            Ok(Some(false)) => Err(AppActionError::Listen(anyhow::anyhow!(
+                "Connection closed before an Outbind was received"
+            ))),
+            Ok(None) => Ok(None),
+            Err(err) => Err(err),
+        };
+
+        // Dropping the client closes the connection.
+        self.push_event(Event::Disconnected);
+
+        result
+    }
+
     async fn unbind(&self, action: UnbindAction) {
         let Some(client) = self.state.client().as_ref().map(|client| client.clone()) else {
             return;
@@ -252,32 +486,236 @@ impl BackgroundApp {
         self.request_repaint();
     }
 
+    /// Sends a request and waits for its response, pushing both to the events.
+    async fn request(&self, client: &Client, pdu: impl Into<Pdu>) -> Result<Command, Error> {
+        let response = client
+            .send_mapped(pdu)
+            .and_then(|(command, response)| {
+                self.outgoing_event_blink();
+                self.push_event(Event::Sent(command));
+                self.request_repaint();
+
+                response
+            })
+            .await
+            .inspect(|response| {
+                self.incoming_event_blink();
+                self.push_event(Event::Received(response.clone()));
+            });
+
+        self.request_repaint();
+
+        response
+    }
+
+    async fn submit_sm(&self, client: &Client, sm: SubmitSm) {
+        if let Err(err) = self.request(client, sm).await {
+            self.push_event(Event::Error(AppActionError::SubmitSm(err)));
+            self.request_repaint();
+        }
+    }
+
     async fn submit_sms(&self, action: SubmitSmsAction) {
         let Some(client) = self.state.client().as_ref().map(|client| client.clone()) else {
             return;
         };
 
         for sm in action.sms {
-            _ = client
+            self.submit_sm(&client, sm).await;
+        }
+    }
+
+    /// Sends a request on behalf of a tab that shows the response.
+    async fn request_into(
+        &self,
+        pdu: impl Into<Pdu>,
+        response: ResponseSlot,
+        loading: Arc<AtomicBool>,
+        error: impl FnOnce(Error) -> AppActionError,
+    ) {
+        let Some(client) = self.state.client().as_ref().map(|client| client.clone()) else {
+            return;
+        };
+
+        loading.store(true, Ordering::Relaxed);
+
+        let result = self.request(&client, pdu).await.map_err(|err| {
+            let message = err.to_string();
+
+            self.push_event(Event::Error(error(err)));
+
+            message
+        });
+
+        *response.lock() = Some(result);
+
+        loading.store(false, Ordering::Relaxed);
+
+        self.request_repaint();
+    }
+
+    async fn query_sm(&self, action: QuerySmAction) {
+        self.request_into(
+            action.query_sm,
+            action.response,
+            action.loading,
+            AppActionError::QuerySm,
+        )
+        .await
+    }
+
+    async fn cancel_sm(&self, action: CancelSmAction) {
+        self.request_into(
+            action.cancel_sm,
+            action.response,
+            action.loading,
+            AppActionError::CancelSm,
+        )
+        .await
+    }
+
+    async fn replace_sm(&self, action: ReplaceSmAction) {
+        self.request_into(
+            action.replace_sm,
+            action.response,
+            action.loading,
+            AppActionError::ReplaceSm,
+        )
+        .await
+    }
+
+    async fn submit_multi(&self, action: SubmitMultiAction) {
+        let Some(client) = self.state.client().as_ref().map(|client| client.clone()) else {
+            return;
+        };
+
+        action.loading.store(true, Ordering::Relaxed);
+
+        for sm in action.sms {
+            let result = self.request(&client, sm).await.map_err(|err| {
+                let message = err.to_string();
+
+                self.push_event(Event::Error(AppActionError::SubmitMulti(err)));
+
+                message
+            });
+
+            action.responses.lock().push(result);
+        }
+
+        action.loading.store(false, Ordering::Relaxed);
+
+        self.request_repaint();
+    }
+
+    async fn data_sm(&self, action: DataSmAction) {
+        self.request_into(
+            action.data_sm,
+            action.response,
+            action.loading,
+            AppActionError::DataSm,
+        )
+        .await
+    }
+
+    async fn broadcast_sm(&self, action: BroadcastSmAction) {
+        self.request_into(
+            action.broadcast_sm,
+            action.response,
+            action.loading,
+            AppActionError::BroadcastSm,
+        )
+        .await
+    }
+
+    async fn query_broadcast_sm(&self, action: QueryBroadcastSmAction) {
+        self.request_into(
+            action.query_broadcast_sm,
+            action.response,
+            action.loading,
+            AppActionError::QueryBroadcastSm,
+        )
+        .await
+    }
+
+    async fn cancel_broadcast_sm(&self, action: CancelBroadcastSmAction) {
+        self.request_into(
+            action.cancel_broadcast_sm,
+            action.response,
+            action.loading,
+            AppActionError::CancelBroadcastSm,
+        )
+        .await
+    }
+
+    /// Starts the replay in its own task, so that it does not hold up other actions.
+    fn replay(&self, action: ReplayAction) {
+        let Some(client) = self.state.client().as_ref().map(|client| client.clone()) else {
+            return;
+        };
+
+        action.loading.store(true, Ordering::Relaxed);
+        self.request_repaint();
+
+        let this = self.clone();
+
+        runtime::spawn(async move { this.run_replay(client, action).await });
+    }
+
+    async fn run_replay(&self, client: Client, action: ReplayAction) {
+        let started = Instant::now();
+        let cancelled = || action.cancel.load(Ordering::Relaxed);
+
+        for (at, sm) in action.sms {
+            // Offsets are relative to the start, so the time spent sending does not add up.
+            while !cancelled() && started.elapsed() < at {
+                runtime::sleep((at - started.elapsed()).min(CANCEL_POLL_INTERVAL)).await;
+            }
+
+            if cancelled() {
+                break;
+            }
+
+            self.submit_sm_detached(&client, sm).await;
+        }
+
+        action.loading.store(false, Ordering::Relaxed);
+        self.request_repaint();
+    }
+
+    /// Sends a `SubmitSm` and waits for its response in another task, so that the next one can be sent right away.
+    async fn submit_sm_detached(&self, client: &Client, sm: SubmitSm) {
+        let (sent, is_sent) = futures::channel::oneshot::channel();
+        let client = client.clone();
+        let this = self.clone();
+
+        runtime::spawn(async move {
+            let response = client
                 .send_mapped(sm)
                 .and_then(|(command, response)| {
-                    self.outgoing_event_blink();
-                    self.push_event(Event::Sent(command));
-                    self.request_repaint();
+                    this.outgoing_event_blink();
+                    this.push_event(Event::Sent(command));
+                    this.request_repaint();
+
+                    let _ = sent.send(());
 
                     response
                 })
-                .await
-                .map(|response| {
-                    self.incoming_event_blink();
-                    self.push_event(Event::Received(response));
-                })
-                .map_err(|err| {
-                    self.push_event(Event::Error(AppActionError::SubmitSm(err)));
-                });
+                .await;
 
-            self.request_repaint();
-        }
+            match response {
+                Ok(response) => {
+                    this.incoming_event_blink();
+                    this.push_event(Event::Received(response));
+                }
+                Err(err) => this.push_event(Event::Error(AppActionError::SubmitSm(err))),
+            }
+
+            this.request_repaint();
+        });
+
+        // Messages keep their order as long as the next one is only sent after this one.
+        let _ = is_sent.await;
     }
 
     pub async fn run(self, mut actions: tokio::sync::mpsc::UnboundedReceiver<Action>) {
@@ -286,7 +724,50 @@ impl BackgroundApp {
                 Action::Bind(action) => self.bind(action).await,
                 Action::Unbind(action) => self.unbind(action).await,
                 Action::SubmitSms(action) => self.submit_sms(action).await,
+                Action::QuerySm(action) => self.query_sm(action).await,
+                Action::CancelSm(action) => self.cancel_sm(action).await,
+                Action::ReplaceSm(action) => self.replace_sm(action).await,
+                Action::SubmitMulti(action) => self.submit_multi(action).await,
+                Action::DataSm(action) => self.data_sm(action).await,
+                Action::BroadcastSm(action) => self.broadcast_sm(action).await,
+                Action::QueryBroadcastSm(action) => self.query_broadcast_sm(action).await,
+                Action::CancelBroadcastSm(action) => self.cancel_broadcast_sm(action).await,
+                Action::Replay(action) => self.replay(action),
+                #[cfg(not(target_arch = "wasm32"))]
+                Action::Listen(action) => self.listen(action),
             }
         }
     }
 }
+
+/// Runs `future` until it completes, the `deadline` of a listen passes or it is cancelled.
+///
+/// Returns `None` if cancelled.
+#[cfg(not(target_arch = "wasm32"))]
+async fn until_stopped<T>(
+    future: impl Future<Output = T>,
+    deadline: Instant,
+    cancel: &AtomicBool,
+) -> Result<Option<T>, AppActionError> {
+    let cancelled = || cancel.load(Ordering::Relaxed);
+
+    let stopped = async {
+        while !cancelled() && Instant::now() < deadline {
+            runtime::sleep(
+                deadline
+                    .saturating_duration_since(Instant::now())
+                    .min(CANCEL_POLL_INTERVAL),
+            )
+            .await;
+        }
+    };
+
+    match futures::future::select(std::pin::pin!(future), std::pin::pin!(stopped)).await {
+        Either::Left((output, _)) => Ok(Some(output)),
+        Either::Right(_) if cancelled() => Ok(None),
+        Either::Right(_) => Err(AppActionError::Listen(anyhow::anyhow!(
+            "No SMSC sent an Outbind within {} seconds",
+            LISTEN_TIMEOUT.as_secs()
+        ))),
+    }
+}
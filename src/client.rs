@@ -1,5 +1,8 @@
 use futures::TryFutureExt;
-use rusmpp::{Command, CommandStatus, Pdu};
+use rusmpp::{
+    Command, CommandStatus, Pdu,
+    pdus::{DataSmResp, DeliverSmResp},
+};
 use rusmppc::{Client, error::Error};
 
 pub trait ClientExt {
@@ -7,6 +10,20 @@ pub trait ClientExt {
         &self,
         pdu: impl Into<Pdu>,
     ) -> impl Future<Output = Result<(Command, impl Future<Output = Result<Command, Error>>), Error>>;
+
+    fn deliver_sm_resp_mapped(
+        &self,
+        status: CommandStatus,
+        sequence_number: u32,
+        pdu: DeliverSmResp,
+    ) -> impl Future<Output = Result<Command, Error>>;
+
+    fn data_sm_resp_mapped(
+        &self,
+        status: CommandStatus,
+        sequence_number: u32,
+        pdu: DataSmResp,
+    ) -> impl Future<Output = Result<Command, Error>>;
 }
 
 impl ClientExt for Client {
@@ -30,4 +47,38 @@ impl ClientExt for Client {
                 )
             })
     }
+
+    /// Sends a [`DeliverSmResp`] with the given status and sequence number and maps it into the sent [`Command`].
+    async fn deliver_sm_resp_mapped(
+        &self,
+        status: CommandStatus,
+        sequence_number: u32,
+        pdu: DeliverSmResp,
+    ) -> Result<Command, Error> {
+        self.status(status)
+            .deliver_sm_resp(sequence_number, pdu.clone())
+            .await?;
+
+        Ok(Command::builder()
+            .status(status)
+            .sequence_number(sequence_number)
+            .pdu(Pdu::from(pdu)))
+    }
+
+    /// Sends a [`DataSmResp`] with the given status and sequence number and maps it into the sent [`Command`].
+    async fn data_sm_resp_mapped(
+        &self,
+        status: CommandStatus,
+        sequence_number: u32,
+        pdu: DataSmResp,
+    ) -> Result<Command, Error> {
+        self.status(status)
+            .data_sm_resp(sequence_number, pdu.clone())
+            .await?;
+
+        Ok(Command::builder()
+            .status(status)
+            .sequence_number(sequence_number)
+            .pdu(Pdu::from(pdu)))
+    }
 }
@@ -0,0 +1,89 @@
+//! Pairing of requests with their responses
+
+use std::{collections::HashMap, time::Duration};
+
+use rusmpp::Command;
+
+use crate::values::Event;
+
+/// How long a sent request waits for its response before it is considered unanswered, also the client's response
+/// timeout
+pub const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The request answered by a response
+#[derive(Debug, Clone, Copy)]
+pub struct Request {
+    /// Id of the request record
+    pub id: u64,
+    /// Time between the request and the response
+    pub round_trip: Duration,
+}
+
+/// A request waiting for its response
+#[derive(Debug, Clone, Copy)]
+struct Pending {
+    id: u64,
+    uptime: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    Sent,
+    Received,
+}
+
+/// Pairs responses with their requests by direction and sequence number.
+///
+/// The [`EventStore`](crate::store::EventStore) runs every pushed event through its correlator and keeps the result in
+/// [`Record::request`](crate::values::Record::request), imported logs are correlated once when loaded.
+#[derive(Default)]
+pub struct Correlator {
+    /// Requests waiting for a response by direction and sequence number
+    pending: HashMap<(Direction, u32), Pending>,
+}
+
+impl Correlator {
+    /// Returns the request answered by the event, if it is a response.
+    pub fn correlate(&mut self, id: u64, uptime: Duration, event: &Event) -> Option<Request> {
+        self.pending
+            .retain(|_, pending| uptime.saturating_sub(pending.uptime) <= RESPONSE_TIMEOUT);
+
+        let (direction, command) = match event {
+            Event::Sent(command) => (Direction::Sent, command),
+            Event::Received(command) => (Direction::Received, command),
+            // Sequence numbers start over with every session.
+            Event::Connected | Event::Disconnected => {
+                self.pending.clear();
+
+                return None;
+            }
+            _ => return None,
+        };
+
+        if !is_response(command) {
+            self.pending.insert(
+                (direction, command.sequence_number()),
+                Pending { id, uptime },
+            );
+
+            return None;
+        }
+
+        let request_direction = match direction {
+            Direction::Sent => Direction::Received,
+            Direction::Received => Direction::Sent,
+        };
+
+        self.pending
+            .remove(&(request_direction, command.sequence_number()))
+            .map(|request| Request {
+                id: request.id,
+                round_trip: uptime.saturating_sub(request.uptime),
+            })
+    }
+}
+
+/// Response command ids have the most significant bit set.
+pub fn is_response(command: &Command) -> bool {
+    u32::from(command.id()) & 0x8000_0000 != 0
+}
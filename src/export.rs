@@ -0,0 +1,232 @@
+//! Export of the event log for attaching to tickets and offline analysis
+
+use serde_json::{Value, json};
+use strum::VariantArray;
+
+use crate::{
+    hex,
+    inspect::{Inspect, Node},
+    values::Record,
+    wire,
+};
+
+mod pcap;
+
+#[derive(VariantArray, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum ExportFormat {
+    #[default]
+    JsonLines,
+    Csv,
+    Pcap,
+}
+
+impl ::core::convert::From<ExportFormat> for &'static str {
+    #[inline]
+    fn from(x: ExportFormat) -> &'static str {
+        match x {
+            ExportFormat::JsonLines => "JSON Lines",
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Pcap => "pcap",
+        }
+    }
+}
+
+impl ExportFormat {
+    pub const fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::JsonLines => "jsonl",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Pcap => "pcap",
+        }
+    }
+
+    pub fn encode<'a>(self, records: impl Iterator<Item = &'a Record>) -> Vec<u8> {
+        match self {
+            ExportFormat::JsonLines => json_lines(records),
+            ExportFormat::Csv => csv(records),
+            ExportFormat::Pcap => pcap::encode(records),
+        }
+    }
+}
+
+fn json_lines<'a>(records: impl Iterator<Item = &'a Record>) -> Vec<u8> {
+    records.map(json_line).collect::<String>().into_bytes()
+}
+
+/// One JSON object with the decoded fields and the wire bytes of commands, terminated by a newline.
+pub fn json_line(record: &Record) -> String {
+    let mut object = json!({
+        "id": record.id,
+        "time": record.time.to_rfc3339(),
+        "uptime_ms": record.uptime.as_secs_f64() * 1000.0,
+        "kind": record.event.kind(),
+        "summary": record.event.summary(),
+    });
+
+    if let Some(command) = record.event.command() {
+        object["command"] = json!({
+            "command_id": format!("{:?}", command.id()),
+            "command_status": format!("{:?}", command.status()),
+            "sequence_number": command.sequence_number(),
+            "fields": command.inspect().iter().map(node_json).collect::<Vec<_>>(),
+            "hex": hex::encode(&wire::encode(command)),
+        });
+    }
+
+    let mut line = object.to_string();
+
+    line.push('\n');
+
+    line
+}
+
+fn node_json(node: &Node) -> Value {
+    let mut object = json!({
+        "name": node.name,
+        "value": node.value,
+    });
+
+    if !node.children.is_empty() {
+        object["children"] = node.children.iter().map(node_json).collect();
+    }
+
+    object
+}
+
+const CSV_HEADER: [&str; 13] = [
+    "id",
+    "time",
+    "uptime_ms",
+    "kind",
+    "command_id",
+    "command_status",
+    "sequence_number",
+    "source_addr",
+    "destination_addr",
+    "message_id",
+    "short_message",
+    "summary",
+    "hex",
+];
+
+/// One row per record, with the most common fields flattened into columns.
+fn csv<'a>(records: impl Iterator<Item = &'a Record>) -> Vec<u8> {
+    let mut text = csv_row(CSV_HEADER.map(String::from));
+
+    for record in records {
+        let command = record.event.command();
+        let nodes = command.map(Inspect::inspect).unwrap_or_default();
+        let field = |name: &str| find(&nodes, name).unwrap_or_default();
+
+        text.push_str(&csv_row([
+            record.id.to_string(),
+            record.time.to_rfc3339(),
+            format!("{:.3}", record.uptime.as_secs_f64() * 1000.0),
+            record.event.kind().to_string(),
+            command
+                .map(|command| format!("{:?}", command.id()))
+                .unwrap_or_default(),
+            command
+                .map(|command| format!("{:?}", command.status()))
+                .unwrap_or_default(),
+            command
+                .map(|command| command.sequence_number().to_string())
+                .unwrap_or_default(),
+            field("source_addr"),
+            field("destination_addr"),
+            field("message_id"),
+            field("short_message"),
+            record.event.summary(),
+            command
+                .map(|command| hex::encode(&wire::encode(command)))
+                .unwrap_or_default(),
+        ]));
+    }
+
+    text.into_bytes()
+}
+
+fn csv_row<const N: usize>(fields: [String; N]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    row.push_str("\r\n");
+
+    row
+}
+
+/// Value of the first node with the given name, depth first.
+fn find(nodes: &[Node], name: &str) -> Option<String> {
+    nodes.iter().find_map(|node| {
+        if node.name == name {
+            return Some(node.value.clone());
+        }
+
+        find(&node.children, name)
+    })
+}
+
+/// Opens a save dialog and writes the file.
+///
+/// Returns `Ok(false)` if the dialog was cancelled.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save(file_name: &str, bytes: &[u8]) -> anyhow::Result<bool> {
+    let Some(path) = rfd::FileDialog::new().set_file_name(file_name).save_file() else {
+        return Ok(false);
+    };
+
+    std::fs::write(path, bytes)?;
+
+    Ok(true)
+}
+
+/// Triggers a browser download of the file.
+#[cfg(target_arch = "wasm32")]
+pub fn save(file_name: &str, bytes: &[u8]) -> anyhow::Result<bool> {
+    use wasm_bindgen::JsCast;
+
+    let js_err = |err: wasm_bindgen::JsValue| anyhow::anyhow!("{err:?}");
+
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let blob = web_sys::Blob::new_with_u8_array_sequence(&parts).map_err(js_err)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(js_err)?;
+
+    let anchor = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| anyhow::anyhow!("No document available"))?
+        .create_element("a")
+        .map_err(js_err)?
+        .dyn_into::<web_sys::HtmlAnchorElement>()
+        .map_err(|_| anyhow::anyhow!("Failed to create download link"))?;
+
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    // Revoking right away may cancel the download in some browsers.
+    crate::runtime::spawn(async move {
+        crate::runtime::sleep(std::time::Duration::from_secs(1)).await;
+
+        let _ = web_sys::Url::revoke_object_url(&url);
+    });
+
+    Ok(true)
+}
+
+/// Default file name for an export, e.g. `rusmppc-20250101-120000.jsonl`
+pub fn file_name(format: ExportFormat) -> String {
+    format!(
+        "rusmppc-{}.{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S"),
+        format.extension()
+    )
+}
@@ -0,0 +1,222 @@
+//! Classic pcap with synthetic IPv4/TCP framing, so that Wireshark's SMPP dissector can decode the commands.
+
+use crate::{
+    values::{Event, Record},
+    wire,
+};
+
+/// `LINKTYPE_RAW`, packets start with the IP header
+const LINKTYPE_RAW: u32 = 101;
+
+const ESME_ADDR: [u8; 4] = [10, 0, 0, 1];
+const MC_ADDR: [u8; 4] = [10, 0, 0, 2];
+/// Registered SMPP port, decoded as SMPP by Wireshark without any configuration
+const MC_PORT: u16 = 2775;
+const FIRST_ESME_PORT: u16 = 50000;
+
+const MAX_SEGMENT_SIZE: usize = 1460;
+
+const SYN: u8 = 0x02;
+const PSH: u8 = 0x08;
+const ACK: u8 = 0x10;
+
+#[derive(Clone, Copy)]
+enum Direction {
+    ToMc,
+    ToEsme,
+}
+
+/// One synthetic TCP connection per session
+struct Stream {
+    esme_port: u16,
+    esme_seq: u32,
+    mc_seq: u32,
+}
+
+struct Writer {
+    bytes: Vec<u8>,
+    ip_id: u16,
+}
+
+pub fn encode<'a>(records: impl Iterator<Item = &'a Record>) -> Vec<u8> {
+    let mut writer = Writer::new();
+    let mut stream: Option<Stream> = None;
+    let mut next_port = FIRST_ESME_PORT;
+
+    for record in records {
+        let (direction, command) = match &record.event {
+            Event::Connected => {
+                stream = None;
+
+                continue;
+            }
+            Event::Sent(command) => (Direction::ToMc, command),
+            Event::Received(command) => (Direction::ToEsme, command),
+            _ => continue,
+        };
+
+        let stream = stream.get_or_insert_with(|| {
+            let stream = writer.handshake(record, next_port);
+
+            next_port = next_port.wrapping_add(1).max(FIRST_ESME_PORT);
+
+            stream
+        });
+
+        let payload = wire::encode(command);
+
+        for segment in payload.chunks(MAX_SEGMENT_SIZE) {
+            writer.segment(record, stream, direction, PSH | ACK, segment);
+        }
+    }
+
+    writer.bytes
+}
+
+impl Writer {
+    fn new() -> Self {
+        let mut bytes = Vec::new();
+
+        bytes.extend(0xA1B2_C3D4_u32.to_le_bytes());
+        bytes.extend(2_u16.to_le_bytes());
+        bytes.extend(4_u16.to_le_bytes());
+        // thiszone and sigfigs
+        bytes.extend(0_i32.to_le_bytes());
+        bytes.extend(0_u32.to_le_bytes());
+        // snaplen
+        bytes.extend(65_535_u32.to_le_bytes());
+        bytes.extend(LINKTYPE_RAW.to_le_bytes());
+
+        Self { bytes, ip_id: 0 }
+    }
+
+    fn handshake(&mut self, record: &Record, esme_port: u16) -> Stream {
+        let mut stream = Stream {
+            esme_port,
+            esme_seq: 0,
+            mc_seq: 0,
+        };
+
+        self.segment(record, &mut stream, Direction::ToMc, SYN, &[]);
+        self.segment(record, &mut stream, Direction::ToEsme, SYN | ACK, &[]);
+        self.segment(record, &mut stream, Direction::ToMc, ACK, &[]);
+
+        stream
+    }
+
+    fn segment(
+        &mut self,
+        record: &Record,
+        stream: &mut Stream,
+        direction: Direction,
+        flags: u8,
+        payload: &[u8],
+    ) {
+        let (src, dst, src_port, dst_port, seq, ack) = match direction {
+            Direction::ToMc => (
+                ESME_ADDR,
+                MC_ADDR,
+                stream.esme_port,
+                MC_PORT,
+                stream.esme_seq,
+                stream.mc_seq,
+            ),
+            Direction::ToEsme => (
+                MC_ADDR,
+                ESME_ADDR,
+                MC_PORT,
+                stream.esme_port,
+                stream.mc_seq,
+                stream.esme_seq,
+            ),
+        };
+
+        // SYN consumes one sequence number.
+        let advance = payload.len() as u32 + u32::from(flags & SYN != 0);
+
+        match direction {
+            Direction::ToMc => stream.esme_seq = stream.esme_seq.wrapping_add(advance),
+            Direction::ToEsme => stream.mc_seq = stream.mc_seq.wrapping_add(advance),
+        }
+
+        let ack = if flags & ACK != 0 { ack } else { 0 };
+
+        let mut tcp = Vec::with_capacity(20 + payload.len());
+
+        tcp.extend(src_port.to_be_bytes());
+        tcp.extend(dst_port.to_be_bytes());
+        tcp.extend(seq.to_be_bytes());
+        tcp.extend(ack.to_be_bytes());
+        // data offset of 5 words, no options
+        tcp.push(5 << 4);
+        tcp.push(flags);
+        tcp.extend(65_535_u16.to_be_bytes());
+        // checksum, filled below
+        tcp.extend([0, 0]);
+        // urgent pointer
+        tcp.extend([0, 0]);
+        tcp.extend(payload);
+
+        let mut pseudo = Vec::with_capacity(12 + tcp.len());
+
+        pseudo.extend(src);
+        pseudo.extend(dst);
+        pseudo.extend([0, 6]);
+        pseudo.extend((tcp.len() as u16).to_be_bytes());
+        pseudo.extend(&tcp);
+
+        tcp[16..18].copy_from_slice(&checksum(&pseudo).to_be_bytes());
+
+        let mut ip = Vec::with_capacity(20 + tcp.len());
+
+        ip.push(0x45);
+        ip.push(0);
+        ip.extend(((20 + tcp.len()) as u16).to_be_bytes());
+        ip.extend(self.ip_id.to_be_bytes());
+        // don't fragment
+        ip.extend(0x4000_u16.to_be_bytes());
+        // ttl and protocol
+        ip.extend([64, 6]);
+        // checksum, filled below
+        ip.extend([0, 0]);
+        ip.extend(src);
+        ip.extend(dst);
+
+        let header_checksum = checksum(&ip);
+
+        ip[10..12].copy_from_slice(&header_checksum.to_be_bytes());
+        ip.extend(tcp);
+
+        self.ip_id = self.ip_id.wrapping_add(1);
+        self.packet(record, &ip);
+    }
+
+    fn packet(&mut self, record: &Record, packet: &[u8]) {
+        let seconds = record.time.timestamp() as u32;
+        let micros = record.time.timestamp_subsec_micros();
+
+        self.bytes.extend(seconds.to_le_bytes());
+        self.bytes.extend(micros.to_le_bytes());
+        self.bytes.extend((packet.len() as u32).to_le_bytes());
+        self.bytes.extend((packet.len() as u32).to_le_bytes());
+        self.bytes.extend(packet);
+    }
+}
+
+/// Internet checksum (RFC 1071)
+fn checksum(bytes: &[u8]) -> u16 {
+    let mut sum = bytes
+        .chunks(2)
+        .map(|chunk| match chunk {
+            [high, low] => u32::from(u16::from_be_bytes([*high, *low])),
+            [high] => u32::from(*high) << 8,
+            _ => 0,
+        })
+        .sum::<u32>();
+
+    while sum > 0xFFFF {
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+
+    !(sum as u16)
+}
@@ -0,0 +1,493 @@
+//! GSM 7-bit default alphabet, extension table and national language shift tables (3GPP TS 23.038)
+
+mod indian;
+
+use indian::*;
+
+/// Escape to the extension table
+pub const ESCAPE: u8 = 0x1B;
+
+/// User data header IE selecting the national single shift table
+pub const SINGLE_SHIFT_IEI: u8 = 0x24;
+
+/// User data header IE selecting the national locking shift table
+pub const LOCKING_SHIFT_IEI: u8 = 0x25;
+
+/// Default alphabet indexed by septet value.
+///
+/// The escape septet `0x1B` is mapped to a non-breaking space, it never appears on its own in decoded text.
+pub const DEFAULT_ALPHABET: [char; 128] = [
+    '@', '£', '$', '¥', 'è', 'é', 'ù', 'ì', 'ò', 'Ç', '\n', 'Ø', 'ø', '\r', 'Å', 'å', //
+    'Δ', '_', 'Φ', 'Γ', 'Λ', 'Ω', 'Π', 'Ψ', 'Σ', 'Θ', 'Ξ', '\u{A0}', 'Æ', 'æ', 'ß', 'É', //
+    ' ', '!', '"', '#', '¤', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/', //
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?', //
+    '¡', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', //
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'Ä', 'Ö', 'Ñ', 'Ü', '§', //
+    '¿', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'ä', 'ö', 'ñ', 'ü', 'à', //
+];
+
+/// Reserved septet of a national table, never encoded
+const RESERVED: char = char::REPLACEMENT_CHARACTER;
+
+/// Extension table as `(septet, char)`, each entry is preceded by [`ESCAPE`] on the wire.
+pub const EXTENSION_TABLE: [(u8, char); 10] = [
+    (0x0A, '\u{C}'),
+    (0x14, '^'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x65, '€'),
+];
+
+/// Turkish locking shift table
+const TURKISH_LOCKING_SHIFT: [char; 128] = [
+    '@', '£', '$', '¥', '€', 'é', 'ù', 'ı', 'ò', 'Ç', '\n', 'Ğ', 'ğ', '\r', 'Å', 'å', //
+    'Δ', '_', 'Φ', 'Γ', 'Λ', 'Ω', 'Π', 'Ψ', 'Σ', 'Θ', 'Ξ', '\u{A0}', 'Ş', 'ş', 'ß', 'É', //
+    ' ', '!', '"', '#', '¤', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/', //
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?', //
+    'İ', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', //
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'Ä', 'Ö', 'Ñ', 'Ü', '§', //
+    'ç', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'ä', 'ö', 'ñ', 'ü', 'à', //
+];
+
+/// Portuguese locking shift table
+const PORTUGUESE_LOCKING_SHIFT: [char; 128] = [
+    '@', '£', '$', '¥', 'ê', 'é', 'ú', 'í', 'ó', 'ç', '\n', 'Ô', 'ô', '\r', 'Á', 'á', //
+    'Δ', '_', 'ª', 'Ç', 'À', '∞', '^', '\\', '€', 'Ó', '|', '\u{A0}', 'Â', 'â', 'Ê', 'É', //
+    ' ', '!', '"', '#', 'º', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/', //
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?', //
+    'Í', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', //
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'Ã', 'Õ', 'Ú', 'Ü', '§', //
+    '~', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'ã', 'õ', '`', 'ü', 'à', //
+];
+
+/// Turkish single shift table
+const TURKISH_SINGLE_SHIFT: [(u8, char); 17] = [
+    (0x0A, '\u{C}'),
+    (0x14, '^'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x47, 'Ğ'),
+    (0x49, 'İ'),
+    (0x53, 'Ş'),
+    (0x63, 'ç'),
+    (0x65, '€'),
+    (0x67, 'ğ'),
+    (0x69, 'ı'),
+    (0x73, 'ş'),
+];
+
+/// Spanish single shift table, Spanish has no locking shift table
+const SPANISH_SINGLE_SHIFT: [(u8, char); 19] = [
+    (0x09, 'ç'),
+    (0x0A, '\u{C}'),
+    (0x14, '^'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'Á'),
+    (0x49, 'Í'),
+    (0x4F, 'Ó'),
+    (0x55, 'Ú'),
+    (0x61, 'á'),
+    (0x65, '€'),
+    (0x69, 'í'),
+    (0x6F, 'ó'),
+    (0x75, 'ú'),
+];
+
+/// Portuguese single shift table
+const PORTUGUESE_SINGLE_SHIFT: [(u8, char); 37] = [
+    (0x05, 'ê'),
+    (0x09, 'ç'),
+    (0x0A, '\u{C}'),
+    (0x0B, 'Ô'),
+    (0x0C, 'ô'),
+    (0x0E, 'Á'),
+    (0x0F, 'á'),
+    (0x12, 'Φ'),
+    (0x13, 'Γ'),
+    (0x14, '^'),
+    (0x15, 'Ω'),
+    (0x16, 'Π'),
+    (0x17, 'Ψ'),
+    (0x18, 'Σ'),
+    (0x19, 'Θ'),
+    (0x1F, 'Ê'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'À'),
+    (0x49, 'Í'),
+    (0x4F, 'Ó'),
+    (0x55, 'Ú'),
+    (0x5B, 'Ã'),
+    (0x5C, 'Õ'),
+    (0x61, 'Â'),
+    (0x65, '€'),
+    (0x69, 'í'),
+    (0x6F, 'ó'),
+    (0x75, 'ú'),
+    (0x7B, 'ã'),
+    (0x7C, 'õ'),
+    (0x7F, 'â'),
+];
+
+/// GSM-safe lookalike of a character outside of the default tables
+fn lookalike(c: char) -> Option<&'static str> {
+    let lookalike = match c {
+        '‘' | '’' | '‚' | '‛' | '′' | '`' | '´' => "'",
+        '“' | '”' | '„' | '‟' | '″' | '«' | '»' => "\"",
+        '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' => "-",
+        '…' => "...",
+        '•' | '·' => "*",
+        '×' => "x",
+        '÷' => "/",
+        '©' => "(c)",
+        '®' => "(R)",
+        '™' => "TM",
+        '\t' | '\u{A0}' | '\u{2002}'..='\u{200A}' | '\u{202F}' | '\u{3000}' => " ",
+        '\u{200B}' | '\u{FEFF}' => "",
+        'á' | 'â' | 'ã' | 'ā' | 'ª' => "a",
+        'Á' | 'Â' | 'Ã' | 'À' | 'Ā' => "A",
+        'ç' | 'č' | 'ć' => "c",
+        'Č' | 'Ć' => "C",
+        'ê' | 'ë' | 'ē' | 'ę' => "e",
+        'È' | 'Ê' | 'Ë' | 'Ē' | 'Ę' => "E",
+        'ğ' => "g",
+        'Ğ' => "G",
+        'í' | 'î' | 'ï' | 'ı' => "i",
+        'Í' | 'Î' | 'Ï' | 'Ì' | 'İ' => "I",
+        'ł' => "l",
+        'Ł' => "L",
+        'ó' | 'ô' | 'õ' | 'º' => "o",
+        'Ó' | 'Ô' | 'Õ' | 'Ò' => "O",
+        'œ' => "oe",
+        'Œ' => "OE",
+        'š' | 'ş' | 'ś' => "s",
+        'Š' | 'Ş' | 'Ś' => "S",
+        'ú' | 'û' => "u",
+        'Ú' | 'Û' | 'Ù' => "U",
+        'ý' | 'ÿ' => "y",
+        'Ý' | 'Ÿ' => "Y",
+        'ž' | 'ź' | 'ż' => "z",
+        'Ž' | 'Ź' | 'Ż' => "Z",
+        _ => return None,
+    };
+
+    Some(lookalike)
+}
+
+/// Languages with national shift tables, valued by their identifier in the shift IEs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NationalLanguage {
+    Turkish = 0x01,
+    Spanish = 0x02,
+    Portuguese = 0x03,
+    Bengali = 0x04,
+    Gujarati = 0x05,
+    Hindi = 0x06,
+    Kannada = 0x07,
+    Malayalam = 0x08,
+    Oriya = 0x09,
+    Punjabi = 0x0A,
+    Tamil = 0x0B,
+    Telugu = 0x0C,
+    Urdu = 0x0D,
+}
+
+impl NationalLanguage {
+    pub const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0x01 => Some(Self::Turkish),
+            0x02 => Some(Self::Spanish),
+            0x03 => Some(Self::Portuguese),
+            0x04 => Some(Self::Bengali),
+            0x05 => Some(Self::Gujarati),
+            0x06 => Some(Self::Hindi),
+            0x07 => Some(Self::Kannada),
+            0x08 => Some(Self::Malayalam),
+            0x09 => Some(Self::Oriya),
+            0x0A => Some(Self::Punjabi),
+            0x0B => Some(Self::Tamil),
+            0x0C => Some(Self::Telugu),
+            0x0D => Some(Self::Urdu),
+            _ => None,
+        }
+    }
+
+    pub const fn id(self) -> u8 {
+        self as u8
+    }
+
+    /// [`None`] for Spanish, which only has a single shift table
+    pub const fn locking_shift_table(self) -> Option<&'static [char; 128]> {
+        match self {
+            Self::Turkish => Some(&TURKISH_LOCKING_SHIFT),
+            Self::Spanish => None,
+            Self::Portuguese => Some(&PORTUGUESE_LOCKING_SHIFT),
+            Self::Bengali => Some(&BENGALI_LOCKING_SHIFT),
+            Self::Gujarati => Some(&GUJARATI_LOCKING_SHIFT),
+            Self::Hindi => Some(&HINDI_LOCKING_SHIFT),
+            Self::Kannada => Some(&KANNADA_LOCKING_SHIFT),
+            Self::Malayalam => Some(&MALAYALAM_LOCKING_SHIFT),
+            Self::Oriya => Some(&ORIYA_LOCKING_SHIFT),
+            Self::Punjabi => Some(&PUNJABI_LOCKING_SHIFT),
+            Self::Tamil => Some(&TAMIL_LOCKING_SHIFT),
+            Self::Telugu => Some(&TELUGU_LOCKING_SHIFT),
+            Self::Urdu => Some(&URDU_LOCKING_SHIFT),
+        }
+    }
+
+    pub const fn single_shift_table(self) -> &'static [(u8, char)] {
+        match self {
+            Self::Turkish => &TURKISH_SINGLE_SHIFT,
+            Self::Spanish => &SPANISH_SINGLE_SHIFT,
+            Self::Portuguese => &PORTUGUESE_SINGLE_SHIFT,
+            Self::Bengali => &BENGALI_SINGLE_SHIFT,
+            Self::Gujarati => &GUJARATI_SINGLE_SHIFT,
+            Self::Hindi => &HINDI_SINGLE_SHIFT,
+            Self::Kannada => &KANNADA_SINGLE_SHIFT,
+            Self::Malayalam => &MALAYALAM_SINGLE_SHIFT,
+            Self::Oriya => &ORIYA_SINGLE_SHIFT,
+            Self::Punjabi => &PUNJABI_SINGLE_SHIFT,
+            Self::Tamil => &TAMIL_SINGLE_SHIFT,
+            Self::Telugu => &TELUGU_SINGLE_SHIFT,
+            Self::Urdu => &URDU_SINGLE_SHIFT,
+        }
+    }
+}
+
+/// The tables a message is encoded with, the default alphabet and extension table unless shifted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Alphabet {
+    pub locking_shift: Option<NationalLanguage>,
+    pub single_shift: Option<NationalLanguage>,
+}
+
+impl Alphabet {
+    pub const fn new(
+        locking_shift: Option<NationalLanguage>,
+        single_shift: Option<NationalLanguage>,
+    ) -> Self {
+        Self {
+            locking_shift,
+            single_shift,
+        }
+    }
+
+    /// Reads the shift IEs of a user data header, unknown languages keep the default tables.
+    pub fn from_udh(udh: &[u8]) -> Self {
+        let mut alphabet = Self::default();
+        let mut ies = udh.get(1..).unwrap_or_default();
+
+        while let [iei, iel, rest @ ..] = ies {
+            let len = (*iel as usize).min(rest.len());
+            let (data, tail) = rest.split_at(len);
+
+            match (*iei, data) {
+                (LOCKING_SHIFT_IEI, [id]) => {
+                    alphabet.locking_shift = NationalLanguage::from_id(*id)
+                }
+                (SINGLE_SHIFT_IEI, [id]) => alphabet.single_shift = NationalLanguage::from_id(*id),
+                _ => {}
+            }
+
+            ies = tail;
+        }
+
+        alphabet
+    }
+
+    const fn basic_table(&self) -> &'static [char; 128] {
+        match self.locking_shift {
+            Some(language) => match language.locking_shift_table() {
+                Some(table) => table,
+                None => &DEFAULT_ALPHABET,
+            },
+            None => &DEFAULT_ALPHABET,
+        }
+    }
+
+    const fn extension_table(&self) -> &'static [(u8, char)] {
+        match self.single_shift {
+            Some(language) => language.single_shift_table(),
+            None => &EXTENSION_TABLE,
+        }
+    }
+
+    /// The shift IEs announcing the tables, empty for the default alphabet
+    pub fn udh_ies(&self) -> Vec<u8> {
+        let mut ies = Vec::new();
+
+        if let Some(language) = self.single_shift {
+            ies.extend([SINGLE_SHIFT_IEI, 0x01, language.id()]);
+        }
+
+        if let Some(language) = self
+            .locking_shift
+            .filter(|language| language.locking_shift_table().is_some())
+        {
+            ies.extend([LOCKING_SHIFT_IEI, 0x01, language.id()]);
+        }
+
+        ies
+    }
+
+    /// Decodes unpacked septets, one per octet.
+    ///
+    /// Unknown extension septets are decoded from the basic table, as recommended by the specification.
+    pub fn decode_unpacked(&self, bytes: &[u8]) -> String {
+        let basic_table = self.basic_table();
+        let mut text = String::with_capacity(bytes.len());
+        let mut bytes = bytes.iter().map(|byte| byte & 0x7F);
+
+        while let Some(septet) = bytes.next() {
+            if septet != ESCAPE {
+                text.push(basic_table[septet as usize]);
+
+                continue;
+            }
+
+            let Some(septet) = bytes.next() else {
+                break;
+            };
+
+            match self
+                .extension_table()
+                .iter()
+                .find(|(value, _)| *value == septet)
+            {
+                Some((_, c)) => text.push(*c),
+                None => text.push(basic_table[septet as usize]),
+            }
+        }
+
+        text
+    }
+
+    /// Replaces the characters outside of the tables with their lookalike, if it is inside.
+    pub fn transliterate(&self, text: &str) -> String {
+        let mut transliterated = String::with_capacity(text.len());
+
+        for c in text.chars() {
+            match self.lookalike(c) {
+                Some(lookalike) => transliterated.push_str(lookalike),
+                None => transliterated.push(c),
+            }
+        }
+
+        transliterated
+    }
+
+    /// Whether [`Alphabet::transliterate`] would replace anything
+    pub fn has_lookalikes(&self, text: &str) -> bool {
+        text.chars().any(|c| self.lookalike(c).is_some())
+    }
+
+    fn lookalike(&self, c: char) -> Option<&'static str> {
+        if self.encode_char(c).is_some() {
+            return None;
+        }
+
+        lookalike(c).filter(|lookalike| lookalike.chars().all(|c| self.encode_char(c).is_some()))
+    }
+
+    /// Septets of a character, preceded by [`ESCAPE`] for the extension table.
+    ///
+    /// Returns [`None`] if the character is in neither table.
+    pub fn encode_char(&self, c: char) -> Option<Vec<u8>> {
+        if let Some(septet) = self
+            .basic_table()
+            .iter()
+            .position(|alphabet_char| *alphabet_char == c && c != '\u{A0}' && c != RESERVED)
+        {
+            return Some(vec![septet as u8]);
+        }
+
+        self.extension_table()
+            .iter()
+            .find(|(_, extension_char)| *extension_char == c)
+            .map(|(septet, _)| vec![ESCAPE, *septet])
+    }
+}
+
+/// Carriage return, pads seven spare bits so they are not read as `@`
+const CR: u8 = 0x0D;
+
+/// Fill bits aligning the first septet after a UDH of `octets` in packed GSM 7-bit.
+pub const fn fill_bits(octets: usize) -> usize {
+    (7 - octets * 8 % 7) % 7
+}
+
+/// Packs septets into octets, least significant bit first, after `fill_bits` zero bits.
+///
+/// The fill bits align the first septet on a septet boundary after a user data header.
+pub fn pack(septets: &[u8], fill_bits: usize) -> Vec<u8> {
+    if (fill_bits + septets.len() * 7) % 8 == 1 {
+        let mut padded = septets.to_vec();
+
+        padded.push(CR);
+
+        return pack(&padded, fill_bits);
+    }
+
+    let bits = fill_bits + septets.len() * 7;
+    let mut octets = vec![0u8; bits.div_ceil(8)];
+
+    for (index, septet) in septets.iter().enumerate() {
+        let position = fill_bits + index * 7;
+        let (octet, shift) = (position / 8, position % 8);
+        let value = u16::from(septet & 0x7F) << shift;
+
+        octets[octet] |= value as u8;
+
+        if shift > 1 {
+            octets[octet + 1] |= (value >> 8) as u8;
+        }
+    }
+
+    octets
+}
+
+/// Unpacks the septets of [`pack`], a trailing padding CR is dropped.
+pub fn unpack(octets: &[u8], fill_bits: usize) -> Vec<u8> {
+    let bits = (octets.len() * 8).saturating_sub(fill_bits);
+
+    let mut septets: Vec<u8> = (0..bits / 7)
+        .map(|index| {
+            let position = fill_bits + index * 7;
+            let (octet, shift) = (position / 8, position % 8);
+            let low = u16::from(octets[octet]);
+            let high = u16::from(octets.get(octet + 1).copied().unwrap_or_default());
+
+            (((high << 8 | low) >> shift) & 0x7F) as u8
+        })
+        .collect();
+
+    if bits.is_multiple_of(7) && septets.last() == Some(&CR) {
+        septets.pop();
+    }
+
+    septets
+}
@@ -0,0 +1,1107 @@
+//! National language shift tables of the Indian languages (3GPP TS 23.038 A.2.4 to A.2.13 and A.3.4 to A.3.13)
+//!
+//! Septets the specification leaves reserved decode as [`RESERVED`].
+
+use super::RESERVED;
+
+/// Bengali locking shift table
+pub(super) const BENGALI_LOCKING_SHIFT: [char; 128] = [
+    '\u{0981}', '\u{0982}', '\u{0983}', '\u{0985}', '\u{0986}', '\u{0987}', '\u{0988}',
+    '\u{0989}', //
+    '\u{098A}', '\u{098B}', '\n', '\u{098C}', RESERVED, '\r', RESERVED, '\u{098F}', //
+    '\u{0990}', RESERVED, RESERVED, '\u{0993}', '\u{0994}', '\u{0995}', '\u{0996}',
+    '\u{0997}', //
+    '\u{0998}', '\u{0999}', '\u{099A}', '\u{A0}', '\u{099B}', '\u{099C}', '\u{099D}',
+    '\u{099E}', //
+    ' ', '!', '\u{099F}', '\u{09A0}', '\u{09A1}', '\u{09A2}', '\u{09A3}', '\u{09A4}', //
+    ')', '(', '\u{09A5}', '\u{09A6}', ',', '\u{09A7}', '.', '\u{09A8}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', RESERVED, '\u{09AA}', '\u{09AB}', '?', //
+    '\u{09AC}', '\u{09AD}', '\u{09AE}', '\u{09AF}', '\u{09B0}', RESERVED, '\u{09B2}',
+    RESERVED, //
+    RESERVED, RESERVED, '\u{09B6}', '\u{09B7}', '\u{09B8}', '\u{09B9}', '\u{09BC}',
+    '\u{09BD}', //
+    '\u{09BE}', '\u{09BF}', '\u{09C0}', '\u{09C1}', '\u{09C2}', '\u{09C3}', '\u{09C4}',
+    RESERVED, //
+    RESERVED, '\u{09C7}', '\u{09C8}', RESERVED, RESERVED, '\u{09CB}', '\u{09CC}',
+    '\u{09CD}', //
+    '\u{09CE}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{09D7}', '\u{09DC}', '\u{09DD}', '\u{09F0}', '\u{09F1}', //
+];
+
+/// Gujarati locking shift table
+pub(super) const GUJARATI_LOCKING_SHIFT: [char; 128] = [
+    '\u{0A81}', '\u{0A82}', '\u{0A83}', '\u{0A85}', '\u{0A86}', '\u{0A87}', '\u{0A88}',
+    '\u{0A89}', //
+    '\u{0A8A}', '\u{0A8B}', '\n', '\u{0A8C}', '\u{0A8D}', '\r', RESERVED, '\u{0A8F}', //
+    '\u{0A90}', '\u{0A91}', RESERVED, '\u{0A93}', '\u{0A94}', '\u{0A95}', '\u{0A96}',
+    '\u{0A97}', //
+    '\u{0A98}', '\u{0A99}', '\u{0A9A}', '\u{A0}', '\u{0A9B}', '\u{0A9C}', '\u{0A9D}',
+    '\u{0A9E}', //
+    ' ', '!', '\u{0A9F}', '\u{0AA0}', '\u{0AA1}', '\u{0AA2}', '\u{0AA3}', '\u{0AA4}', //
+    ')', '(', '\u{0AA5}', '\u{0AA6}', ',', '\u{0AA7}', '.', '\u{0AA8}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', RESERVED, '\u{0AAA}', '\u{0AAB}', '?', //
+    '\u{0AAC}', '\u{0AAD}', '\u{0AAE}', '\u{0AAF}', '\u{0AB0}', RESERVED, '\u{0AB2}',
+    '\u{0AB3}', //
+    RESERVED, '\u{0AB5}', '\u{0AB6}', '\u{0AB7}', '\u{0AB8}', '\u{0AB9}', '\u{0ABC}',
+    '\u{0ABD}', //
+    '\u{0ABE}', '\u{0ABF}', '\u{0AC0}', '\u{0AC1}', '\u{0AC2}', '\u{0AC3}', '\u{0AC4}',
+    '\u{0AC5}', //
+    RESERVED, '\u{0AC7}', '\u{0AC8}', '\u{0AC9}', RESERVED, '\u{0ACB}', '\u{0ACC}',
+    '\u{0ACD}', //
+    '\u{0AD0}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0AE0}', '\u{0AE1}', '\u{0AE2}', '\u{0AE3}', '\u{0AF1}', //
+];
+
+/// Hindi locking shift table
+pub(super) const HINDI_LOCKING_SHIFT: [char; 128] = [
+    '\u{0901}', '\u{0902}', '\u{0903}', '\u{0905}', '\u{0906}', '\u{0907}', '\u{0908}',
+    '\u{0909}', //
+    '\u{090A}', '\u{090B}', '\n', '\u{090C}', '\u{090D}', '\r', '\u{090E}', '\u{090F}', //
+    '\u{0910}', '\u{0911}', '\u{0912}', '\u{0913}', '\u{0914}', '\u{0915}', '\u{0916}',
+    '\u{0917}', //
+    '\u{0918}', '\u{0919}', '\u{091A}', '\u{A0}', '\u{091B}', '\u{091C}', '\u{091D}',
+    '\u{091E}', //
+    ' ', '!', '\u{091F}', '\u{0920}', '\u{0921}', '\u{0922}', '\u{0923}', '\u{0924}', //
+    ')', '(', '\u{0925}', '\u{0926}', ',', '\u{0927}', '.', '\u{0928}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', '\u{0929}', '\u{092A}', '\u{092B}', '?', //
+    '\u{092C}', '\u{092D}', '\u{092E}', '\u{092F}', '\u{0930}', '\u{0931}', '\u{0932}',
+    '\u{0933}', //
+    '\u{0934}', '\u{0935}', '\u{0936}', '\u{0937}', '\u{0938}', '\u{0939}', '\u{093C}',
+    '\u{093D}', //
+    '\u{093E}', '\u{093F}', '\u{0940}', '\u{0941}', '\u{0942}', '\u{0943}', '\u{0944}',
+    '\u{0945}', //
+    '\u{0946}', '\u{0947}', '\u{0948}', '\u{0949}', '\u{094A}', '\u{094B}', '\u{094C}',
+    '\u{094D}', //
+    '\u{0950}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0972}', '\u{097B}', '\u{097C}', '\u{097E}', '\u{097F}', //
+];
+
+/// Kannada locking shift table
+pub(super) const KANNADA_LOCKING_SHIFT: [char; 128] = [
+    RESERVED, '\u{0C82}', '\u{0C83}', '\u{0C85}', '\u{0C86}', '\u{0C87}', '\u{0C88}',
+    '\u{0C89}', //
+    '\u{0C8A}', '\u{0C8B}', '\n', '\u{0C8C}', RESERVED, '\r', '\u{0C8E}', '\u{0C8F}', //
+    '\u{0C90}', RESERVED, '\u{0C92}', '\u{0C93}', '\u{0C94}', '\u{0C95}', '\u{0C96}',
+    '\u{0C97}', //
+    '\u{0C98}', '\u{0C99}', '\u{0C9A}', '\u{A0}', '\u{0C9B}', '\u{0C9C}', '\u{0C9D}',
+    '\u{0C9E}', //
+    ' ', '!', '\u{0C9F}', '\u{0CA0}', '\u{0CA1}', '\u{0CA2}', '\u{0CA3}', '\u{0CA4}', //
+    ')', '(', '\u{0CA5}', '\u{0CA6}', ',', '\u{0CA7}', '.', '\u{0CA8}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', RESERVED, '\u{0CAA}', '\u{0CAB}', '?', //
+    '\u{0CAC}', '\u{0CAD}', '\u{0CAE}', '\u{0CAF}', '\u{0CB0}', '\u{0CB1}', '\u{0CB2}',
+    '\u{0CB3}', //
+    RESERVED, '\u{0CB5}', '\u{0CB6}', '\u{0CB7}', '\u{0CB8}', '\u{0CB9}', '\u{0CBC}',
+    '\u{0CBD}', //
+    '\u{0CBE}', '\u{0CBF}', '\u{0CC0}', '\u{0CC1}', '\u{0CC2}', '\u{0CC3}', '\u{0CC4}',
+    RESERVED, //
+    '\u{0CC6}', '\u{0CC7}', '\u{0CC8}', RESERVED, '\u{0CCA}', '\u{0CCB}', '\u{0CCC}',
+    '\u{0CCD}', //
+    '\u{0CD5}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0CD6}', '\u{0CE0}', '\u{0CE1}', '\u{0CE2}', '\u{0CE3}', //
+];
+
+/// Malayalam locking shift table
+pub(super) const MALAYALAM_LOCKING_SHIFT: [char; 128] = [
+    RESERVED, '\u{0D02}', '\u{0D03}', '\u{0D05}', '\u{0D06}', '\u{0D07}', '\u{0D08}',
+    '\u{0D09}', //
+    '\u{0D0A}', '\u{0D0B}', '\n', '\u{0D0C}', RESERVED, '\r', '\u{0D0E}', '\u{0D0F}', //
+    '\u{0D10}', RESERVED, '\u{0D12}', '\u{0D13}', '\u{0D14}', '\u{0D15}', '\u{0D16}',
+    '\u{0D17}', //
+    '\u{0D18}', '\u{0D19}', '\u{0D1A}', '\u{A0}', '\u{0D1B}', '\u{0D1C}', '\u{0D1D}',
+    '\u{0D1E}', //
+    ' ', '!', '\u{0D1F}', '\u{0D20}', '\u{0D21}', '\u{0D22}', '\u{0D23}', '\u{0D24}', //
+    ')', '(', '\u{0D25}', '\u{0D26}', ',', '\u{0D27}', '.', '\u{0D28}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', RESERVED, '\u{0D2A}', '\u{0D2B}', '?', //
+    '\u{0D2C}', '\u{0D2D}', '\u{0D2E}', '\u{0D2F}', '\u{0D30}', '\u{0D31}', '\u{0D32}',
+    '\u{0D33}', //
+    '\u{0D34}', '\u{0D35}', '\u{0D36}', '\u{0D37}', '\u{0D38}', '\u{0D39}', RESERVED,
+    '\u{0D3D}', //
+    '\u{0D3E}', '\u{0D3F}', '\u{0D40}', '\u{0D41}', '\u{0D42}', '\u{0D43}', '\u{0D44}',
+    RESERVED, //
+    '\u{0D46}', '\u{0D47}', '\u{0D48}', RESERVED, '\u{0D4A}', '\u{0D4B}', '\u{0D4C}',
+    '\u{0D4D}', //
+    '\u{0D57}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0D60}', '\u{0D61}', '\u{0D62}', '\u{0D63}', '\u{0D79}', //
+];
+
+/// Oriya locking shift table
+pub(super) const ORIYA_LOCKING_SHIFT: [char; 128] = [
+    '\u{0B01}', '\u{0B02}', '\u{0B03}', '\u{0B05}', '\u{0B06}', '\u{0B07}', '\u{0B08}',
+    '\u{0B09}', //
+    '\u{0B0A}', '\u{0B0B}', '\n', '\u{0B0C}', RESERVED, '\r', RESERVED, '\u{0B0F}', //
+    '\u{0B10}', RESERVED, RESERVED, '\u{0B13}', '\u{0B14}', '\u{0B15}', '\u{0B16}',
+    '\u{0B17}', //
+    '\u{0B18}', '\u{0B19}', '\u{0B1A}', '\u{A0}', '\u{0B1B}', '\u{0B1C}', '\u{0B1D}',
+    '\u{0B1E}', //
+    ' ', '!', '\u{0B1F}', '\u{0B20}', '\u{0B21}', '\u{0B22}', '\u{0B23}', '\u{0B24}', //
+    ')', '(', '\u{0B25}', '\u{0B26}', ',', '\u{0B27}', '.', '\u{0B28}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', RESERVED, '\u{0B2A}', '\u{0B2B}', '?', //
+    '\u{0B2C}', '\u{0B2D}', '\u{0B2E}', '\u{0B2F}', '\u{0B30}', RESERVED, '\u{0B32}',
+    '\u{0B33}', //
+    RESERVED, '\u{0B35}', '\u{0B36}', '\u{0B37}', '\u{0B38}', '\u{0B39}', '\u{0B3C}',
+    '\u{0B3D}', //
+    '\u{0B3E}', '\u{0B3F}', '\u{0B40}', '\u{0B41}', '\u{0B42}', '\u{0B43}', '\u{0B44}',
+    RESERVED, //
+    RESERVED, '\u{0B47}', '\u{0B48}', RESERVED, RESERVED, '\u{0B4B}', '\u{0B4C}',
+    '\u{0B4D}', //
+    '\u{0B56}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0B57}', '\u{0B60}', '\u{0B61}', '\u{0B62}', '\u{0B63}', //
+];
+
+/// Punjabi locking shift table
+pub(super) const PUNJABI_LOCKING_SHIFT: [char; 128] = [
+    '\u{0A01}', '\u{0A02}', '\u{0A03}', '\u{0A05}', '\u{0A06}', '\u{0A07}', '\u{0A08}',
+    '\u{0A09}', //
+    '\u{0A0A}', RESERVED, '\n', RESERVED, RESERVED, '\r', RESERVED, '\u{0A0F}', //
+    '\u{0A10}', RESERVED, RESERVED, '\u{0A13}', '\u{0A14}', '\u{0A15}', '\u{0A16}',
+    '\u{0A17}', //
+    '\u{0A18}', '\u{0A19}', '\u{0A1A}', '\u{A0}', '\u{0A1B}', '\u{0A1C}', '\u{0A1D}',
+    '\u{0A1E}', //
+    ' ', '!', '\u{0A1F}', '\u{0A20}', '\u{0A21}', '\u{0A22}', '\u{0A23}', '\u{0A24}', //
+    ')', '(', '\u{0A25}', '\u{0A26}', ',', '\u{0A27}', '.', '\u{0A28}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', RESERVED, '\u{0A2A}', '\u{0A2B}', '?', //
+    '\u{0A2C}', '\u{0A2D}', '\u{0A2E}', '\u{0A2F}', '\u{0A30}', RESERVED, '\u{0A32}',
+    '\u{0A33}', //
+    RESERVED, '\u{0A35}', '\u{0A36}', RESERVED, '\u{0A38}', '\u{0A39}', '\u{0A3C}',
+    RESERVED, //
+    '\u{0A3E}', '\u{0A3F}', '\u{0A40}', '\u{0A41}', '\u{0A42}', RESERVED, RESERVED,
+    RESERVED, //
+    RESERVED, '\u{0A47}', '\u{0A48}', RESERVED, RESERVED, '\u{0A4B}', '\u{0A4C}',
+    '\u{0A4D}', //
+    '\u{0A51}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0A70}', '\u{0A71}', '\u{0A72}', '\u{0A73}', '\u{0A74}', //
+];
+
+/// Tamil locking shift table
+pub(super) const TAMIL_LOCKING_SHIFT: [char; 128] = [
+    RESERVED, '\u{0B82}', '\u{0B83}', '\u{0B85}', '\u{0B86}', '\u{0B87}', '\u{0B88}',
+    '\u{0B89}', //
+    '\u{0B8A}', RESERVED, '\n', RESERVED, RESERVED, '\r', '\u{0B8E}', '\u{0B8F}', //
+    '\u{0B90}', RESERVED, '\u{0B92}', '\u{0B93}', '\u{0B94}', '\u{0B95}', RESERVED,
+    RESERVED, //
+    RESERVED, '\u{0B99}', '\u{0B9A}', '\u{A0}', RESERVED, '\u{0B9C}', RESERVED, '\u{0B9E}', //
+    ' ', '!', '\u{0B9F}', RESERVED, RESERVED, RESERVED, '\u{0BA3}', '\u{0BA4}', //
+    ')', '(', RESERVED, RESERVED, ',', RESERVED, '.', '\u{0BA8}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', '\u{0BA9}', '\u{0BAA}', RESERVED, '?', //
+    RESERVED, RESERVED, '\u{0BAE}', '\u{0BAF}', '\u{0BB0}', '\u{0BB1}', '\u{0BB2}',
+    '\u{0BB3}', //
+    '\u{0BB4}', '\u{0BB5}', '\u{0BB6}', '\u{0BB7}', '\u{0BB8}', '\u{0BB9}', RESERVED,
+    RESERVED, //
+    '\u{0BBE}', '\u{0BBF}', '\u{0BC0}', '\u{0BC1}', '\u{0BC2}', RESERVED, RESERVED,
+    RESERVED, //
+    '\u{0BC6}', '\u{0BC7}', '\u{0BC8}', RESERVED, '\u{0BCA}', '\u{0BCB}', '\u{0BCC}',
+    '\u{0BCD}', //
+    '\u{0BD0}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0BD7}', '\u{0BF0}', '\u{0BF1}', '\u{0BF2}', '\u{0BF9}', //
+];
+
+/// Telugu locking shift table
+pub(super) const TELUGU_LOCKING_SHIFT: [char; 128] = [
+    '\u{0C01}', '\u{0C02}', '\u{0C03}', '\u{0C05}', '\u{0C06}', '\u{0C07}', '\u{0C08}',
+    '\u{0C09}', //
+    '\u{0C0A}', '\u{0C0B}', '\n', '\u{0C0C}', RESERVED, '\r', '\u{0C0E}', '\u{0C0F}', //
+    '\u{0C10}', RESERVED, '\u{0C12}', '\u{0C13}', '\u{0C14}', '\u{0C15}', '\u{0C16}',
+    '\u{0C17}', //
+    '\u{0C18}', '\u{0C19}', '\u{0C1A}', '\u{A0}', '\u{0C1B}', '\u{0C1C}', '\u{0C1D}',
+    '\u{0C1E}', //
+    ' ', '!', '\u{0C1F}', '\u{0C20}', '\u{0C21}', '\u{0C22}', '\u{0C23}', '\u{0C24}', //
+    ')', '(', '\u{0C25}', '\u{0C26}', ',', '\u{0C27}', '.', '\u{0C28}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', RESERVED, '\u{0C2A}', '\u{0C2B}', '?', //
+    '\u{0C2C}', '\u{0C2D}', '\u{0C2E}', '\u{0C2F}', '\u{0C30}', '\u{0C31}', '\u{0C32}',
+    '\u{0C33}', //
+    RESERVED, '\u{0C35}', '\u{0C36}', '\u{0C37}', '\u{0C38}', '\u{0C39}', RESERVED,
+    '\u{0C3D}', //
+    '\u{0C3E}', '\u{0C3F}', '\u{0C40}', '\u{0C41}', '\u{0C42}', '\u{0C43}', '\u{0C44}',
+    RESERVED, //
+    '\u{0C46}', '\u{0C47}', '\u{0C48}', RESERVED, '\u{0C4A}', '\u{0C4B}', '\u{0C4C}',
+    '\u{0C4D}', //
+    '\u{0C55}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0C56}', '\u{0C60}', '\u{0C61}', '\u{0C62}', '\u{0C63}', //
+];
+
+/// Urdu locking shift table
+pub(super) const URDU_LOCKING_SHIFT: [char; 128] = [
+    '\u{0627}', '\u{0622}', '\u{0628}', '\u{067B}', '\u{0680}', '\u{067E}', '\u{06A6}',
+    '\u{062A}', //
+    '\u{06C2}', '\u{067F}', '\n', '\u{0679}', '\u{067D}', '\r', '\u{067A}', '\u{067C}', //
+    '\u{062B}', '\u{062C}', '\u{0681}', '\u{0684}', '\u{0683}', '\u{0685}', '\u{0686}',
+    '\u{0687}', //
+    '\u{062D}', '\u{062E}', '\u{062F}', '\u{A0}', '\u{068C}', '\u{0688}', '\u{0689}',
+    '\u{068A}', //
+    ' ', '!', '\u{068F}', '\u{068D}', '\u{0630}', '\u{0631}', '\u{0691}', '\u{0693}', //
+    ')', '(', '\u{0699}', '\u{0632}', ',', '\u{0696}', '.', '\u{0698}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', '\u{069A}', '\u{0633}', '\u{0634}', '?', //
+    '\u{0635}', '\u{0636}', '\u{0637}', '\u{0638}', '\u{0639}', '\u{0641}', '\u{0642}',
+    '\u{06A9}', //
+    '\u{06AA}', '\u{06AB}', '\u{06AF}', '\u{06B3}', '\u{06B1}', '\u{0644}', '\u{0645}',
+    '\u{0646}', //
+    '\u{06BA}', '\u{06BB}', '\u{06BC}', '\u{0648}', '\u{06C4}', '\u{06D5}', '\u{06C1}',
+    '\u{06BE}', //
+    '\u{0621}', '\u{06CC}', '\u{06D0}', '\u{06D2}', '\u{064D}', '\u{0650}', '\u{064F}',
+    '\u{0657}', //
+    '\u{0654}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0655}', '\u{0651}', '\u{0653}', '\u{0656}', '\u{0670}', //
+];
+
+/// Bengali single shift table
+pub(super) const BENGALI_SINGLE_SHIFT: [(u8, char); 82] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{09E6}'),
+    (0x1A, '\u{09E7}'),
+    (0x1C, '\u{09E8}'),
+    (0x1D, '\u{09E9}'),
+    (0x1E, '\u{09EA}'),
+    (0x1F, '\u{09EB}'),
+    (0x20, '\u{09EC}'),
+    (0x21, '\u{09ED}'),
+    (0x22, '\u{09EE}'),
+    (0x23, '\u{09EF}'),
+    (0x24, '\u{09DF}'),
+    (0x25, '\u{09E0}'),
+    (0x26, '\u{09E1}'),
+    (0x27, '\u{09E2}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{09E3}'),
+    (0x2B, '\u{09F2}'),
+    (0x2C, '\u{09F3}'),
+    (0x2D, '\u{09F4}'),
+    (0x2E, '\u{09F5}'),
+    (0x2F, '\\'),
+    (0x30, '\u{09F6}'),
+    (0x31, '\u{09F7}'),
+    (0x32, '\u{09F8}'),
+    (0x33, '\u{09F9}'),
+    (0x34, '\u{09FA}'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
+
+/// Gujarati single shift table
+pub(super) const GUJARATI_SINGLE_SHIFT: [(u8, char); 70] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0AE6}'),
+    (0x1D, '\u{0AE7}'),
+    (0x1E, '\u{0AE8}'),
+    (0x1F, '\u{0AE9}'),
+    (0x20, '\u{0AEA}'),
+    (0x21, '\u{0AEB}'),
+    (0x22, '\u{0AEC}'),
+    (0x23, '\u{0AED}'),
+    (0x24, '\u{0AEE}'),
+    (0x25, '\u{0AEF}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
+
+/// Hindi single shift table
+pub(super) const HINDI_SINGLE_SHIFT: [(u8, char); 88] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0966}'),
+    (0x1D, '\u{0967}'),
+    (0x1E, '\u{0968}'),
+    (0x1F, '\u{0969}'),
+    (0x20, '\u{096A}'),
+    (0x21, '\u{096B}'),
+    (0x22, '\u{096C}'),
+    (0x23, '\u{096D}'),
+    (0x24, '\u{096E}'),
+    (0x25, '\u{096F}'),
+    (0x26, '\u{0951}'),
+    (0x27, '\u{0952}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{0953}'),
+    (0x2B, '\u{0954}'),
+    (0x2C, '\u{0958}'),
+    (0x2D, '\u{0959}'),
+    (0x2E, '\u{095A}'),
+    (0x2F, '\\'),
+    (0x30, '\u{095B}'),
+    (0x31, '\u{095C}'),
+    (0x32, '\u{095D}'),
+    (0x33, '\u{095E}'),
+    (0x34, '\u{095F}'),
+    (0x35, '\u{0960}'),
+    (0x36, '\u{0961}'),
+    (0x37, '\u{0962}'),
+    (0x38, '\u{0963}'),
+    (0x39, '\u{0970}'),
+    (0x3A, '\u{0971}'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
+
+/// Kannada single shift table
+pub(super) const KANNADA_SINGLE_SHIFT: [(u8, char); 73] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0CE6}'),
+    (0x1D, '\u{0CE7}'),
+    (0x1E, '\u{0CE8}'),
+    (0x1F, '\u{0CE9}'),
+    (0x20, '\u{0CEA}'),
+    (0x21, '\u{0CEB}'),
+    (0x22, '\u{0CEC}'),
+    (0x23, '\u{0CED}'),
+    (0x24, '\u{0CEE}'),
+    (0x25, '\u{0CEF}'),
+    (0x26, '\u{0CDE}'),
+    (0x27, '\u{0CF1}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{0CF2}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
+
+/// Malayalam single shift table
+pub(super) const MALAYALAM_SINGLE_SHIFT: [(u8, char); 82] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0D66}'),
+    (0x1D, '\u{0D67}'),
+    (0x1E, '\u{0D68}'),
+    (0x1F, '\u{0D69}'),
+    (0x20, '\u{0D6A}'),
+    (0x21, '\u{0D6B}'),
+    (0x22, '\u{0D6C}'),
+    (0x23, '\u{0D6D}'),
+    (0x24, '\u{0D6E}'),
+    (0x25, '\u{0D6F}'),
+    (0x26, '\u{0D70}'),
+    (0x27, '\u{0D71}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{0D72}'),
+    (0x2B, '\u{0D73}'),
+    (0x2C, '\u{0D74}'),
+    (0x2D, '\u{0D75}'),
+    (0x2E, '\u{0D7A}'),
+    (0x2F, '\\'),
+    (0x30, '\u{0D7B}'),
+    (0x31, '\u{0D7C}'),
+    (0x32, '\u{0D7D}'),
+    (0x33, '\u{0D7E}'),
+    (0x34, '\u{0D7F}'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
+
+/// Oriya single shift table
+pub(super) const ORIYA_SINGLE_SHIFT: [(u8, char); 75] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0B66}'),
+    (0x1D, '\u{0B67}'),
+    (0x1E, '\u{0B68}'),
+    (0x1F, '\u{0B69}'),
+    (0x20, '\u{0B6A}'),
+    (0x21, '\u{0B6B}'),
+    (0x22, '\u{0B6C}'),
+    (0x23, '\u{0B6D}'),
+    (0x24, '\u{0B6E}'),
+    (0x25, '\u{0B6F}'),
+    (0x26, '\u{0B5C}'),
+    (0x27, '\u{0B5D}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{0B5F}'),
+    (0x2B, '\u{0B70}'),
+    (0x2C, '\u{0B71}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
+
+/// Punjabi single shift table
+pub(super) const PUNJABI_SINGLE_SHIFT: [(u8, char); 76] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0A66}'),
+    (0x1D, '\u{0A67}'),
+    (0x1E, '\u{0A68}'),
+    (0x1F, '\u{0A69}'),
+    (0x20, '\u{0A6A}'),
+    (0x21, '\u{0A6B}'),
+    (0x22, '\u{0A6C}'),
+    (0x23, '\u{0A6D}'),
+    (0x24, '\u{0A6E}'),
+    (0x25, '\u{0A6F}'),
+    (0x26, '\u{0A59}'),
+    (0x27, '\u{0A5A}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{0A5B}'),
+    (0x2B, '\u{0A5C}'),
+    (0x2C, '\u{0A5E}'),
+    (0x2D, '\u{0A75}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
+
+/// Tamil single shift table
+pub(super) const TAMIL_SINGLE_SHIFT: [(u8, char); 77] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0BE6}'),
+    (0x1D, '\u{0BE7}'),
+    (0x1E, '\u{0BE8}'),
+    (0x1F, '\u{0BE9}'),
+    (0x20, '\u{0BEA}'),
+    (0x21, '\u{0BEB}'),
+    (0x22, '\u{0BEC}'),
+    (0x23, '\u{0BED}'),
+    (0x24, '\u{0BEE}'),
+    (0x25, '\u{0BEF}'),
+    (0x26, '\u{0BF3}'),
+    (0x27, '\u{0BF4}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{0BF5}'),
+    (0x2B, '\u{0BF6}'),
+    (0x2C, '\u{0BF7}'),
+    (0x2D, '\u{0BF8}'),
+    (0x2E, '\u{0BFA}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
+
+/// Telugu single shift table
+pub(super) const TELUGU_SINGLE_SHIFT: [(u8, char); 80] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0C66}'),
+    (0x1D, '\u{0C67}'),
+    (0x1E, '\u{0C68}'),
+    (0x1F, '\u{0C69}'),
+    (0x20, '\u{0C6A}'),
+    (0x21, '\u{0C6B}'),
+    (0x22, '\u{0C6C}'),
+    (0x23, '\u{0C6D}'),
+    (0x24, '\u{0C6E}'),
+    (0x25, '\u{0C6F}'),
+    (0x26, '\u{0C58}'),
+    (0x27, '\u{0C59}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{0C78}'),
+    (0x2B, '\u{0C79}'),
+    (0x2C, '\u{0C7A}'),
+    (0x2D, '\u{0C7B}'),
+    (0x2E, '\u{0C7C}'),
+    (0x2F, '\\'),
+    (0x30, '\u{0C7D}'),
+    (0x31, '\u{0C7E}'),
+    (0x32, '\u{0C7F}'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
+
+/// Urdu single shift table
+pub(super) const URDU_SINGLE_SHIFT: [(u8, char); 90] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0600}'),
+    (0x1A, '\u{0601}'),
+    (0x1C, '\u{06F0}'),
+    (0x1D, '\u{06F1}'),
+    (0x1E, '\u{06F2}'),
+    (0x1F, '\u{06F3}'),
+    (0x20, '\u{06F4}'),
+    (0x21, '\u{06F5}'),
+    (0x22, '\u{06F6}'),
+    (0x23, '\u{06F7}'),
+    (0x24, '\u{06F8}'),
+    (0x25, '\u{06F9}'),
+    (0x26, '\u{060C}'),
+    (0x27, '\u{060D}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{060E}'),
+    (0x2B, '\u{060F}'),
+    (0x2C, '\u{0610}'),
+    (0x2D, '\u{0611}'),
+    (0x2E, '\u{0612}'),
+    (0x2F, '\\'),
+    (0x30, '\u{0613}'),
+    (0x31, '\u{0614}'),
+    (0x32, '\u{061B}'),
+    (0x33, '\u{061F}'),
+    (0x34, '\u{0640}'),
+    (0x35, '\u{0652}'),
+    (0x36, '\u{0658}'),
+    (0x37, '\u{066B}'),
+    (0x38, '\u{066C}'),
+    (0x39, '\u{0672}'),
+    (0x3A, '\u{0673}'),
+    (0x3B, '\u{06CD}'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x3F, '\u{06D4}'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
@@ -0,0 +1,114 @@
+//! Import of event logs exported as JSON Lines
+
+use std::time::Duration;
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+    hex,
+    result::AppActionError,
+    values::{Event, Record},
+    wire,
+};
+
+#[derive(Deserialize)]
+struct Line {
+    id: u64,
+    time: DateTime<Utc>,
+    uptime_ms: f64,
+    kind: String,
+    summary: String,
+    command: Option<LineCommand>,
+}
+
+#[derive(Deserialize)]
+struct LineCommand {
+    hex: String,
+}
+
+/// Parses a log exported with [`ExportFormat::JsonLines`](crate::export::ExportFormat::JsonLines).
+///
+/// Commands are decoded from their wire bytes, errors only keep their summary.
+pub fn parse(bytes: &[u8]) -> anyhow::Result<Vec<Record>> {
+    let text = std::str::from_utf8(bytes).context("Not a UTF-8 file")?;
+
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse_line(line).with_context(|| format!("Line {}", index + 1)))
+        .collect()
+}
+
+fn parse_line(line: &str) -> anyhow::Result<Record> {
+    let line = serde_json::from_str::<Line>(line)?;
+
+    let command = || {
+        let hex = &line.command.as_ref().context("Missing command")?.hex;
+
+        let bytes = hex::decode(hex).context("Invalid command hex")?;
+
+        wire::decode(&bytes).context("Invalid command bytes")
+    };
+
+    let event = match line.kind.as_str() {
+        "Sent" => Event::Sent(command()?),
+        "Received" => Event::Received(command()?),
+        "Connected" => Event::Connected,
+        "Disconnected" => Event::Disconnected,
+        "Closed" => Event::Closed,
+        "Bound" => Event::Bound,
+        "Error" => Event::Error(AppActionError::Imported(line.summary.clone())),
+        kind => anyhow::bail!("Unknown event kind {kind:?}"),
+    };
+
+    let uptime = Duration::try_from_secs_f64(line.uptime_ms / 1000.0)
+        .with_context(|| format!("Invalid uptime_ms {}", line.uptime_ms))?;
+
+    Ok(Record {
+        id: line.id,
+        time: line.time,
+        uptime,
+        event,
+        request: None,
+    })
+}
+
+/// Opens a file dialog and calls `on_open` with the name and content of the picked file.
+///
+/// `on_open` is not called if the dialog was cancelled.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn open(on_open: impl FnOnce(String, anyhow::Result<Vec<u8>>) + 'static) {
+    let Some(path) = rfd::FileDialog::new()
+        .add_filter("JSON Lines", &["jsonl"])
+        .pick_file()
+    else {
+        return;
+    };
+
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    on_open(name, std::fs::read(path).map_err(Into::into));
+}
+
+/// Opens the browser's file picker and calls `on_open` with the name and content of the picked file.
+///
+/// `on_open` is not called if the dialog was cancelled.
+#[cfg(target_arch = "wasm32")]
+pub fn open(on_open: impl FnOnce(String, anyhow::Result<Vec<u8>>) + 'static) {
+    crate::runtime::spawn(async move {
+        let Some(file) = rfd::AsyncFileDialog::new()
+            .add_filter("JSON Lines", &["jsonl"])
+            .pick_file()
+            .await
+        else {
+            return;
+        };
+
+        on_open(file.file_name(), Ok(file.read().await));
+    });
+}
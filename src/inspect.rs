@@ -0,0 +1,1054 @@
+//! Human readable tree representation of [`Command`]s
+
+use std::{borrow::Cow, fmt::Debug, ops::Range};
+
+use rusmpp::{
+    Command, CommandStatus, Pdu,
+    encode::Length,
+    pdus::{
+        AlertNotification, BindReceiver, BindReceiverResp, BindTransceiver, BindTransceiverResp,
+        BindTransmitter, BindTransmitterResp, BroadcastSm, BroadcastSmResp, CancelBroadcastSm,
+        CancelSm, DataSm, DataSmResp, DeliverSm, DeliverSmResp, Outbind, QueryBroadcastSm,
+        QueryBroadcastSmResp, QuerySm, QuerySmResp, ReplaceSm, SubmitMulti, SubmitMultiResp,
+        SubmitSm, SubmitSmResp,
+    },
+    tlvs::{Tlv, TlvValue},
+    values::{DataCoding, DestAddress, EsmClass, GsmFeatures, RegisteredDelivery, UnsuccessSme},
+};
+
+use crate::{
+    gsm7::{self, Alphabet},
+    iso8859,
+};
+
+/// A named value with optional children
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub name: Cow<'static, str>,
+    pub value: String,
+    /// Bytes of the encoded command this node was decoded from, if known
+    pub range: Option<Range<usize>>,
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    pub fn new(name: impl Into<Cow<'static, str>>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            range: None,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_children(mut self, children: Vec<Node>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn at(mut self, range: Range<usize>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    fn shift(&mut self, offset: usize) {
+        if let Some(range) = &mut self.range {
+            *range = range.start + offset..range.end + offset;
+        }
+
+        self.children
+            .iter_mut()
+            .for_each(|child| child.shift(offset));
+    }
+
+    /// Calls `f` for every node with a known range, children after their parent.
+    pub fn visit_ranges(&self, f: &mut impl FnMut(&Node, &Range<usize>)) {
+        if let Some(range) = &self.range {
+            f(self, range);
+        }
+
+        self.children.iter().for_each(|child| child.visit_ranges(f));
+    }
+}
+
+/// Types that can be displayed as a list of [`Node`]s
+pub trait Inspect {
+    fn inspect(&self) -> Vec<Node>;
+}
+
+impl Inspect for Command {
+    fn inspect(&self) -> Vec<Node> {
+        let header = Node::new("header", "").at(0..16).with_children(vec![
+            Node::new("command_length", (self.length() + 4).to_string()).at(0..4),
+            Node::new(
+                "command_id",
+                format!("{:?} (0x{:08X})", self.id(), u32::from(self.id())),
+            )
+            .at(4..8),
+            Node::new("command_status", status_str(self.status())).at(8..12),
+            Node::new("sequence_number", self.sequence_number().to_string()).at(12..16),
+        ]);
+
+        let mut body = self.pdu().map(Inspect::inspect).unwrap_or_default();
+
+        // PDU fields are laid out relative to the start of the body.
+        body.iter_mut().for_each(|node| node.shift(16));
+
+        let length = self.pdu().map(Length::length).unwrap_or(0);
+
+        vec![
+            header,
+            Node::new("body", "")
+                .at(16..16 + length)
+                .with_children(body),
+        ]
+    }
+}
+
+impl Inspect for Pdu {
+    fn inspect(&self) -> Vec<Node> {
+        match self {
+            Pdu::BindTransmitter(pdu) => pdu.inspect(),
+            Pdu::BindReceiver(pdu) => pdu.inspect(),
+            Pdu::BindTransceiver(pdu) => pdu.inspect(),
+            Pdu::BindTransmitterResp(pdu) => pdu.inspect(),
+            Pdu::BindReceiverResp(pdu) => pdu.inspect(),
+            Pdu::BindTransceiverResp(pdu) => pdu.inspect(),
+            Pdu::Outbind(pdu) => pdu.inspect(),
+            Pdu::AlertNotification(pdu) => pdu.inspect(),
+            Pdu::SubmitSm(pdu) => pdu.inspect(),
+            Pdu::SubmitSmResp(pdu) => pdu.inspect(),
+            Pdu::DeliverSm(pdu) => pdu.inspect(),
+            Pdu::DeliverSmResp(pdu) => pdu.inspect(),
+            Pdu::DataSm(pdu) => pdu.inspect(),
+            Pdu::DataSmResp(pdu) => pdu.inspect(),
+            Pdu::QuerySm(pdu) => pdu.inspect(),
+            Pdu::QuerySmResp(pdu) => pdu.inspect(),
+            Pdu::CancelSm(pdu) => pdu.inspect(),
+            Pdu::ReplaceSm(pdu) => pdu.inspect(),
+            Pdu::SubmitMulti(pdu) => pdu.inspect(),
+            Pdu::SubmitMultiResp(pdu) => pdu.inspect(),
+            Pdu::BroadcastSm(pdu) => pdu.inspect(),
+            Pdu::BroadcastSmResp(pdu) => pdu.inspect(),
+            Pdu::QueryBroadcastSm(pdu) => pdu.inspect(),
+            Pdu::QueryBroadcastSmResp(pdu) => pdu.inspect(),
+            Pdu::CancelBroadcastSm(pdu) => pdu.inspect(),
+            Pdu::Other { body, .. } => {
+                let bytes = body.as_bytes();
+
+                vec![Node::new("body", hex_str(bytes)).at(0..bytes.len())]
+            }
+            // The remaining PDUs consist of the header only.
+            _ => Vec::new(),
+        }
+    }
+}
+
+macro_rules! impl_inspect_bind {
+    ($($pdu:ty),*) => {
+        $(
+            impl Inspect for $pdu {
+                fn inspect(&self) -> Vec<Node> {
+                    let mut cursor = Cursor::default();
+
+                    vec![
+                        Node::new("system_id", c_str(&self.system_id))
+                            .at(cursor.take(&self.system_id)),
+                        Node::new("password", c_str(&self.password))
+                            .at(cursor.take(&self.password)),
+                        Node::new("system_type", c_str(&self.system_type))
+                            .at(cursor.take(&self.system_type)),
+                        Node::new("interface_version", enum_str(self.interface_version))
+                            .at(cursor.take(&self.interface_version)),
+                        Node::new("addr_ton", enum_str(self.addr_ton))
+                            .at(cursor.take(&self.addr_ton)),
+                        Node::new("addr_npi", enum_str(self.addr_npi))
+                            .at(cursor.take(&self.addr_npi)),
+                        Node::new("address_range", c_str(&self.address_range))
+                            .at(cursor.take(&self.address_range)),
+                    ]
+                }
+            }
+        )*
+    };
+}
+
+impl_inspect_bind!(BindTransmitter, BindReceiver, BindTransceiver);
+
+macro_rules! impl_inspect_bind_resp {
+    ($($pdu:ty),*) => {
+        $(
+            impl Inspect for $pdu {
+                fn inspect(&self) -> Vec<Node> {
+                    let mut cursor = Cursor::default();
+
+                    vec![
+                        Node::new("system_id", c_str(&self.system_id))
+                            .at(cursor.take(&self.system_id)),
+                    ]
+                }
+            }
+        )*
+    };
+}
+
+impl_inspect_bind_resp!(BindTransmitterResp, BindReceiverResp, BindTransceiverResp);
+
+impl Inspect for Outbind {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+
+        vec![
+            Node::new("system_id", c_str(&self.system_id)).at(cursor.take(&self.system_id)),
+            Node::new("password", c_str(&self.password)).at(cursor.take(&self.password)),
+        ]
+    }
+}
+
+impl Inspect for AlertNotification {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+
+        vec![
+            Node::new("source_addr_ton", enum_str(self.source_addr_ton))
+                .at(cursor.take(&self.source_addr_ton)),
+            Node::new("source_addr_npi", enum_str(self.source_addr_npi))
+                .at(cursor.take(&self.source_addr_npi)),
+            Node::new("source_addr", c_str(&self.source_addr)).at(cursor.take(&self.source_addr)),
+            Node::new("esme_addr_ton", enum_str(self.esme_addr_ton))
+                .at(cursor.take(&self.esme_addr_ton)),
+            Node::new("esme_addr_npi", enum_str(self.esme_addr_npi))
+                .at(cursor.take(&self.esme_addr_npi)),
+            Node::new("esme_addr", c_str(&self.esme_addr)).at(cursor.take(&self.esme_addr)),
+        ]
+    }
+}
+
+macro_rules! impl_inspect_sm {
+    ($($pdu:ty),*) => {
+        $(
+            impl Inspect for $pdu {
+                fn inspect(&self) -> Vec<Node> {
+                    let mut cursor = Cursor::default();
+                    let short_message = self.short_message().as_bytes();
+
+                    let mut nodes = vec![
+                        Node::new("service_type", c_str(self.service_type.value()))
+                            .at(cursor.take(&self.service_type)),
+                        Node::new("source_addr_ton", enum_str(self.source_addr_ton))
+                            .at(cursor.take(&self.source_addr_ton)),
+                        Node::new("source_addr_npi", enum_str(self.source_addr_npi))
+                            .at(cursor.take(&self.source_addr_npi)),
+                        Node::new("source_addr", c_str(&self.source_addr))
+                            .at(cursor.take(&self.source_addr)),
+                        Node::new("dest_addr_ton", enum_str(self.dest_addr_ton))
+                            .at(cursor.take(&self.dest_addr_ton)),
+                        Node::new("dest_addr_npi", enum_str(self.dest_addr_npi))
+                            .at(cursor.take(&self.dest_addr_npi)),
+                        Node::new("destination_addr", c_str(&self.destination_addr))
+                            .at(cursor.take(&self.destination_addr)),
+                        esm_class_node(self.esm_class).at(cursor.take(&self.esm_class)),
+                        Node::new("protocol_id", u8_str(self.protocol_id))
+                            .at(cursor.take(&self.protocol_id)),
+                        Node::new("priority_flag", u8_str(self.priority_flag.into()))
+                            .at(cursor.take(&self.priority_flag)),
+                        Node::new("schedule_delivery_time", c_str(&self.schedule_delivery_time))
+                            .at(cursor.take(&self.schedule_delivery_time)),
+                        Node::new("validity_period", c_str(&self.validity_period))
+                            .at(cursor.take(&self.validity_period)),
+                        registered_delivery_node(self.registered_delivery)
+                            .at(cursor.take(&self.registered_delivery)),
+                        Node::new("replace_if_present_flag", enum_str(self.replace_if_present_flag))
+                            .at(cursor.take(&self.replace_if_present_flag)),
+                        Node::new("data_coding", enum_str(self.data_coding))
+                            .at(cursor.take(&self.data_coding)),
+                        Node::new("sm_default_msg_id", u8_str(self.sm_default_msg_id))
+                            .at(cursor.take(&self.sm_default_msg_id)),
+                        Node::new("sm_length", short_message.len().to_string())
+                            .at(cursor.take_n(1)),
+                        payload_node(
+                            "short_message",
+                            self.esm_class,
+                            self.data_coding,
+                            short_message,
+                            cursor.take_n(short_message.len()),
+                        ),
+                    ];
+
+                    nodes.extend(tlv_nodes(self.tlvs(), &mut cursor));
+
+                    nodes
+                }
+            }
+        )*
+    };
+}
+
+impl_inspect_sm!(SubmitSm, DeliverSm);
+
+impl Inspect for DataSm {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+
+        let mut nodes = vec![
+            Node::new("service_type", c_str(self.service_type.value()))
+                .at(cursor.take(&self.service_type)),
+            Node::new("source_addr_ton", enum_str(self.source_addr_ton))
+                .at(cursor.take(&self.source_addr_ton)),
+            Node::new("source_addr_npi", enum_str(self.source_addr_npi))
+                .at(cursor.take(&self.source_addr_npi)),
+            Node::new("source_addr", c_str(&self.source_addr)).at(cursor.take(&self.source_addr)),
+            Node::new("dest_addr_ton", enum_str(self.dest_addr_ton))
+                .at(cursor.take(&self.dest_addr_ton)),
+            Node::new("dest_addr_npi", enum_str(self.dest_addr_npi))
+                .at(cursor.take(&self.dest_addr_npi)),
+            Node::new("destination_addr", c_str(&self.destination_addr))
+                .at(cursor.take(&self.destination_addr)),
+            esm_class_node(self.esm_class).at(cursor.take(&self.esm_class)),
+            registered_delivery_node(self.registered_delivery)
+                .at(cursor.take(&self.registered_delivery)),
+            Node::new("data_coding", enum_str(self.data_coding)).at(cursor.take(&self.data_coding)),
+        ];
+
+        nodes.extend(tlv_nodes_with_data_coding(
+            self.tlvs(),
+            &mut cursor,
+            self.esm_class,
+            self.data_coding,
+        ));
+
+        nodes
+    }
+}
+
+macro_rules! impl_inspect_sm_resp {
+    ($($pdu:ty),*) => {
+        $(
+            impl Inspect for $pdu {
+                fn inspect(&self) -> Vec<Node> {
+                    let mut cursor = Cursor::default();
+
+                    let mut nodes = vec![
+                        Node::new("message_id", c_str(self.message_id()))
+                            .at(cursor.take(self.message_id())),
+                    ];
+
+                    nodes.extend(tlv_nodes(self.tlvs(), &mut cursor));
+
+                    nodes
+                }
+            }
+        )*
+    };
+}
+
+impl_inspect_sm_resp!(SubmitSmResp, DeliverSmResp, DataSmResp);
+
+impl Inspect for QuerySm {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+
+        vec![
+            Node::new("message_id", c_str(&self.message_id)).at(cursor.take(&self.message_id)),
+            Node::new("source_addr_ton", enum_str(self.source_addr_ton))
+                .at(cursor.take(&self.source_addr_ton)),
+            Node::new("source_addr_npi", enum_str(self.source_addr_npi))
+                .at(cursor.take(&self.source_addr_npi)),
+            Node::new("source_addr", c_str(&self.source_addr)).at(cursor.take(&self.source_addr)),
+        ]
+    }
+}
+
+impl Inspect for QuerySmResp {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+
+        vec![
+            Node::new("message_id", c_str(&self.message_id)).at(cursor.take(&self.message_id)),
+            Node::new("final_date", c_str(&self.final_date)).at(cursor.take(&self.final_date)),
+            Node::new("message_state", enum_str(self.message_state))
+                .at(cursor.take(&self.message_state)),
+            Node::new("error_code", u8_str(self.error_code)).at(cursor.take(&self.error_code)),
+        ]
+    }
+}
+
+impl Inspect for CancelSm {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+
+        vec![
+            Node::new("service_type", c_str(self.service_type.value()))
+                .at(cursor.take(&self.service_type)),
+            Node::new("message_id", c_str(&self.message_id)).at(cursor.take(&self.message_id)),
+            Node::new("source_addr_ton", enum_str(self.source_addr_ton))
+                .at(cursor.take(&self.source_addr_ton)),
+            Node::new("source_addr_npi", enum_str(self.source_addr_npi))
+                .at(cursor.take(&self.source_addr_npi)),
+            Node::new("source_addr", c_str(&self.source_addr)).at(cursor.take(&self.source_addr)),
+            Node::new("dest_addr_ton", enum_str(self.dest_addr_ton))
+                .at(cursor.take(&self.dest_addr_ton)),
+            Node::new("dest_addr_npi", enum_str(self.dest_addr_npi))
+                .at(cursor.take(&self.dest_addr_npi)),
+            Node::new("destination_addr", c_str(&self.destination_addr))
+                .at(cursor.take(&self.destination_addr)),
+        ]
+    }
+}
+
+impl Inspect for ReplaceSm {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+        let short_message = self.short_message().as_bytes();
+
+        let mut nodes = vec![
+            Node::new("message_id", c_str(&self.message_id)).at(cursor.take(&self.message_id)),
+            Node::new("source_addr_ton", enum_str(self.source_addr_ton))
+                .at(cursor.take(&self.source_addr_ton)),
+            Node::new("source_addr_npi", enum_str(self.source_addr_npi))
+                .at(cursor.take(&self.source_addr_npi)),
+            Node::new("source_addr", c_str(&self.source_addr)).at(cursor.take(&self.source_addr)),
+            Node::new(
+                "schedule_delivery_time",
+                c_str(&self.schedule_delivery_time),
+            )
+            .at(cursor.take(&self.schedule_delivery_time)),
+            Node::new("validity_period", c_str(&self.validity_period))
+                .at(cursor.take(&self.validity_period)),
+            registered_delivery_node(self.registered_delivery)
+                .at(cursor.take(&self.registered_delivery)),
+            Node::new("sm_default_msg_id", u8_str(self.sm_default_msg_id))
+                .at(cursor.take(&self.sm_default_msg_id)),
+            Node::new("sm_length", short_message.len().to_string()).at(cursor.take_n(1)),
+            // `replace_sm` has no data coding of its own, the MC keeps the one of the replaced message.
+            octets_node(
+                "short_message",
+                short_message,
+                cursor.take_n(short_message.len()),
+            ),
+        ];
+
+        nodes.extend(tlv_nodes(
+            self.message_payload()
+                .map(std::slice::from_ref)
+                .unwrap_or_default(),
+            &mut cursor,
+        ));
+
+        nodes
+    }
+}
+
+impl Inspect for SubmitMulti {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+        let short_message = self.short_message().as_bytes();
+        let dest_address = self.dest_address();
+
+        let mut nodes = vec![
+            Node::new("service_type", c_str(self.service_type.value()))
+                .at(cursor.take(&self.service_type)),
+            Node::new("source_addr_ton", enum_str(self.source_addr_ton))
+                .at(cursor.take(&self.source_addr_ton)),
+            Node::new("source_addr_npi", enum_str(self.source_addr_npi))
+                .at(cursor.take(&self.source_addr_npi)),
+            Node::new("source_addr", c_str(&self.source_addr)).at(cursor.take(&self.source_addr)),
+            Node::new("number_of_dests", dest_address.len().to_string()).at(cursor.take_n(1)),
+            dest_address_node(dest_address, &mut cursor),
+            esm_class_node(self.esm_class).at(cursor.take(&self.esm_class)),
+            Node::new("protocol_id", u8_str(self.protocol_id)).at(cursor.take(&self.protocol_id)),
+            Node::new("priority_flag", u8_str(self.priority_flag.into()))
+                .at(cursor.take(&self.priority_flag)),
+            Node::new(
+                "schedule_delivery_time",
+                c_str(&self.schedule_delivery_time),
+            )
+            .at(cursor.take(&self.schedule_delivery_time)),
+            Node::new("validity_period", c_str(&self.validity_period))
+                .at(cursor.take(&self.validity_period)),
+            registered_delivery_node(self.registered_delivery)
+                .at(cursor.take(&self.registered_delivery)),
+            Node::new(
+                "replace_if_present_flag",
+                enum_str(self.replace_if_present_flag),
+            )
+            .at(cursor.take(&self.replace_if_present_flag)),
+            Node::new("data_coding", enum_str(self.data_coding)).at(cursor.take(&self.data_coding)),
+            Node::new("sm_default_msg_id", u8_str(self.sm_default_msg_id))
+                .at(cursor.take(&self.sm_default_msg_id)),
+            Node::new("sm_length", short_message.len().to_string()).at(cursor.take_n(1)),
+            payload_node(
+                "short_message",
+                self.esm_class,
+                self.data_coding,
+                short_message,
+                cursor.take_n(short_message.len()),
+            ),
+        ];
+
+        nodes.extend(tlv_nodes(self.tlvs(), &mut cursor));
+
+        nodes
+    }
+}
+
+fn dest_address_node(dest_address: &[DestAddress], cursor: &mut Cursor) -> Node {
+    let start = cursor.offset;
+
+    let children = dest_address
+        .iter()
+        .map(|dest_address| {
+            let start = cursor.offset;
+            let dest_flag =
+                Node::new("dest_flag", u8_str(dest_flag(dest_address))).at(cursor.take_n(1));
+
+            let (name, mut children) = match dest_address {
+                DestAddress::SmeAddress(sme_address) => (
+                    "sme_address",
+                    vec![
+                        Node::new("dest_addr_ton", enum_str(sme_address.dest_addr_ton))
+                            .at(cursor.take(&sme_address.dest_addr_ton)),
+                        Node::new("dest_addr_npi", enum_str(sme_address.dest_addr_npi))
+                            .at(cursor.take(&sme_address.dest_addr_npi)),
+                        Node::new("destination_addr", c_str(&sme_address.destination_addr))
+                            .at(cursor.take(&sme_address.destination_addr)),
+                    ],
+                ),
+                DestAddress::DistributionListName(distribution_list_name) => (
+                    "distribution_list_name",
+                    vec![
+                        Node::new("dl_name", c_str(&distribution_list_name.dl_name))
+                            .at(cursor.take(&distribution_list_name.dl_name)),
+                    ],
+                ),
+            };
+
+            children.insert(0, dest_flag);
+
+            Node::new(name, "")
+                .at(start..cursor.offset)
+                .with_children(children)
+        })
+        .collect::<Vec<_>>();
+
+    Node::new("dest_address", format!("[{}]", children.len()))
+        .at(start..cursor.offset)
+        .with_children(children)
+}
+
+const fn dest_flag(dest_address: &DestAddress) -> u8 {
+    match dest_address {
+        DestAddress::SmeAddress(_) => 0x01,
+        DestAddress::DistributionListName(_) => 0x02,
+    }
+}
+
+impl Inspect for SubmitMultiResp {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+        let unsuccess_sme = self.unsuccess_sme();
+
+        let mut nodes = vec![
+            Node::new("message_id", c_str(&self.message_id)).at(cursor.take(&self.message_id)),
+            Node::new("no_unsuccess", unsuccess_sme.len().to_string()).at(cursor.take_n(1)),
+            unsuccess_sme_node(unsuccess_sme, &mut cursor),
+        ];
+
+        nodes.extend(tlv_nodes(self.tlvs(), &mut cursor));
+
+        nodes
+    }
+}
+
+fn unsuccess_sme_node(unsuccess_sme: &[UnsuccessSme], cursor: &mut Cursor) -> Node {
+    let start = cursor.offset;
+
+    let children = unsuccess_sme
+        .iter()
+        .map(|sme| {
+            let start = cursor.offset;
+
+            let children = vec![
+                Node::new("dest_addr_ton", enum_str(sme.dest_addr_ton))
+                    .at(cursor.take(&sme.dest_addr_ton)),
+                Node::new("dest_addr_npi", enum_str(sme.dest_addr_npi))
+                    .at(cursor.take(&sme.dest_addr_npi)),
+                Node::new("destination_addr", c_str(&sme.destination_addr))
+                    .at(cursor.take(&sme.destination_addr)),
+                Node::new("error_status_code", status_str(sme.error_status_code))
+                    .at(cursor.take_n(4)),
+            ];
+
+            Node::new("unsuccess_sme", c_str(&sme.destination_addr))
+                .at(start..cursor.offset)
+                .with_children(children)
+        })
+        .collect::<Vec<_>>();
+
+    Node::new("unsuccess_sme", format!("[{}]", children.len()))
+        .at(start..cursor.offset)
+        .with_children(children)
+}
+
+impl Inspect for BroadcastSm {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+
+        let mut nodes = vec![
+            Node::new("service_type", c_str(self.service_type.value()))
+                .at(cursor.take(&self.service_type)),
+            Node::new("source_addr_ton", enum_str(self.source_addr_ton))
+                .at(cursor.take(&self.source_addr_ton)),
+            Node::new("source_addr_npi", enum_str(self.source_addr_npi))
+                .at(cursor.take(&self.source_addr_npi)),
+            Node::new("source_addr", c_str(&self.source_addr)).at(cursor.take(&self.source_addr)),
+            Node::new("message_id", c_str(&self.message_id)).at(cursor.take(&self.message_id)),
+            Node::new("priority_flag", u8_str(self.priority_flag.into()))
+                .at(cursor.take(&self.priority_flag)),
+            Node::new(
+                "schedule_delivery_time",
+                c_str(&self.schedule_delivery_time),
+            )
+            .at(cursor.take(&self.schedule_delivery_time)),
+            Node::new("validity_period", c_str(&self.validity_period))
+                .at(cursor.take(&self.validity_period)),
+            Node::new(
+                "replace_if_present_flag",
+                enum_str(self.replace_if_present_flag),
+            )
+            .at(cursor.take(&self.replace_if_present_flag)),
+            Node::new("data_coding", enum_str(self.data_coding)).at(cursor.take(&self.data_coding)),
+            Node::new("sm_default_msg_id", u8_str(self.sm_default_msg_id))
+                .at(cursor.take(&self.sm_default_msg_id)),
+        ];
+
+        nodes.extend(tlv_nodes_with_data_coding(
+            self.tlvs(),
+            &mut cursor,
+            EsmClass::default(),
+            self.data_coding,
+        ));
+
+        nodes
+    }
+}
+
+impl Inspect for QueryBroadcastSm {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+
+        let mut nodes = vec![
+            Node::new("message_id", c_str(&self.message_id)).at(cursor.take(&self.message_id)),
+            Node::new("source_addr_ton", enum_str(self.source_addr_ton))
+                .at(cursor.take(&self.source_addr_ton)),
+            Node::new("source_addr_npi", enum_str(self.source_addr_npi))
+                .at(cursor.take(&self.source_addr_npi)),
+            Node::new("source_addr", c_str(&self.source_addr)).at(cursor.take(&self.source_addr)),
+        ];
+
+        nodes.extend(tlv_nodes(
+            self.user_message_reference()
+                .map(std::slice::from_ref)
+                .unwrap_or_default(),
+            &mut cursor,
+        ));
+
+        nodes
+    }
+}
+
+impl Inspect for CancelBroadcastSm {
+    fn inspect(&self) -> Vec<Node> {
+        let mut cursor = Cursor::default();
+
+        let mut nodes = vec![
+            Node::new("service_type", c_str(self.service_type.value()))
+                .at(cursor.take(&self.service_type)),
+            Node::new("message_id", c_str(&self.message_id)).at(cursor.take(&self.message_id)),
+            Node::new("source_addr_ton", enum_str(self.source_addr_ton))
+                .at(cursor.take(&self.source_addr_ton)),
+            Node::new("source_addr_npi", enum_str(self.source_addr_npi))
+                .at(cursor.take(&self.source_addr_npi)),
+            Node::new("source_addr", c_str(&self.source_addr)).at(cursor.take(&self.source_addr)),
+        ];
+
+        nodes.extend(tlv_nodes(self.tlvs(), &mut cursor));
+
+        nodes
+    }
+}
+
+macro_rules! impl_inspect_broadcast_resp {
+    ($($pdu:ty),*) => {
+        $(
+            impl Inspect for $pdu {
+                fn inspect(&self) -> Vec<Node> {
+                    let mut cursor = Cursor::default();
+
+                    let mut nodes = vec![
+                        Node::new("message_id", c_str(&self.message_id))
+                            .at(cursor.take(&self.message_id)),
+                    ];
+
+                    nodes.extend(tlv_nodes(self.tlvs(), &mut cursor));
+
+                    nodes
+                }
+            }
+        )*
+    };
+}
+
+impl_inspect_broadcast_resp!(BroadcastSmResp, QueryBroadcastSmResp);
+
+/// Tracks the offset of consecutive fields, relative to the start of the PDU body
+#[derive(Default)]
+struct Cursor {
+    offset: usize,
+}
+
+impl Cursor {
+    fn take(&mut self, field: &impl Length) -> Range<usize> {
+        self.take_n(field.length())
+    }
+
+    fn take_n(&mut self, length: usize) -> Range<usize> {
+        let start = self.offset;
+
+        self.offset += length;
+
+        start..self.offset
+    }
+}
+
+pub fn status_str(status: CommandStatus) -> String {
+    format!("{status:?} (0x{:08X})", u32::from(status))
+}
+
+fn c_str(value: &impl ToString) -> String {
+    format!("\"{}\"", value.to_string())
+}
+
+fn u8_str(value: u8) -> String {
+    format!("{value} (0x{value:02X})")
+}
+
+fn enum_str<T: Debug + Copy>(value: T) -> String
+where
+    u8: From<T>,
+{
+    format!("{value:?} (0x{:02X})", u8::from(value))
+}
+
+fn esm_class_node(esm_class: EsmClass) -> Node {
+    Node::new("esm_class", format!("0x{:02X}", u8::from(esm_class))).with_children(vec![
+        Node::new("messaging_mode", format!("{:?}", esm_class.messaging_mode)),
+        Node::new("message_type", format!("{:?}", esm_class.message_type)),
+        Node::new(
+            "ansi41_specific",
+            format!("{:?}", esm_class.ansi41_specific),
+        ),
+        Node::new("gsm_features", format!("{:?}", esm_class.gsm_features)),
+    ])
+}
+
+fn registered_delivery_node(registered_delivery: RegisteredDelivery) -> Node {
+    Node::new(
+        "registered_delivery",
+        format!("0x{:02X}", u8::from(registered_delivery)),
+    )
+    .with_children(vec![
+        Node::new(
+            "mc_delivery_receipt",
+            format!("{:?}", registered_delivery.mc_delivery_receipt),
+        ),
+        Node::new(
+            "sme_originated_acknowledgement",
+            format!("{:?}", registered_delivery.sme_originated_acknowledgement),
+        ),
+        Node::new(
+            "intermediate_notification",
+            format!("{:?}", registered_delivery.intermediate_notification),
+        ),
+    ])
+}
+
+const fn udhi(esm_class: EsmClass) -> bool {
+    matches!(
+        esm_class.gsm_features,
+        GsmFeatures::UdhiIndicator | GsmFeatures::SetUdhiAndReplyPath
+    )
+}
+
+/// Splits the user data header, if any, and decodes the text of `short_message` or `message_payload`.
+fn payload_node(
+    name: &'static str,
+    esm_class: EsmClass,
+    data_coding: DataCoding,
+    bytes: &[u8],
+    range: Range<usize>,
+) -> Node {
+    let (udh, text) = if udhi(esm_class) {
+        split_udh(bytes)
+    } else {
+        (None, bytes)
+    };
+
+    let mut children = Vec::new();
+    let udh_length = udh.map(<[u8]>::len).unwrap_or(0);
+
+    if let Some(udh) = udh {
+        children.push(udh_node(udh, range.start));
+    }
+
+    let alphabet = udh.map(Alphabet::from_udh).unwrap_or_default();
+    let decoded = decode_text(data_coding, alphabet, text, udh_length);
+    let text_range = range.start + udh_length..range.end;
+
+    // Shown as unpacked, but packed septets can fit in 7 bits as well.
+    if data_coding == DataCoding::McSpecific && !text.is_empty() && fits_unpacked(text) {
+        children.push(
+            Node::new(
+                "if_packed",
+                format!("{:?}", decode_packed(alphabet, text, udh_length)),
+            )
+            .at(text_range.clone()),
+        );
+    }
+
+    children.push(Node::new("hex", hex_str(text)).at(text_range));
+
+    Node::new(
+        name,
+        decoded
+            .map(|text| format!("{text:?}"))
+            .unwrap_or_else(|| format!("{} octets", bytes.len())),
+    )
+    .at(range)
+    .with_children(children)
+}
+
+/// Decodes the text of `short_message` or `message_payload`, without the user data header.
+pub fn decode_payload(
+    esm_class: EsmClass,
+    data_coding: DataCoding,
+    bytes: &[u8],
+) -> Option<String> {
+    let (udh, text) = if udhi(esm_class) {
+        split_udh(bytes)
+    } else {
+        (None, bytes)
+    };
+
+    let alphabet = udh.map(Alphabet::from_udh).unwrap_or_default();
+
+    decode_text(
+        data_coding,
+        alphabet,
+        text,
+        udh.map(<[u8]>::len).unwrap_or(0),
+    )
+}
+
+/// Shows bytes we can not decode, such as a `short_message` without a data coding.
+fn octets_node(name: &'static str, bytes: &[u8], range: Range<usize>) -> Node {
+    Node::new(name, format!("{} octets", bytes.len()))
+        .at(range.clone())
+        .with_children(vec![Node::new("hex", hex_str(bytes)).at(range)])
+}
+
+/// Splits the user data header from the user data.
+///
+/// Returns [`None`] as header if the length octet does not fit the data.
+pub fn split_udh(bytes: &[u8]) -> (Option<&[u8]>, &[u8]) {
+    match bytes.first() {
+        Some(len) if (*len as usize) < bytes.len() => {
+            let end = *len as usize + 1;
+
+            (Some(&bytes[..end]), &bytes[end..])
+        }
+        _ => (None, bytes),
+    }
+}
+
+fn udh_node(udh: &[u8], start: usize) -> Node {
+    let mut children = Vec::new();
+    let mut ies = &udh[1..];
+    let mut offset = start + 1;
+
+    while let [iei, iel, rest @ ..] = ies {
+        let len = (*iel as usize).min(rest.len());
+        let (data, tail) = rest.split_at(len);
+
+        let value = match (*iei, data) {
+            (gsm7::SINGLE_SHIFT_IEI | gsm7::LOCKING_SHIFT_IEI, [id]) => shift_language(*id),
+            _ => hex_str(data),
+        };
+
+        children.push(Node::new(ie_name(*iei), value).at(offset..offset + 2 + len));
+
+        ies = tail;
+        offset += 2 + len;
+    }
+
+    Node::new("udh", hex_str(udh))
+        .at(start..start + udh.len())
+        .with_children(children)
+}
+
+/// Language of a shift IE, unknown ones keep the default tables
+fn shift_language(id: u8) -> String {
+    match gsm7::NationalLanguage::from_id(id) {
+        Some(language) => format!("{id:02X} ({language:?})"),
+        None => format!("{id:02X} (unknown language)"),
+    }
+}
+
+fn ie_name(iei: u8) -> Cow<'static, str> {
+    match iei {
+        0x00 => "concatenated_sms_8bit_ref".into(),
+        0x04 => "application_port_8bit".into(),
+        0x05 => "application_port_16bit".into(),
+        0x08 => "concatenated_sms_16bit_ref".into(),
+        0x24 => "national_single_shift".into(),
+        0x25 => "national_locking_shift".into(),
+        iei => format!("iei_0x{iei:02X}").into(),
+    }
+}
+
+/// Decodes text for the data codings we know how to display.
+///
+/// The alphabet holds the GSM 7-bit shift tables announced by the UDH of `udh_len` octets.
+///
+/// GSM 7-bit under [`DataCoding::McSpecific`] may be sent one septet per octet or packed, the PDU does not say
+/// which. It is read as unpacked when every octet fits in 7 bits, and as packed otherwise.
+pub fn decode_text(
+    data_coding: DataCoding,
+    alphabet: Alphabet,
+    bytes: &[u8],
+    udh_len: usize,
+) -> Option<String> {
+    match data_coding {
+        DataCoding::McSpecific if fits_unpacked(bytes) => Some(alphabet.decode_unpacked(bytes)),
+        DataCoding::McSpecific => Some(decode_packed(alphabet, bytes, udh_len)),
+        DataCoding::Ia5 => Some(bytes.iter().map(|b| (b & 0x7F) as char).collect()),
+        DataCoding::Latin1 => Some(bytes.iter().map(|b| *b as char).collect()),
+        DataCoding::Cyrillic => Some(bytes.iter().map(|b| iso8859::cyrillic_char(*b)).collect()),
+        DataCoding::LatinHebrew => Some(
+            bytes
+                .iter()
+                .map(|b| iso8859::hebrew_char(*b).unwrap_or(char::REPLACEMENT_CHARACTER))
+                .collect(),
+        ),
+        DataCoding::Ucs2 => {
+            let units = bytes
+                .chunks(2)
+                .map(|chunk| u16::from_be_bytes([chunk[0], *chunk.get(1).unwrap_or(&0)]));
+
+            Some(
+                char::decode_utf16(units)
+                    .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                    .collect(),
+            )
+        }
+        _ => None,
+    }
+}
+
+pub fn hex_str(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{byte:02X}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn tlv_nodes(tlvs: &[Tlv], cursor: &mut Cursor) -> Vec<Node> {
+    tlvs.iter()
+        .map(|tlv| tlv_node(tlv, cursor.take(tlv)))
+        .collect()
+}
+
+/// Like [`tlv_nodes`], but decodes `message_payload` with the data coding of the PDU.
+fn tlv_nodes_with_data_coding(
+    tlvs: &[Tlv],
+    cursor: &mut Cursor,
+    esm_class: EsmClass,
+    data_coding: DataCoding,
+) -> Vec<Node> {
+    tlvs.iter()
+        .map(|tlv| {
+            let range = cursor.take(tlv);
+
+            match tlv.value() {
+                Some(TlvValue::MessagePayload(payload)) => tlv_header(tlv)
+                    .at(range.clone())
+                    .with_children(vec![payload_node(
+                        "message_payload",
+                        esm_class,
+                        data_coding,
+                        payload.as_bytes(),
+                        range.start + 4..range.end,
+                    )]),
+                _ => tlv_node(tlv, range),
+            }
+        })
+        .collect()
+}
+
+fn tlv_node(tlv: &Tlv, range: Range<usize>) -> Node {
+    let value = match tlv.value() {
+        Some(TlvValue::ReceiptedMessageId(id)) => c_str(id),
+        Some(TlvValue::MessageState(state)) => enum_str(*state),
+        Some(TlvValue::MessagePayload(payload)) => hex_str(payload.as_bytes()),
+        Some(value) => format!("{value:?}"),
+        None => String::new(),
+    };
+
+    // tag and length take 4 octets
+    let value = Node::new("value", value).at(range.start + 4..range.end);
+
+    tlv_header(tlv).at(range).with_children(vec![value])
+}
+
+fn tlv_header(tlv: &Tlv) -> Node {
+    let tag = tlv.tag();
+
+    Node::new(
+        snake_case(&format!("{tag:?}")),
+        format!(
+            "tag 0x{:04X}, length {}",
+            u16::from(tag),
+            tlv.value_length()
+        ),
+    )
+}
+
+/// `ReceiptedMessageId` -> `receipted_message_id`
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+
+    snake
+}
+
+/// Whether every octet could be an unpacked GSM 7-bit septet.
+fn fits_unpacked(bytes: &[u8]) -> bool {
+    bytes.iter().all(|byte| byte & 0x80 == 0)
+}
+
+/// Packed GSM 7-bit, aligned after the UDH of `udh_len` octets
+fn decode_packed(alphabet: Alphabet, bytes: &[u8], udh_len: usize) -> String {
+    alphabet.decode_unpacked(&gsm7::unpack(bytes, gsm7::fill_bits(udh_len)))
+}
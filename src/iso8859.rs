@@ -0,0 +1,61 @@
+//! ISO/IEC 8859-5 (Cyrillic) and 8859-8 (Latin/Hebrew), the single octet charsets of data codings `0x06` and `0x07`
+//!
+//! Both share ASCII and the C1 controls with Latin-1 below `0xA0`.
+
+/// Character of an ISO/IEC 8859-5 octet
+pub const fn cyrillic_char(octet: u8) -> char {
+    let code = match octet {
+        0x00..=0xA0 | 0xAD => octet as u32,
+        0xF0 => 0x2116,
+        0xFD => 0xA7,
+        _ => octet as u32 - 0xA0 + 0x400,
+    };
+
+    match char::from_u32(code) {
+        Some(c) => c,
+        None => char::REPLACEMENT_CHARACTER,
+    }
+}
+
+/// ISO/IEC 8859-5 octet of a character
+pub fn cyrillic_octet(c: char) -> Option<u8> {
+    match c as u32 {
+        code @ (0x00..=0xA0 | 0xAD) => Some(code as u8),
+        0x2116 => Some(0xF0),
+        0xA7 => Some(0xFD),
+        code @ (0x401..=0x40C | 0x40E..=0x44F | 0x451..=0x45C | 0x45E..=0x45F) => {
+            Some((code - 0x400 + 0xA0) as u8)
+        }
+        _ => None,
+    }
+}
+
+/// Character of an ISO/IEC 8859-8 octet, [`None`] for the unassigned ones
+pub const fn hebrew_char(octet: u8) -> Option<char> {
+    let code = match octet {
+        0x00..=0xA0 | 0xA2..=0xA9 | 0xAB..=0xB9 | 0xBB..=0xBE => octet as u32,
+        0xAA => 0xD7,
+        0xBA => 0xF7,
+        0xDF => 0x2017,
+        0xE0..=0xFA => octet as u32 - 0xE0 + 0x5D0,
+        0xFD => 0x200E,
+        0xFE => 0x200F,
+        _ => return None,
+    };
+
+    char::from_u32(code)
+}
+
+/// ISO/IEC 8859-8 octet of a character
+pub fn hebrew_octet(c: char) -> Option<u8> {
+    match c as u32 {
+        code @ (0x00..=0xA0 | 0xA2..=0xA9 | 0xAB..=0xB9 | 0xBB..=0xBE) => Some(code as u8),
+        0xD7 => Some(0xAA),
+        0xF7 => Some(0xBA),
+        0x2017 => Some(0xDF),
+        code @ 0x5D0..=0x5EA => Some((code - 0x5D0 + 0xE0) as u8),
+        0x200E => Some(0xFD),
+        0x200F => Some(0xFE),
+        _ => None,
+    }
+}
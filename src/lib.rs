@@ -4,12 +4,22 @@ pub mod apps;
 pub mod background;
 pub mod client;
 pub mod colors;
+pub mod correlation;
+pub mod export;
+pub mod gsm7;
+pub mod hex;
+pub mod import;
 pub mod insight;
+pub mod inspect;
+pub mod iso8859;
+pub mod receipt;
 pub mod result;
 pub mod runtime;
 pub mod state;
+pub mod store;
 pub mod types;
 pub mod values;
 pub mod widgets;
+pub mod wire;
 
 pub use app::App;
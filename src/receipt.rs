@@ -0,0 +1,162 @@
+use rusmpp::{
+    pdus::DeliverSm,
+    tlvs::TlvValue,
+    values::{MessageState, MessageType},
+};
+
+/// Delivery receipt carried in a `DeliverSm`
+///
+/// Parsed from the de-facto standard short message format
+/// `id:IIIIIIIIII sub:SSS dlvrd:DDD submit date:YYMMDDhhmm done date:YYMMDDhhmm stat:DDDDDDD err:E text:...`
+/// and the `receipted_message_id`/`message_state` TLVs.
+#[derive(Debug, Clone, Default)]
+pub struct DeliveryReceipt {
+    pub id: Option<String>,
+    pub sub: Option<String>,
+    pub dlvrd: Option<String>,
+    pub submit_date: Option<String>,
+    pub done_date: Option<String>,
+    pub stat: Option<String>,
+    pub err: Option<String>,
+    pub text: Option<String>,
+    pub receipted_message_id: Option<String>,
+    pub message_state: Option<MessageState>,
+}
+
+const KEYS: [&str; 8] = [
+    "id:",
+    "sub:",
+    "dlvrd:",
+    "submit date:",
+    "done date:",
+    "stat:",
+    "err:",
+    "text:",
+];
+
+impl DeliveryReceipt {
+    /// Parses the receipt from a `DeliverSm`, if its `esm_class` marks it as an MC delivery receipt.
+    pub fn from_deliver_sm(deliver_sm: &DeliverSm) -> Option<Self> {
+        if deliver_sm.esm_class.message_type != MessageType::ShortMessageContainsMCDeliveryReceipt {
+            return None;
+        }
+
+        let text = String::from_utf8_lossy(deliver_sm.short_message().as_bytes());
+        let mut receipt = Self::parse(&text);
+
+        for tlv in deliver_sm.tlvs() {
+            match tlv.value() {
+                Some(TlvValue::ReceiptedMessageId(id)) => {
+                    receipt.receipted_message_id = Some(id.to_string())
+                }
+                Some(TlvValue::MessageState(state)) => receipt.message_state = Some(*state),
+                _ => {}
+            }
+        }
+
+        Some(receipt)
+    }
+
+    /// Parses the short message part of a delivery receipt.
+    ///
+    /// Keys are matched case-insensitively and may appear in any order. Missing keys are left as [`None`].
+    pub fn parse(text: &str) -> Self {
+        // ASCII lowercasing keeps byte offsets intact, so positions can be used to slice the original text.
+        let lower = text.to_ascii_lowercase();
+
+        let mut positions = KEYS
+            .iter()
+            .enumerate()
+            .filter_map(|(index, key)| find_key(&lower, key).map(|position| (position, index)))
+            .collect::<Vec<_>>();
+
+        positions.sort_unstable();
+
+        let mut values: [Option<String>; 8] = Default::default();
+
+        for (i, (position, index)) in positions.iter().enumerate() {
+            let start = position + KEYS[*index].len();
+
+            // `text` is the last field by convention and may contain spaces and other keys.
+            let end = if KEYS[*index] == "text:" {
+                text.len()
+            } else {
+                positions
+                    .get(i + 1)
+                    .map(|(next, _)| *next)
+                    .unwrap_or(text.len())
+            };
+
+            values[*index] = Some(text[start..end.max(start)].trim().to_string());
+        }
+
+        let [id, sub, dlvrd, submit_date, done_date, stat, err, text] = values;
+
+        Self {
+            id,
+            sub,
+            dlvrd,
+            submit_date,
+            done_date,
+            stat,
+            err,
+            text,
+            receipted_message_id: None,
+            message_state: None,
+        }
+    }
+
+    /// The id of the receipted message, preferring the `receipted_message_id` TLV.
+    pub fn message_id(&self) -> Option<&str> {
+        self.receipted_message_id
+            .as_deref()
+            .or(self.id.as_deref())
+            .filter(|id| !id.is_empty())
+    }
+
+    /// Human readable state of the receipted message, preferring the `stat` field.
+    pub fn state(&self) -> Option<String> {
+        self.stat
+            .clone()
+            .filter(|stat| !stat.is_empty())
+            .or_else(|| self.message_state.map(|state| format!("{state:?}")))
+    }
+
+    /// Whether the receipt reports a final state of the message.
+    pub fn is_final(&self) -> bool {
+        if let Some(state) = self.message_state {
+            return !matches!(
+                state,
+                MessageState::Scheduled | MessageState::Enroute | MessageState::Accepted
+            );
+        }
+
+        self.stat.as_deref().is_some_and(|stat| {
+            !matches!(
+                stat.to_ascii_uppercase().as_str(),
+                "" | "ENROUTE" | "ACCEPTD" | "SCHEDULED"
+            )
+        })
+    }
+
+    /// Whether the receipt reports a successful delivery.
+    pub fn is_delivered(&self) -> bool {
+        self.stat
+            .as_deref()
+            .is_some_and(|stat| stat.eq_ignore_ascii_case("DELIVRD"))
+            || self.message_state == Some(MessageState::Delivered)
+    }
+}
+
+/// Finds a key at the start of the text or after whitespace, so that `id:` does not match inside `dlvrd:`.
+fn find_key(text: &str, key: &str) -> Option<usize> {
+    text.match_indices(key)
+        .map(|(position, _)| position)
+        .find(|position| {
+            *position == 0
+                || text[..*position]
+                    .chars()
+                    .next_back()
+                    .is_some_and(char::is_whitespace)
+        })
+}
@@ -5,12 +5,27 @@ pub type AppResult<T> = Result<T, AppUiError>;
 #[derive(Debug)]
 pub enum AppActionError {
     Connection(anyhow::Error),
+    /// Accepting an `Outbind` session failed
+    #[cfg(not(target_arch = "wasm32"))]
+    Listen(anyhow::Error),
     Bind(rusmppc::error::Error),
     SubmitSm(rusmppc::error::Error),
+    QuerySm(rusmppc::error::Error),
+    CancelSm(rusmppc::error::Error),
+    ReplaceSm(rusmppc::error::Error),
+    SubmitMulti(rusmppc::error::Error),
+    DataSm(rusmppc::error::Error),
+    BroadcastSm(rusmppc::error::Error),
+    QueryBroadcastSm(rusmppc::error::Error),
+    CancelBroadcastSm(rusmppc::error::Error),
     Unbind(rusmppc::error::Error),
     Close(rusmppc::error::Error),
+    DeliverSmResp(rusmppc::error::Error),
+    DataSmResp(rusmppc::error::Error),
     /// rusmppc event stream background error
     Background(rusmppc::error::Error),
+    /// Error restored from an imported log, only its summary is kept
+    Imported(String),
 }
 
 #[derive(Debug, Clone)]
@@ -65,9 +80,106 @@ impl AppUiError {
         Self::Field(SmppFieldError::PriorityFlag)
     }
 
+    pub const fn invalid_message_id() -> Self {
+        Self::Field(SmppFieldError::MessageId)
+    }
+
+    pub const fn invalid_schedule_delivery_time() -> Self {
+        Self::Field(SmppFieldError::ScheduleDeliveryTime)
+    }
+
+    pub const fn invalid_validity_period() -> Self {
+        Self::Field(SmppFieldError::ValidityPeriod)
+    }
+
+    pub const fn invalid_smpp_time(field: &'static str, error: SmppTimeError) -> Self {
+        Self::Field(SmppFieldError::SmppTime { field, error })
+    }
+
+    pub const fn invalid_registered_delivery() -> Self {
+        Self::Field(SmppFieldError::RegisteredDelivery)
+    }
+
+    pub const fn invalid_short_message() -> Self {
+        Self::Field(SmppFieldError::ShortMessage)
+    }
+
+    pub const fn invalid_number_of_dests() -> Self {
+        Self::Field(SmppFieldError::NumberOfDests)
+    }
+
+    pub const fn invalid_dl_name() -> Self {
+        Self::Field(SmppFieldError::DlName)
+    }
+
+    pub const fn invalid_message_payload() -> Self {
+        Self::Field(SmppFieldError::MessagePayload)
+    }
+
+    pub const fn invalid_user_message_reference() -> Self {
+        Self::Field(SmppFieldError::UserMessageReference)
+    }
+
+    pub const fn invalid_source_port() -> Self {
+        Self::Field(SmppFieldError::SourcePort)
+    }
+
+    pub const fn invalid_destination_port() -> Self {
+        Self::Field(SmppFieldError::DestinationPort)
+    }
+
+    pub const fn invalid_qos_time_to_live() -> Self {
+        Self::Field(SmppFieldError::QosTimeToLive)
+    }
+
+    pub const fn invalid_broadcast_area() -> Self {
+        Self::Field(SmppFieldError::BroadcastArea)
+    }
+
+    pub const fn invalid_broadcast_content_type() -> Self {
+        Self::Field(SmppFieldError::BroadcastContentType)
+    }
+
+    pub const fn invalid_broadcast_rep_num() -> Self {
+        Self::Field(SmppFieldError::BroadcastRepNum)
+    }
+
+    pub const fn invalid_broadcast_frequency_interval() -> Self {
+        Self::Field(SmppFieldError::BroadcastFrequencyInterval)
+    }
+
+    pub const fn invalid_tlv_tag() -> Self {
+        Self::Field(SmppFieldError::TlvTag)
+    }
+
+    pub const fn invalid_tlv_value() -> Self {
+        Self::Field(SmppFieldError::TlvValue)
+    }
+
+    pub const fn invalid_callback_num() -> Self {
+        Self::Field(SmppFieldError::CallbackNum)
+    }
+
+    pub const fn duplicate_tlv() -> Self {
+        Self::Field(SmppFieldError::DuplicateTlv)
+    }
+
+    pub const fn invalid_data_coding_override() -> Self {
+        Self::Field(SmppFieldError::DataCodingOverride)
+    }
+
     pub const fn invalid_enquire_link_interval() -> Self {
         Self::Field(SmppFieldError::EnquireLinkInterval)
     }
+
+    pub const fn invalid_deliver_sm_resp_delay() -> Self {
+        Self::Field(SmppFieldError::DeliverSmRespDelay)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub const fn invalid_listen_address() -> Self {
+        Self::Field(SmppFieldError::ListenAddress)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -90,8 +202,60 @@ pub enum SmppFieldError {
     SmDefaultMsgId,
     /// Invalid Priority Flag
     PriorityFlag,
+    /// Invalid Message ID
+    MessageId,
+    /// Invalid Schedule Delivery Time
+    ScheduleDeliveryTime,
+    /// Invalid Validity Period
+    ValidityPeriod,
+    /// Invalid Registered Delivery
+    RegisteredDelivery,
+    /// Invalid Short Message
+    ShortMessage,
+    /// Invalid Number of Dests
+    NumberOfDests,
+    /// Invalid Distribution List Name
+    DlName,
+    /// Invalid Message Payload
+    MessagePayload,
+    /// Invalid User Message Reference
+    UserMessageReference,
+    /// Invalid Source Port
+    SourcePort,
+    /// Invalid Destination Port
+    DestinationPort,
+    /// Invalid QoS Time To Live
+    QosTimeToLive,
+    /// Invalid Broadcast Area
+    BroadcastArea,
+    /// Invalid Broadcast Content Type
+    BroadcastContentType,
+    /// Invalid Broadcast Rep Num
+    BroadcastRepNum,
+    /// Invalid Broadcast Frequency Interval
+    BroadcastFrequencyInterval,
+    /// Invalid raw TLV Tag
+    TlvTag,
+    /// Invalid raw TLV Value
+    TlvValue,
+    /// Invalid Callback Num
+    CallbackNum,
+    /// TLV Tag already present
+    DuplicateTlv,
+    /// Invalid absolute or relative time of the named field
+    SmppTime {
+        field: &'static str,
+        error: SmppTimeError,
+    },
+    /// Invalid Data Coding Override
+    DataCodingOverride,
     /// Invalid Enquire Link Interval
     EnquireLinkInterval,
+    /// Invalid Deliver Sm Response Delay
+    DeliverSmRespDelay,
+    /// Invalid Outbind Listen Address
+    #[cfg(not(target_arch = "wasm32"))]
+    ListenAddress,
 }
 
 impl SmppFieldError {
@@ -114,9 +278,70 @@ impl SmppFieldError {
             SmppFieldError::PriorityFlag => {
                 "Priority Flag must be a valid unsigned 8-bit integer.".into()
             }
+            SmppFieldError::MessageId => "Message ID must be 1-64 ascii octets long.".into(),
+            SmppFieldError::ScheduleDeliveryTime => {
+                "Schedule Delivery Time must be empty or 16 ascii octets long.".into()
+            }
+            SmppFieldError::ValidityPeriod => {
+                "Validity Period must be empty or 16 ascii octets long.".into()
+            }
+            SmppFieldError::RegisteredDelivery => {
+                "Registered Delivery must be a valid unsigned 8-bit integer.".into()
+            }
+            SmppFieldError::ShortMessage => {
+                "Short Message must fit in 255 octets once encoded.".into()
+            }
+            SmppFieldError::NumberOfDests => "Number of Dests must be 1-254.".into(),
+            SmppFieldError::DlName => {
+                "Distribution List Name must be 1-20 ascii octets long.".into()
+            }
+            SmppFieldError::MessagePayload => {
+                "Message Payload must fit in 65535 octets once encoded.".into()
+            }
+            SmppFieldError::UserMessageReference => {
+                "User Message Reference must be empty or a valid unsigned 16-bit integer.".into()
+            }
+            SmppFieldError::SourcePort => {
+                "Source Port must be empty or a valid unsigned 16-bit integer.".into()
+            }
+            SmppFieldError::DestinationPort => {
+                "Destination Port must be empty or a valid unsigned 16-bit integer.".into()
+            }
+            SmppFieldError::QosTimeToLive => {
+                "QoS Time To Live must be empty or a valid unsigned 32-bit integer.".into()
+            }
+            SmppFieldError::BroadcastArea => "Broadcast Area must be non empty hex octets.".into(),
+            SmppFieldError::BroadcastContentType => {
+                "Broadcast Content Type must be a valid unsigned 16-bit integer.".into()
+            }
+            SmppFieldError::BroadcastRepNum => {
+                "Broadcast Rep Num must be a valid unsigned 16-bit integer.".into()
+            }
+            SmppFieldError::BroadcastFrequencyInterval => {
+                "Broadcast Frequency Interval must be a valid unsigned 16-bit integer.".into()
+            }
+            SmppFieldError::TlvTag => {
+                "Raw TLV Tag must be hex of a tag without its own kind.".into()
+            }
+            SmppFieldError::TlvValue => "Raw TLV Value must be hex of at most 65535 octets.".into(),
+            SmppFieldError::CallbackNum => "Callback Num must be 1-16 ascii digits.".into(),
+            SmppFieldError::DuplicateTlv => "TLV Tag is already present.".into(),
+            SmppFieldError::SmppTime { field, error } => {
+                format!("{field} {}", error.display_message()).into()
+            }
+            SmppFieldError::DataCodingOverride => {
+                "Data Coding Override must be empty or a valid unsigned 8-bit integer.".into()
+            }
             SmppFieldError::EnquireLinkInterval => {
                 "Enquire Link Interval must be a valid positive integer.".into()
             }
+            SmppFieldError::DeliverSmRespDelay => {
+                "Deliver Sm Response Delay must be a valid positive integer.".into()
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            SmppFieldError::ListenAddress => {
+                "Listen Address must be a socket address, e.g. 127.0.0.1:2775.".into()
+            }
         }
     }
 }
@@ -141,15 +366,95 @@ impl SmppUrlError {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum SmppTimeError {
+    /// Year outside of 2000-2099, the format only has two digits
+    Year,
+    /// Day not in the month
+    Date,
+    /// Hour, minute, second or tenths out of range
+    Time,
+    /// More than 48 quarter hours from UTC
+    UtcOffset,
+    /// Relative field above 99
+    RelativeField,
+    /// Relative time of zero
+    ZeroDuration,
+}
+
+impl SmppTimeError {
+    pub const fn display_message(&self) -> &'static str {
+        match self {
+            SmppTimeError::Year => "year must be between 2000 and 2099.",
+            SmppTimeError::Date => "date does not exist.",
+            SmppTimeError::Time => "time of day is out of range.",
+            SmppTimeError::UtcOffset => "UTC offset must be within 12 hours.",
+            SmppTimeError::RelativeField => "relative fields must be 0-99.",
+            SmppTimeError::ZeroDuration => "relative duration must not be zero.",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum MultiPartError {
-    // TODO
-    Todo,
+    /// Character the data coding can not represent, at its zero based position in the message
+    UnencodableCharacter { position: usize, character: char },
+    /// Binary message that is not hex
+    InvalidHex,
+    /// More short messages than the 255 a concatenation UDH or the SAR TLVs can count
+    TooManySegments(usize),
+    /// Nothing to send
+    EmptyMessage,
+    /// No room left for the message after the user data header
+    HeaderOverflow {
+        part_header_size: usize,
+        max_message_size: usize,
+    },
+    /// Message that must fit in a single short message or payload
+    TooLong { max_message_size: usize },
 }
 
 impl MultiPartError {
+    /// Finds the position of a character reported without one.
+    pub fn unencodable_character(message: &str, character: char) -> Self {
+        Self::UnencodableCharacter {
+            position: message
+                .chars()
+                .position(|c| c == character)
+                .unwrap_or_default(),
+            character,
+        }
+    }
+
     pub fn display_message(&self) -> Cow<'static, str> {
-        // TODO
-        "".into()
+        match self {
+            MultiPartError::UnencodableCharacter {
+                position,
+                character,
+            } => format!(
+                "Character {character:?} (U+{:04X}) at position {} is not supported by the data coding, pick a wider one or Auto.",
+                *character as u32,
+                position + 1
+            )
+            .into(),
+            MultiPartError::InvalidHex => {
+                "Binary messages must be hex octets, e.g. 0A1B2C.".into()
+            }
+            MultiPartError::TooManySegments(count) => format!(
+                "Message needs {count} short messages, at most 255 can be concatenated."
+            )
+            .into(),
+            MultiPartError::EmptyMessage => "Short Message is empty.".into(),
+            MultiPartError::HeaderOverflow {
+                part_header_size,
+                max_message_size,
+            } => format!(
+                "The user data header ({part_header_size}) leaves no room for the message in a short message ({max_message_size})."
+            )
+            .into(),
+            MultiPartError::TooLong { max_message_size } => {
+                format!("Message must fit in {max_message_size} octets once encoded.").into()
+            }
+        }
     }
 }
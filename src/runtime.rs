@@ -14,3 +14,7 @@ where
 {
     wasm_bindgen_futures::spawn_local(future)
 }
+
+pub async fn sleep(duration: std::time::Duration) {
+    futures_timer::Delay::new(duration).await
+}
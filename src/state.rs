@@ -1,10 +1,15 @@
-use std::{ops::Deref, sync::Arc};
+use std::{ops::Deref, sync::Arc, time::Duration};
 
 use eframe::egui::Context;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use rusmppc::Client;
+use web_time::Instant;
 
-use crate::{values::Event, widgets::BlinkerHandle};
+use crate::{
+    store::{DEFAULT_CAPACITY, EventStore, Snapshot},
+    values::{Event, Record},
+    widgets::BlinkerHandle,
+};
 
 #[derive(Clone)]
 pub struct AppState {
@@ -17,11 +22,44 @@ pub struct EventsHolder {
 }
 
 impl EventsHolder {
-    /// Get a read-only reference to the events
+    /// Brings the snapshot up to date with the stored events
     ///
-    /// The returned reference must dropped as soon as possible to avoid blocking writes
-    pub fn events(&self) -> impl Deref<Target = Vec<Event>> + '_ {
-        self.inner.events.read()
+    /// Only the lock of the store is held, and only for copying the new records
+    pub fn sync(&self, snapshot: &mut Snapshot) {
+        self.inner.events.lock().sync(snapshot);
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.inner.events.lock().capacity()
+    }
+
+    pub fn set_capacity(&self, capacity: usize) {
+        let evicted = self.inner.events.lock().set_capacity(capacity);
+
+        self.inner.spill(evicted);
+    }
+
+    /// Starts appending evicted events to the file, or stops if `path` is [`None`]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_spill(&self, path: Option<std::path::PathBuf>) -> std::io::Result<()> {
+        *self.inner.spill.lock() = path.map(crate::store::Spill::create).transpose()?;
+
+        Ok(())
+    }
+
+    /// Path of the spill file and the error that stopped spilling, if any
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spill_status(&self) -> Option<(std::path::PathBuf, Option<String>)> {
+        self.inner
+            .spill
+            .lock()
+            .as_ref()
+            .map(|spill| (spill.path().to_path_buf(), spill.error()))
+    }
+
+    /// Monotonic time since the app started, comparable to [`Record::uptime`]
+    pub fn uptime(&self) -> Duration {
+        self.inner.started.elapsed()
     }
 }
 
@@ -58,7 +96,10 @@ impl Deref for AppState {
 pub struct AppStateInner {
     ctx: Context,
     client: Arc<RwLock<Option<Client>>>,
-    events: RwLock<Vec<Event>>,
+    events: Mutex<EventStore>,
+    #[cfg(not(target_arch = "wasm32"))]
+    spill: Mutex<Option<crate::store::Spill>>,
+    started: Instant,
     incoming_event_blinker_handle: BlinkerHandle,
     outgoing_event_blinker_handle: BlinkerHandle,
 }
@@ -72,7 +113,10 @@ impl AppStateInner {
         Self {
             ctx,
             client: Arc::new(RwLock::new(None)),
-            events: RwLock::new(Vec::new()),
+            events: Mutex::new(EventStore::new(DEFAULT_CAPACITY)),
+            #[cfg(not(target_arch = "wasm32"))]
+            spill: Mutex::new(None),
+            started: Instant::now(),
             incoming_event_blinker_handle,
             outgoing_event_blinker_handle,
         }
@@ -95,20 +139,34 @@ impl AppStateInner {
     }
 
     pub fn push_event(&self, event: Event) {
-        self.events.write().push(event);
+        let evicted = self.events.lock().push(self.started.elapsed(), event);
+
+        self.spill(evicted);
     }
 
     pub fn extend_events(&self, events: impl Iterator<Item = Event>) {
-        self.events.write().extend(events);
+        let evicted = {
+            let mut store = self.events.lock();
+
+            events
+                .flat_map(|event| store.push(self.started.elapsed(), event))
+                .collect::<Vec<_>>()
+        };
+
+        self.spill(evicted);
     }
 
-    /// Get a read-only reference to the events
-    ///
-    /// The returned reference must dropped as soon as possible to avoid blocking writes
-    pub fn events(&self) -> impl Deref<Target = Vec<Event>> + '_ {
-        self.events.read()
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Only hands the records over to the spill thread, no file is touched here.
+    fn spill(&self, evicted: Vec<Arc<Record>>) {
+        if let Some(spill) = self.spill.lock().as_ref() {
+            spill.write(evicted);
+        }
     }
 
+    #[cfg(target_arch = "wasm32")]
+    fn spill(&self, _evicted: Vec<Arc<Record>>) {}
+
     pub fn client(&self) -> impl Deref<Target = Option<Client>> + '_ {
         self.client.read()
     }
@@ -0,0 +1,293 @@
+//! Bounded storage of the event log
+
+use std::{collections::VecDeque, sync::Arc, time::Duration};
+
+use crate::{
+    correlation::Correlator,
+    values::{Event, Record},
+};
+
+pub const DEFAULT_CAPACITY: usize = 10_000;
+pub const MIN_CAPACITY: usize = 100;
+pub const MAX_CAPACITY: usize = 1_000_000;
+
+/// Ring buffer of records, the oldest records are evicted once the capacity is reached
+pub struct EventStore {
+    records: VecDeque<Arc<Record>>,
+    capacity: usize,
+    next_id: u64,
+    correlator: Correlator,
+}
+
+impl EventStore {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            records: VecDeque::new(),
+            capacity: capacity.clamp(MIN_CAPACITY, MAX_CAPACITY),
+            next_id: 1,
+            correlator: Correlator::default(),
+        }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Stamps the event with the next id, pairs it with its request and stores it.
+    ///
+    /// Returns the evicted records.
+    pub fn push(&mut self, uptime: Duration, event: Event) -> Vec<Arc<Record>> {
+        let request = self.correlator.correlate(self.next_id, uptime, &event);
+        let record = Record::new(self.next_id, uptime, event, request);
+
+        self.next_id += 1;
+        self.records.push_back(Arc::new(record));
+
+        self.evict()
+    }
+
+    /// Returns the evicted records.
+    pub fn set_capacity(&mut self, capacity: usize) -> Vec<Arc<Record>> {
+        self.capacity = capacity.clamp(MIN_CAPACITY, MAX_CAPACITY);
+
+        self.evict()
+    }
+
+    fn evict(&mut self) -> Vec<Arc<Record>> {
+        let excess = self.records.len().saturating_sub(self.capacity);
+
+        self.records.drain(..excess).collect()
+    }
+
+    /// Brings the snapshot up to date, only touching the records that changed since the last sync.
+    pub fn sync(&self, snapshot: &mut Snapshot) {
+        match self.records.front() {
+            Some(first) => snapshot.evict_before(first.id),
+            None => snapshot.records.clear(),
+        }
+
+        let start = match snapshot.records.back() {
+            Some(last) => self.records.partition_point(|record| record.id <= last.id),
+            None => 0,
+        };
+
+        snapshot
+            .records
+            .extend(self.records.range(start..).cloned());
+    }
+}
+
+/// Records owned by the UI, so that rendering never holds the lock of the [`EventStore`]
+///
+/// Records are sorted by id, ids are not necessarily contiguous.
+#[derive(Default)]
+pub struct Snapshot {
+    records: VecDeque<Arc<Record>>,
+}
+
+impl Snapshot {
+    /// Sorts and correlates records read from a file.
+    pub fn from_records(mut records: Vec<Record>) -> Self {
+        records.sort_by_key(|record| record.id);
+
+        let mut correlator = Correlator::default();
+
+        for record in &mut records {
+            record.request = correlator.correlate(record.id, record.uptime, &record.event);
+        }
+
+        Self {
+            records: records.into_iter().map(Arc::new).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn first_id(&self) -> Option<u64> {
+        self.records.front().map(|record| record.id)
+    }
+
+    pub fn last(&self) -> Option<&Record> {
+        self.records.back().map(Arc::as_ref)
+    }
+
+    pub fn get(&self, id: u64) -> Option<&Record> {
+        self.records
+            .binary_search_by_key(&id, |record| record.id)
+            .ok()
+            .map(|index| self.records[index].as_ref())
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Record> {
+        self.records.iter().map(Arc::as_ref)
+    }
+
+    /// Records with an id greater than `id`, or all records if `id` is [`None`]
+    pub fn since(&self, id: Option<u64>) -> impl Iterator<Item = &Record> {
+        let start = match id {
+            Some(id) => self.records.partition_point(|record| record.id <= id),
+            None => 0,
+        };
+
+        self.records.range(start..).map(Arc::as_ref)
+    }
+
+    fn evict_before(&mut self, id: u64) {
+        let evicted = self.records.partition_point(|record| record.id < id);
+
+        self.records.drain(..evicted);
+    }
+}
+
+/// Position of a consumer that processes the records of a [`Snapshot`] incrementally
+///
+/// Every frame only looks at the records pushed since the last one, instead of the whole log.
+#[derive(Default)]
+pub struct Progress {
+    /// Id of the last processed record
+    processed: Option<u64>,
+}
+
+impl Progress {
+    /// Records pushed since the last call, which count as processed from now on.
+    pub fn advance<'a>(
+        &mut self,
+        snapshot: &'a Snapshot,
+    ) -> impl Iterator<Item = &'a Record> + use<'a> {
+        let processed = self.processed;
+
+        if let Some(last) = snapshot.last() {
+            self.processed = Some(last.id);
+        }
+
+        snapshot.since(processed)
+    }
+
+    /// Starts over with the first record.
+    pub fn reset(&mut self) {
+        self.processed = None;
+    }
+}
+
+/// How often the spill thread flushes buffered records to the file
+#[cfg(not(target_arch = "wasm32"))]
+const SPILL_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Appends evicted records to a JSON Lines file, in the format of [`ExportFormat::JsonLines`](crate::export::ExportFormat::JsonLines)
+///
+/// Records are serialized and written on a dedicated thread, so that evicting never blocks the UI or the background app.
+/// The thread flushes periodically and exits once the [`Spill`] is dropped.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct Spill {
+    path: std::path::PathBuf,
+    records: std::sync::mpsc::Sender<Vec<Arc<Record>>>,
+    /// Set on the first failed write, nothing is written afterwards
+    error: Arc<parking_lot::Mutex<Option<String>>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Spill {
+    pub fn create(path: std::path::PathBuf) -> std::io::Result<Self> {
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+
+        let (records, receiver) = std::sync::mpsc::channel();
+        let error = Arc::new(parking_lot::Mutex::new(None));
+
+        let writer = SpillWriter {
+            writer: std::io::BufWriter::new(file),
+            error: error.clone(),
+            dirty: false,
+        };
+
+        std::thread::Builder::new()
+            .name(String::from("spill"))
+            .spawn(move || writer.run(receiver))?;
+
+        Ok(Self {
+            path,
+            records,
+            error,
+        })
+    }
+
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.error.lock().clone()
+    }
+
+    /// Hands the records over to the spill thread.
+    pub fn write(&self, records: Vec<Arc<Record>>) {
+        if records.is_empty() {
+            return;
+        }
+
+        // The thread only exits after a failed write, which is already reported.
+        let _ = self.records.send(records);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct SpillWriter {
+    writer: std::io::BufWriter<std::fs::File>,
+    error: Arc<parking_lot::Mutex<Option<String>>>,
+    /// Whether records were written since the last flush
+    dirty: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SpillWriter {
+    fn run(mut self, receiver: std::sync::mpsc::Receiver<Vec<Arc<Record>>>) {
+        use std::sync::mpsc::RecvTimeoutError;
+
+        loop {
+            let result = match receiver.recv_timeout(SPILL_FLUSH_INTERVAL) {
+                Ok(records) => self.write(&records),
+                Err(RecvTimeoutError::Timeout) => self.flush(),
+                Err(RecvTimeoutError::Disconnected) => {
+                    let _ = self.flush();
+
+                    return;
+                }
+            };
+
+            if let Err(err) = result {
+                *self.error.lock() = Some(err.to_string());
+
+                return;
+            }
+        }
+    }
+
+    fn write(&mut self, records: &[Arc<Record>]) -> std::io::Result<()> {
+        use std::io::Write;
+
+        self.dirty = true;
+
+        records.iter().try_for_each(|record| {
+            self.writer
+                .write_all(crate::export::json_line(record).as_bytes())
+        })
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        use std::io::Write;
+
+        if !std::mem::take(&mut self.dirty) {
+            return Ok(());
+        }
+
+        self.writer.flush()
+    }
+}
@@ -1,20 +1,42 @@
+mod broadcast;
+mod concatenation;
 mod data_coding;
+mod dest_flag;
 mod esm_class;
 mod event;
 mod interface_version;
 mod mode;
 mod npi;
+mod payload_type;
 mod priority_flag;
+mod record;
+mod registered_delivery;
+mod response_status;
+mod shift_table;
+mod smpp_time;
+mod tlv;
 mod ton;
 
+pub use broadcast::{BroadcastAreaFormat, TypeOfNetwork, UnitOfTime};
+pub use concatenation::Concatenation;
 pub use data_coding::DataCoding;
+pub use dest_flag::DestFlag;
 pub use esm_class::{Ansi41Specific, EsmClass, GsmFeatures, MessageType, MessagingMode};
 pub use event::Event;
 pub use interface_version::InterfaceVersion;
 pub use mode::BindMode;
 pub use npi::Npi;
+pub use payload_type::PayloadType;
 pub use priority_flag::{
     Ansi41CbsPriorityFlag, Ansi136PriorityFlag, GsmCbsPriorityFlag, GsmSmsPriorityFlag,
     Is95PriorityFlag,
 };
+pub use record::Record;
+pub use registered_delivery::{
+    IntermediateNotification, MCDeliveryReceipt, RegisteredDelivery, SmeOriginatedAcknowledgement,
+};
+pub use response_status::ResponseStatus;
+pub use shift_table::{LockingShift, SingleShift};
+pub use smpp_time::{AbsoluteTime, SmppTime, SmppTimeFormat};
+pub use tlv::{LanguageIndicator, PrivacyIndicator, TlvEntry, TlvKind};
 pub use ton::Ton;
@@ -0,0 +1,81 @@
+use rusmpp::values::{
+    BroadcastAreaFormat as RusmppBroadcastAreaFormat, TypeOfNetwork as RusmppTypeOfNetwork,
+    UnitOfTime as RusmppUnitOfTime,
+};
+use serde::{Deserialize, Serialize};
+use strum::{IntoStaticStr, VariantArray};
+
+/// Format of the `broadcast_area_identifier` area
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum BroadcastAreaFormat {
+    #[default]
+    AliasName,
+    EllipsoidArc,
+    Polygon,
+}
+
+impl From<BroadcastAreaFormat> for RusmppBroadcastAreaFormat {
+    fn from(format: BroadcastAreaFormat) -> Self {
+        match format {
+            BroadcastAreaFormat::AliasName => RusmppBroadcastAreaFormat::AliasName,
+            BroadcastAreaFormat::EllipsoidArc => RusmppBroadcastAreaFormat::EllipsoidArc,
+            BroadcastAreaFormat::Polygon => RusmppBroadcastAreaFormat::Polygon,
+        }
+    }
+}
+
+/// Network of the `broadcast_content_type`
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum TypeOfNetwork {
+    #[default]
+    Generic,
+    Gsm,
+    Tdma,
+    Cdma,
+}
+
+impl From<TypeOfNetwork> for RusmppTypeOfNetwork {
+    fn from(type_of_network: TypeOfNetwork) -> Self {
+        match type_of_network {
+            TypeOfNetwork::Generic => RusmppTypeOfNetwork::Generic,
+            TypeOfNetwork::Gsm => RusmppTypeOfNetwork::Gsm,
+            TypeOfNetwork::Tdma => RusmppTypeOfNetwork::Tdma,
+            TypeOfNetwork::Cdma => RusmppTypeOfNetwork::Cdma,
+        }
+    }
+}
+
+/// Unit of the `broadcast_frequency_interval`
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum UnitOfTime {
+    AsFrequentlyAsPossible,
+    Seconds,
+    #[default]
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl From<UnitOfTime> for RusmppUnitOfTime {
+    fn from(unit: UnitOfTime) -> Self {
+        match unit {
+            UnitOfTime::AsFrequentlyAsPossible => RusmppUnitOfTime::AsFrequentlyAsPossible,
+            UnitOfTime::Seconds => RusmppUnitOfTime::Seconds,
+            UnitOfTime::Minutes => RusmppUnitOfTime::Minutes,
+            UnitOfTime::Hours => RusmppUnitOfTime::Hours,
+            UnitOfTime::Days => RusmppUnitOfTime::Days,
+            UnitOfTime::Weeks => RusmppUnitOfTime::Weeks,
+            UnitOfTime::Months => RusmppUnitOfTime::Months,
+            UnitOfTime::Years => RusmppUnitOfTime::Years,
+        }
+    }
+}
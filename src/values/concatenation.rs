@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use strum::{IntoStaticStr, VariantArray};
+
+/// How a message that does not fit in one short message is sent
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum Concatenation {
+    #[default]
+    #[strum(serialize = "UDH 8-bit")]
+    Udh8Bit,
+    #[strum(serialize = "UDH 16-bit")]
+    Udh16Bit,
+    #[strum(serialize = "SAR TLVs")]
+    SarTlvs,
+    #[strum(serialize = "Message Payload")]
+    MessagePayload,
+}
+
+impl Concatenation {
+    /// Whether the parts start with a user data header, which must be flagged in the `esm_class`
+    pub const fn uses_udh(&self) -> bool {
+        matches!(self, Concatenation::Udh8Bit | Concatenation::Udh16Bit)
+    }
+
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Concatenation::Udh8Bit => "Each part starts with a UDH with an 8-bit reference",
+            Concatenation::Udh16Bit => "Each part starts with a UDH with a 16-bit reference",
+            Concatenation::SarTlvs => {
+                "Parts carry sar_msg_ref_num, sar_total_segments and sar_segment_seqnum TLVs"
+            }
+            Concatenation::MessagePayload => {
+                "A single SubmitSm with the whole message in the message_payload TLV"
+            }
+        }
+    }
+}
@@ -6,18 +6,73 @@ use strum::{IntoStaticStr, VariantArray};
     IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
 )]
 pub enum DataCoding {
+    /// The encoding with the fewest short messages, picked from the message
+    Auto,
     #[default]
     Gsm7BitUnpacked,
+    Gsm7BitPacked,
+    Ia5,
     Latin1,
     Ucs2,
+    /// Binary, the message is entered as hex
+    OctetUnspecified,
+    /// Binary, the message is entered as hex
+    OctetUnspecified2,
+    Cyrillic,
+    LatinHebrew,
+}
+
+impl DataCoding {
+    /// Data codings with a fixed encoding, for the PDUs that do not pick one from the message
+    pub const FIXED: &[DataCoding] = &[
+        DataCoding::Gsm7BitUnpacked,
+        DataCoding::Gsm7BitPacked,
+        DataCoding::Ia5,
+        DataCoding::Latin1,
+        DataCoding::Ucs2,
+        DataCoding::OctetUnspecified,
+        DataCoding::OctetUnspecified2,
+        DataCoding::Cyrillic,
+        DataCoding::LatinHebrew,
+    ];
+
+    /// Candidates of [`DataCoding::Auto`], cheapest first
+    pub const AUTO_CANDIDATES: [DataCoding; 3] = [
+        DataCoding::Gsm7BitUnpacked,
+        DataCoding::Latin1,
+        DataCoding::Ucs2,
+    ];
+
+    /// Whether the message may be encoded with the GSM 7-bit tables
+    pub const fn is_gsm7(&self) -> bool {
+        matches!(
+            self,
+            DataCoding::Auto | DataCoding::Gsm7BitUnpacked | DataCoding::Gsm7BitPacked
+        )
+    }
+
+    /// Whether the message is entered as hex rather than text
+    pub const fn is_binary(&self) -> bool {
+        matches!(
+            self,
+            DataCoding::OctetUnspecified | DataCoding::OctetUnspecified2
+        )
+    }
 }
 
 impl From<DataCoding> for RusmppDataCoding {
     fn from(data_coding: DataCoding) -> Self {
         match data_coding {
-            DataCoding::Gsm7BitUnpacked => RusmppDataCoding::McSpecific,
+            DataCoding::Auto | DataCoding::Gsm7BitUnpacked | DataCoding::Gsm7BitPacked => {
+                RusmppDataCoding::McSpecific
+            }
+            DataCoding::Ia5 => RusmppDataCoding::Ia5,
             DataCoding::Latin1 => RusmppDataCoding::Latin1,
             DataCoding::Ucs2 => RusmppDataCoding::Ucs2,
+            DataCoding::OctetUnspecified => RusmppDataCoding::OctetUnspecified,
+            DataCoding::OctetUnspecified2 => RusmppDataCoding::OctetUnspecified2,
+            DataCoding::Cyrillic => RusmppDataCoding::Cyrillic,
+            DataCoding::LatinHebrew => RusmppDataCoding::LatinHebrew,
         }
     }
 }
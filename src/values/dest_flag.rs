@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use strum::VariantArray;
+
+/// Kind of a `SubmitMulti` destination
+#[derive(VariantArray, Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum DestFlag {
+    #[default]
+    SmeAddress,
+    DistributionList,
+}
+
+impl ::core::convert::From<DestFlag> for &'static str {
+    #[inline]
+    fn from(x: DestFlag) -> &'static str {
+        match x {
+            DestFlag::SmeAddress => "SME Address",
+            DestFlag::DistributionList => "Distribution List",
+        }
+    }
+}
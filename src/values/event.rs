@@ -12,3 +12,47 @@ pub enum Event {
     Sent(Command),
     Received(Command),
 }
+
+impl Event {
+    pub const fn command(&self) -> Option<&Command> {
+        match self {
+            Event::Sent(command) | Event::Received(command) => Some(command),
+            _ => None,
+        }
+    }
+
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Event::Error(_) => "Error",
+            Event::Connected => "Connected",
+            Event::Disconnected => "Disconnected",
+            Event::Closed => "Closed",
+            Event::Bound => "Bound",
+            Event::Sent(_) => "Sent",
+            Event::Received(_) => "Received",
+        }
+    }
+
+    /// One line description of the event
+    pub fn summary(&self) -> String {
+        match self {
+            Event::Error(AppActionError::Imported(summary)) => summary.clone(),
+            Event::Error(err) => format!("Error: {err:?}"),
+            Event::Connected => String::from("Connected"),
+            Event::Disconnected => String::from("Disconnected"),
+            Event::Closed => String::from("Closed"),
+            Event::Bound => String::from("Bound"),
+            Event::Sent(command) => format!("Sent {}", command_summary(command)),
+            Event::Received(command) => format!("Received {}", command_summary(command)),
+        }
+    }
+}
+
+fn command_summary(command: &Command) -> String {
+    format!(
+        "{:?} #{} {:?}",
+        command.id(),
+        command.sequence_number(),
+        command.status()
+    )
+}
@@ -35,3 +35,22 @@ impl From<Npi> for RusmppNpi {
         }
     }
 }
+
+impl From<RusmppNpi> for Npi {
+    /// Values outside of the specification are mapped to [`Npi::Unknown`].
+    fn from(npi: RusmppNpi) -> Self {
+        match npi {
+            RusmppNpi::Unknown => Npi::Unknown,
+            RusmppNpi::Isdn => Npi::Isdn,
+            RusmppNpi::Data => Npi::Data,
+            RusmppNpi::Telex => Npi::Telex,
+            RusmppNpi::LandMobile => Npi::LandMobile,
+            RusmppNpi::National => Npi::National,
+            RusmppNpi::Private => Npi::Private,
+            RusmppNpi::Ermes => Npi::Ermes,
+            RusmppNpi::Internet => Npi::Internet,
+            RusmppNpi::WapClientId => Npi::WapClientId,
+            _ => Npi::Unknown,
+        }
+    }
+}
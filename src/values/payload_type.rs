@@ -0,0 +1,21 @@
+use rusmpp::values::PayloadType as RusmppPayloadType;
+use serde::{Deserialize, Serialize};
+use strum::{IntoStaticStr, VariantArray};
+
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum PayloadType {
+    #[default]
+    Default,
+    WcmpMessage,
+}
+
+impl From<PayloadType> for RusmppPayloadType {
+    fn from(payload_type: PayloadType) -> Self {
+        match payload_type {
+            PayloadType::Default => RusmppPayloadType::Default,
+            PayloadType::WcmpMessage => RusmppPayloadType::WcmpMessage,
+        }
+    }
+}
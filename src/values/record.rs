@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Local, Utc};
+
+use crate::{correlation::Request, values::Event};
+
+/// An [`Event`] as stored in the event log
+#[derive(Debug)]
+pub struct Record {
+    /// Unique and increasing for the lifetime of the app
+    pub id: u64,
+    /// Wall-clock time the event was pushed at
+    pub time: DateTime<Utc>,
+    /// Monotonic time since the app started, unaffected by clock changes
+    pub uptime: Duration,
+    pub event: Event,
+    /// The request this record responds to, if it is a response
+    pub request: Option<Request>,
+}
+
+impl Record {
+    pub fn new(id: u64, uptime: Duration, event: Event, request: Option<Request>) -> Self {
+        Self {
+            id,
+            time: Utc::now(),
+            uptime,
+            event,
+            request,
+        }
+    }
+
+    /// Local wall-clock time with milliseconds
+    pub fn local_time_str(&self) -> String {
+        self.time
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M:%S%.3f")
+            .to_string()
+    }
+
+    /// Monotonic time as `+seconds.millis`
+    pub fn uptime_str(&self) -> String {
+        format!("+{:.3}s", self.uptime.as_secs_f64())
+    }
+}
@@ -0,0 +1,119 @@
+use rusmpp::values::{
+    IntermediateNotification as RusmppIntermediateNotification,
+    MCDeliveryReceipt as RusmppMCDeliveryReceipt, RegisteredDelivery as RusmppRegisteredDelivery,
+    SmeOriginatedAcknowledgement as RusmppSmeOriginatedAcknowledgement,
+};
+use serde::{Deserialize, Serialize};
+use strum::{IntoStaticStr, VariantArray};
+
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RegisteredDelivery {
+    pub mc_delivery_receipt: MCDeliveryReceipt,
+    pub sme_originated_acknowledgement: SmeOriginatedAcknowledgement,
+    pub intermediate_notification: IntermediateNotification,
+}
+
+impl RegisteredDelivery {
+    pub fn new(
+        mc_delivery_receipt: MCDeliveryReceipt,
+        sme_originated_acknowledgement: SmeOriginatedAcknowledgement,
+        intermediate_notification: IntermediateNotification,
+    ) -> Self {
+        Self {
+            mc_delivery_receipt,
+            sme_originated_acknowledgement,
+            intermediate_notification,
+        }
+    }
+}
+
+impl From<RegisteredDelivery> for RusmppRegisteredDelivery {
+    fn from(value: RegisteredDelivery) -> Self {
+        RusmppRegisteredDelivery::new(
+            RusmppMCDeliveryReceipt::from(value.mc_delivery_receipt),
+            RusmppSmeOriginatedAcknowledgement::from(value.sme_originated_acknowledgement),
+            RusmppIntermediateNotification::from(value.intermediate_notification),
+            0,
+        )
+    }
+}
+
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum MCDeliveryReceipt {
+    #[default]
+    NotRequested,
+    SuccessOrFailure,
+    Failure,
+    Success,
+}
+
+impl From<MCDeliveryReceipt> for RusmppMCDeliveryReceipt {
+    fn from(value: MCDeliveryReceipt) -> Self {
+        match value {
+            MCDeliveryReceipt::NotRequested => RusmppMCDeliveryReceipt::NoMcDeliveryReceiptRequested,
+            MCDeliveryReceipt::SuccessOrFailure => {
+                RusmppMCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsSuccessOrFailure
+            }
+            MCDeliveryReceipt::Failure => {
+                RusmppMCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsFailure
+            }
+            MCDeliveryReceipt::Success => {
+                RusmppMCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsSuccess
+            }
+        }
+    }
+}
+
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum SmeOriginatedAcknowledgement {
+    #[default]
+    NotRequested,
+    Delivery,
+    ManualUser,
+    DeliveryAndManualUser,
+}
+
+impl From<SmeOriginatedAcknowledgement> for RusmppSmeOriginatedAcknowledgement {
+    fn from(value: SmeOriginatedAcknowledgement) -> Self {
+        match value {
+            SmeOriginatedAcknowledgement::NotRequested => {
+                RusmppSmeOriginatedAcknowledgement::NoReceiptSmeAcknowledgementRequested
+            }
+            SmeOriginatedAcknowledgement::Delivery => {
+                RusmppSmeOriginatedAcknowledgement::SmeDeliveryAcknowledgementRequested
+            }
+            SmeOriginatedAcknowledgement::ManualUser => {
+                RusmppSmeOriginatedAcknowledgement::SmeManualUserAcknowledgementRequested
+            }
+            SmeOriginatedAcknowledgement::DeliveryAndManualUser => {
+                RusmppSmeOriginatedAcknowledgement::BothDeliveryAndManualUserAcknowledgmentRequested
+            }
+        }
+    }
+}
+
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum IntermediateNotification {
+    #[default]
+    NotRequested,
+    Requested,
+}
+
+impl From<IntermediateNotification> for RusmppIntermediateNotification {
+    fn from(value: IntermediateNotification) -> Self {
+        match value {
+            IntermediateNotification::NotRequested => {
+                RusmppIntermediateNotification::NoIntermediaryNotificationRequested
+            }
+            IntermediateNotification::Requested => {
+                RusmppIntermediateNotification::IntermediateNotificationRequested
+            }
+        }
+    }
+}
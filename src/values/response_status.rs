@@ -0,0 +1,42 @@
+use rusmpp::CommandStatus;
+use serde::{Deserialize, Serialize};
+use strum::VariantArray;
+
+/// Command status used when automatically responding to incoming requests
+#[derive(VariantArray, Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ResponseStatus {
+    #[default]
+    Ok,
+    SystemError,
+    Throttled,
+    ReceiverTemporaryAppError,
+    ReceiverPermanentAppError,
+    ReceiverRejectMessage,
+}
+
+impl From<ResponseStatus> for CommandStatus {
+    fn from(status: ResponseStatus) -> Self {
+        match status {
+            ResponseStatus::Ok => CommandStatus::EsmeRok,
+            ResponseStatus::SystemError => CommandStatus::EsmeRsyserr,
+            ResponseStatus::Throttled => CommandStatus::EsmeRthrottled,
+            ResponseStatus::ReceiverTemporaryAppError => CommandStatus::EsmeRxTAppn,
+            ResponseStatus::ReceiverPermanentAppError => CommandStatus::EsmeRxPAppn,
+            ResponseStatus::ReceiverRejectMessage => CommandStatus::EsmeRxRAppn,
+        }
+    }
+}
+
+impl ::core::convert::From<ResponseStatus> for &'static str {
+    #[inline]
+    fn from(x: ResponseStatus) -> &'static str {
+        match x {
+            ResponseStatus::Ok => "ESME_ROK",
+            ResponseStatus::SystemError => "ESME_RSYSERR",
+            ResponseStatus::Throttled => "ESME_RTHROTTLED",
+            ResponseStatus::ReceiverTemporaryAppError => "ESME_RX_T_APPN",
+            ResponseStatus::ReceiverPermanentAppError => "ESME_RX_P_APPN",
+            ResponseStatus::ReceiverRejectMessage => "ESME_RX_R_APPN",
+        }
+    }
+}
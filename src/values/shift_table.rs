@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use strum::{IntoStaticStr, VariantArray};
+
+use crate::gsm7::NationalLanguage;
+
+/// GSM 7-bit table replacing the default alphabet for the whole message
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum LockingShift {
+    #[default]
+    #[strum(serialize = "Default Alphabet")]
+    Default,
+    Turkish,
+    Portuguese,
+    Bengali,
+    Gujarati,
+    Hindi,
+    Kannada,
+    Malayalam,
+    Oriya,
+    Punjabi,
+    Tamil,
+    Telugu,
+    Urdu,
+}
+
+impl LockingShift {
+    pub const fn language(&self) -> Option<NationalLanguage> {
+        match self {
+            LockingShift::Default => None,
+            LockingShift::Turkish => Some(NationalLanguage::Turkish),
+            LockingShift::Portuguese => Some(NationalLanguage::Portuguese),
+            LockingShift::Bengali => Some(NationalLanguage::Bengali),
+            LockingShift::Gujarati => Some(NationalLanguage::Gujarati),
+            LockingShift::Hindi => Some(NationalLanguage::Hindi),
+            LockingShift::Kannada => Some(NationalLanguage::Kannada),
+            LockingShift::Malayalam => Some(NationalLanguage::Malayalam),
+            LockingShift::Oriya => Some(NationalLanguage::Oriya),
+            LockingShift::Punjabi => Some(NationalLanguage::Punjabi),
+            LockingShift::Tamil => Some(NationalLanguage::Tamil),
+            LockingShift::Telugu => Some(NationalLanguage::Telugu),
+            LockingShift::Urdu => Some(NationalLanguage::Urdu),
+        }
+    }
+}
+
+/// GSM 7-bit table replacing the extension table, reached through the escape septet
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum SingleShift {
+    #[default]
+    #[strum(serialize = "Default Extension")]
+    Default,
+    Turkish,
+    Spanish,
+    Portuguese,
+    Bengali,
+    Gujarati,
+    Hindi,
+    Kannada,
+    Malayalam,
+    Oriya,
+    Punjabi,
+    Tamil,
+    Telugu,
+    Urdu,
+}
+
+impl SingleShift {
+    pub const fn language(&self) -> Option<NationalLanguage> {
+        match self {
+            SingleShift::Default => None,
+            SingleShift::Turkish => Some(NationalLanguage::Turkish),
+            SingleShift::Spanish => Some(NationalLanguage::Spanish),
+            SingleShift::Portuguese => Some(NationalLanguage::Portuguese),
+            SingleShift::Bengali => Some(NationalLanguage::Bengali),
+            SingleShift::Gujarati => Some(NationalLanguage::Gujarati),
+            SingleShift::Hindi => Some(NationalLanguage::Hindi),
+            SingleShift::Kannada => Some(NationalLanguage::Kannada),
+            SingleShift::Malayalam => Some(NationalLanguage::Malayalam),
+            SingleShift::Oriya => Some(NationalLanguage::Oriya),
+            SingleShift::Punjabi => Some(NationalLanguage::Punjabi),
+            SingleShift::Tamil => Some(NationalLanguage::Tamil),
+            SingleShift::Telugu => Some(NationalLanguage::Telugu),
+            SingleShift::Urdu => Some(NationalLanguage::Urdu),
+        }
+    }
+}
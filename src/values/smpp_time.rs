@@ -0,0 +1,144 @@
+use chrono::{Datelike, Local, NaiveDate, Offset, Timelike};
+use serde::{Deserialize, Serialize};
+use strum::{IntoStaticStr, VariantArray};
+
+use crate::result::SmppTimeError;
+
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum SmppTimeFormat {
+    /// Empty, immediate delivery or the MC default validity
+    #[default]
+    #[strum(serialize = "Not Set")]
+    NotSet,
+    Absolute,
+    Relative,
+}
+
+/// `YYMMDDhhmmsstnnp`, local time with its offset from UTC in quarter hours
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbsoluteTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub tenths: u8,
+    pub utc_offset_quarters: i8,
+}
+
+impl AbsoluteTime {
+    pub fn now() -> Self {
+        let now = Local::now();
+        let offset_secs = now.offset().fix().local_minus_utc();
+
+        Self {
+            year: now.year().clamp(2000, 2099) as u16,
+            month: now.month() as u8,
+            day: now.day() as u8,
+            hour: now.hour() as u8,
+            minute: now.minute() as u8,
+            second: now.second() as u8,
+            tenths: (now.timestamp_subsec_millis() / 100) as u8,
+            utc_offset_quarters: (offset_secs / 900) as i8,
+        }
+    }
+
+    fn encode(&self) -> Result<String, SmppTimeError> {
+        if !(2000..=2099).contains(&self.year) {
+            return Err(SmppTimeError::Year);
+        }
+
+        NaiveDate::from_ymd_opt(self.year.into(), self.month.into(), self.day.into())
+            .ok_or(SmppTimeError::Date)?;
+
+        if self.hour > 23 || self.minute > 59 || self.second > 59 || self.tenths > 9 {
+            return Err(SmppTimeError::Time);
+        }
+
+        if !(-48..=48).contains(&self.utc_offset_quarters) {
+            return Err(SmppTimeError::UtcOffset);
+        }
+
+        Ok(format!(
+            "{:02}{:02}{:02}{:02}{:02}{:02}{}{:02}{}",
+            self.year % 100,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            self.tenths,
+            self.utc_offset_quarters.unsigned_abs(),
+            if self.utc_offset_quarters < 0 {
+                '-'
+            } else {
+                '+'
+            },
+        ))
+    }
+}
+
+impl Default for AbsoluteTime {
+    fn default() -> Self {
+        Self::now()
+    }
+}
+
+/// `YYMMDDhhmmss000R`, a duration from the time the MC received the message
+#[derive(Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelativeTime {
+    pub years: u8,
+    pub months: u8,
+    pub days: u8,
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+}
+
+impl RelativeTime {
+    fn encode(&self) -> Result<String, SmppTimeError> {
+        let values = [
+            self.years,
+            self.months,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+        ];
+
+        if values.iter().any(|value| *value > 99) {
+            return Err(SmppTimeError::RelativeField);
+        }
+
+        if values.iter().all(|value| *value == 0) {
+            return Err(SmppTimeError::ZeroDuration);
+        }
+
+        Ok(format!(
+            "{:02}{:02}{:02}{:02}{:02}{:02}000R",
+            self.years, self.months, self.days, self.hours, self.minutes, self.seconds,
+        ))
+    }
+}
+
+/// Schedule delivery time or validity period, both formats are kept while switching between them
+#[derive(Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SmppTime {
+    pub format: SmppTimeFormat,
+    pub absolute: AbsoluteTime,
+    pub relative: RelativeTime,
+}
+
+impl SmppTime {
+    /// The 16 characters sent on the wire, empty if not set
+    pub fn encode(&self) -> Result<String, SmppTimeError> {
+        match self.format {
+            SmppTimeFormat::NotSet => Ok(String::new()),
+            SmppTimeFormat::Absolute => self.absolute.encode(),
+            SmppTimeFormat::Relative => self.relative.encode(),
+        }
+    }
+}
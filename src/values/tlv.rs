@@ -0,0 +1,211 @@
+use rusmpp::{
+    tlvs::{MessageSubmissionRequestTlvValue, Tlv, TlvTag, TlvValue, UserMessageReference},
+    types::{AnyOctetString, OctetString},
+    values::{
+        LanguageIndicator as RusmppLanguageIndicator, Npi as RusmppNpi,
+        PrivacyIndicator as RusmppPrivacyIndicator, Ton as RusmppTon,
+    },
+};
+use serde::{Deserialize, Serialize};
+use strum::{IntoStaticStr, VariantArray};
+
+use crate::{
+    result::{AppResult, AppUiError},
+    values::{Npi, PayloadType, Ton},
+};
+
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum TlvKind {
+    #[default]
+    #[strum(serialize = "User Message Reference")]
+    UserMessageReference,
+    #[strum(serialize = "Source Port")]
+    SourcePort,
+    #[strum(serialize = "Destination Port")]
+    DestinationPort,
+    #[strum(serialize = "Payload Type")]
+    PayloadType,
+    #[strum(serialize = "Privacy Indicator")]
+    PrivacyIndicator,
+    #[strum(serialize = "Callback Num")]
+    CallbackNum,
+    #[strum(serialize = "Language Indicator")]
+    LanguageIndicator,
+    /// Any tag without its own kind, as hex
+    #[serde(alias = "Vendor")]
+    Raw,
+}
+
+impl TlvKind {
+    /// Tag of the typed kinds, [`None`] for raw TLVs
+    const fn typed_tag(self) -> Option<TlvTag> {
+        match self {
+            TlvKind::UserMessageReference => Some(TlvTag::UserMessageReference),
+            TlvKind::SourcePort => Some(TlvTag::SourcePort),
+            TlvKind::DestinationPort => Some(TlvTag::DestinationPort),
+            TlvKind::PayloadType => Some(TlvTag::PayloadType),
+            TlvKind::PrivacyIndicator => Some(TlvTag::PrivacyIndicator),
+            TlvKind::CallbackNum => Some(TlvTag::CallbackNum),
+            TlvKind::LanguageIndicator => Some(TlvTag::LanguageIndicator),
+            TlvKind::Raw => None,
+        }
+    }
+}
+
+/// One row of a TLV table, every kind keeps its own input so switching kinds loses nothing
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct TlvEntry {
+    pub kind: TlvKind,
+    /// User message reference or port
+    pub number: String,
+    pub payload_type: PayloadType,
+    pub privacy_indicator: PrivacyIndicator,
+    pub language_indicator: LanguageIndicator,
+    pub callback_num_ton: Ton,
+    pub callback_num_npi: Npi,
+    pub callback_num: String,
+    /// Raw tag in hex
+    pub tag: String,
+    /// Raw value in hex
+    pub value: String,
+}
+
+impl TlvEntry {
+    /// Tag the entry is encoded with, [`None`] for an invalid raw tag
+    pub fn tag(&self) -> Option<u16> {
+        match self.kind.typed_tag() {
+            Some(tag) => Some(tag.into()),
+            None => raw_tag(&self.tag),
+        }
+    }
+
+    pub fn build(&self) -> AppResult<Tlv> {
+        let value = match self.kind {
+            TlvKind::UserMessageReference => {
+                MessageSubmissionRequestTlvValue::UserMessageReference(UserMessageReference::new(
+                    self.number
+                        .parse()
+                        .map_err(|_| AppUiError::invalid_user_message_reference())?,
+                ))
+            }
+            TlvKind::SourcePort => MessageSubmissionRequestTlvValue::SourcePort(
+                self.number
+                    .parse()
+                    .map_err(|_| AppUiError::invalid_source_port())?,
+            ),
+            TlvKind::DestinationPort => MessageSubmissionRequestTlvValue::DestinationPort(
+                self.number
+                    .parse()
+                    .map_err(|_| AppUiError::invalid_destination_port())?,
+            ),
+            TlvKind::PayloadType => {
+                MessageSubmissionRequestTlvValue::PayloadType(self.payload_type.into())
+            }
+            TlvKind::PrivacyIndicator => {
+                MessageSubmissionRequestTlvValue::PrivacyIndicator(self.privacy_indicator.into())
+            }
+            TlvKind::CallbackNum => {
+                MessageSubmissionRequestTlvValue::CallbackNum(self.callback_num()?)
+            }
+            TlvKind::LanguageIndicator => {
+                MessageSubmissionRequestTlvValue::LanguageIndicator(self.language_indicator.into())
+            }
+            TlvKind::Raw => {
+                let tag = raw_tag(&self.tag).ok_or_else(AppUiError::invalid_tlv_tag)?;
+                let value = crate::hex::decode(&self.value)
+                    .and_then(|bytes| AnyOctetString::new(bytes).ok())
+                    .ok_or_else(AppUiError::invalid_tlv_value)?;
+
+                return Ok(Tlv::new(TlvValue::Other {
+                    tag: TlvTag::Other(tag),
+                    value,
+                }));
+            }
+        };
+
+        Ok(Tlv::new(value))
+    }
+
+    /// ASCII digit mode, TON, NPI and the digits
+    fn callback_num(&self) -> AppResult<OctetString<4, 19>> {
+        if self.callback_num.is_empty() || !self.callback_num.is_ascii() {
+            return Err(AppUiError::invalid_callback_num());
+        }
+
+        let mut bytes = vec![
+            0x01,
+            RusmppTon::from(self.callback_num_ton).into(),
+            RusmppNpi::from(self.callback_num_npi).into(),
+        ];
+
+        bytes.extend(self.callback_num.as_bytes());
+
+        OctetString::new(bytes).map_err(|_| AppUiError::invalid_callback_num())
+    }
+}
+
+/// Parses a hex tag, tags with a typed kind must use it.
+fn raw_tag(tag: &str) -> Option<u16> {
+    let tag = tag.trim();
+    let tag = tag
+        .strip_prefix("0x")
+        .or_else(|| tag.strip_prefix("0X"))
+        .unwrap_or(tag);
+
+    u16::from_str_radix(tag, 16).ok().filter(|tag| {
+        !TlvKind::VARIANTS
+            .iter()
+            .filter_map(|kind| kind.typed_tag())
+            .any(|typed| u16::from(typed) == *tag)
+    })
+}
+
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum PrivacyIndicator {
+    #[default]
+    NotRestricted,
+    Restricted,
+    Confidential,
+    Secret,
+}
+
+impl From<PrivacyIndicator> for RusmppPrivacyIndicator {
+    fn from(value: PrivacyIndicator) -> Self {
+        match value {
+            PrivacyIndicator::NotRestricted => RusmppPrivacyIndicator::NotRestricted,
+            PrivacyIndicator::Restricted => RusmppPrivacyIndicator::Restricted,
+            PrivacyIndicator::Confidential => RusmppPrivacyIndicator::Confidential,
+            PrivacyIndicator::Secret => RusmppPrivacyIndicator::Secret,
+        }
+    }
+}
+
+#[derive(
+    IntoStaticStr, VariantArray, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum LanguageIndicator {
+    #[default]
+    Unspecified,
+    English,
+    French,
+    Spanish,
+    German,
+    Portuguese,
+}
+
+impl From<LanguageIndicator> for RusmppLanguageIndicator {
+    fn from(value: LanguageIndicator) -> Self {
+        match value {
+            LanguageIndicator::Unspecified => RusmppLanguageIndicator::Unspecified,
+            LanguageIndicator::English => RusmppLanguageIndicator::English,
+            LanguageIndicator::French => RusmppLanguageIndicator::French,
+            LanguageIndicator::Spanish => RusmppLanguageIndicator::Spanish,
+            LanguageIndicator::German => RusmppLanguageIndicator::German,
+            LanguageIndicator::Portuguese => RusmppLanguageIndicator::Portuguese,
+        }
+    }
+}
@@ -29,3 +29,19 @@ impl From<Ton> for RusmppTon {
         }
     }
 }
+
+impl From<RusmppTon> for Ton {
+    /// Values outside of the specification are mapped to [`Ton::Unknown`].
+    fn from(ton: RusmppTon) -> Self {
+        match ton {
+            RusmppTon::Unknown => Ton::Unknown,
+            RusmppTon::International => Ton::International,
+            RusmppTon::National => Ton::National,
+            RusmppTon::NetworkSpecific => Ton::NetworkSpecific,
+            RusmppTon::SubscriberNumber => Ton::SubscriberNumber,
+            RusmppTon::Alphanumeric => Ton::Alphanumeric,
+            RusmppTon::Abbreviated => Ton::Abbreviated,
+            _ => Ton::Unknown,
+        }
+    }
+}
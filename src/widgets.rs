@@ -1,6 +1,9 @@
 mod combo_box;
 pub use combo_box::ComboBox;
 
+mod address_input;
+pub use address_input::AddressInput;
+
 mod bind_indicator;
 pub use bind_indicator::BindIndicator;
 
@@ -9,3 +12,12 @@ pub use blinker::BlinkerHandle;
 
 mod event_indicator;
 pub use event_indicator::EventIndicator;
+
+mod node_tree;
+pub use node_tree::NodeTree;
+
+mod hex_dump;
+pub use hex_dump::HexDump;
+
+mod smpp_time_input;
+pub use smpp_time_input::SmppTimeInput;
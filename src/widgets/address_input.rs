@@ -0,0 +1,67 @@
+use eframe::egui;
+use strum::VariantArray;
+
+use crate::{
+    values::{Npi, Ton},
+    widgets::ComboBox,
+};
+
+/// TON, NPI and address of an SME, laid out as six cells of a grid row
+pub struct AddressInput<'a> {
+    id_salt: &'a str,
+    label: &'a str,
+    ton: &'a mut Ton,
+    npi: &'a mut Npi,
+    addr: &'a mut String,
+    char_limit: usize,
+}
+
+impl<'a> AddressInput<'a> {
+    pub fn new(
+        id_salt: &'a str,
+        label: &'a str,
+        ton: &'a mut Ton,
+        npi: &'a mut Npi,
+        addr: &'a mut String,
+    ) -> Self {
+        Self {
+            id_salt,
+            label,
+            ton,
+            npi,
+            addr,
+            char_limit: 20,
+        }
+    }
+
+    pub fn char_limit(mut self, char_limit: usize) -> Self {
+        self.char_limit = char_limit;
+        self
+    }
+}
+
+impl<'a> egui::Widget for AddressInput<'a> {
+    /// The response is marked as changed when any of the three values changes.
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        ui.label(format!("{} TON", self.label));
+        let ton = ui.add(ComboBox::new(
+            &format!("{}_ton", self.id_salt),
+            self.ton,
+            Ton::VARIANTS,
+        ));
+
+        ui.label(format!("{} NPI", self.label));
+        let npi = ui.add(ComboBox::new(
+            &format!("{}_npi", self.id_salt),
+            self.npi,
+            Npi::VARIANTS,
+        ));
+
+        ui.label(self.label);
+        let addr = ui
+            .add(egui::TextEdit::singleline(self.addr).char_limit(self.char_limit))
+            .on_hover_text(format!("Max {} ASCII characters", self.char_limit));
+
+        ton | npi | addr
+    }
+}
@@ -29,15 +29,26 @@ where
     T: Copy + PartialEq,
     &'static str: From<T>,
 {
+    /// The response is marked as changed when another variant is selected.
     fn ui(self, ui: &mut egui::Ui) -> egui::Response {
-        egui::ComboBox::from_id_salt(self.id_salt)
+        let mut changed = false;
+
+        let mut response = egui::ComboBox::from_id_salt(self.id_salt)
             .width(self.width)
             .selected_text(<&'static str>::from(*self.selected))
             .show_ui(ui, |ui| {
                 for item in self.variants {
-                    ui.selectable_value(self.selected, *item, <&'static str>::from(*item));
+                    changed |= ui
+                        .selectable_value(self.selected, *item, <&'static str>::from(*item))
+                        .changed();
                 }
             })
-            .response
+            .response;
+
+        if changed {
+            response.mark_changed();
+        }
+
+        response
     }
 }
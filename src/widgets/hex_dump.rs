@@ -0,0 +1,126 @@
+use std::ops::Range;
+
+use eframe::egui::{
+    self, Color32, FontId, TextFormat,
+    text::{LayoutJob, TextWrapping},
+};
+
+use crate::colors::HIGH_BLUE;
+
+const BYTES_PER_ROW: usize = 16;
+
+/// Offset, hex and ASCII columns of a byte buffer.
+///
+/// Consecutive fields are shaded alternately and the highlighted range is drawn in [`HIGH_BLUE`].
+pub struct HexDump<'a> {
+    bytes: &'a [u8],
+    fields: Vec<Range<usize>>,
+    highlight: Option<Range<usize>>,
+}
+
+impl<'a> HexDump<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            fields: Vec::new(),
+            highlight: None,
+        }
+    }
+
+    pub fn fields(mut self, fields: Vec<Range<usize>>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    pub fn highlight(mut self, highlight: Option<Range<usize>>) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    fn background(&self, index: usize, shade: Color32) -> Color32 {
+        if self
+            .highlight
+            .as_ref()
+            .is_some_and(|range| range.contains(&index))
+        {
+            return HIGH_BLUE.gamma_multiply(0.5);
+        }
+
+        match self.fields.iter().position(|range| range.contains(&index)) {
+            Some(field) if field % 2 == 1 => shade,
+            _ => Color32::TRANSPARENT,
+        }
+    }
+
+    fn job(&self, ui: &egui::Ui) -> LayoutJob {
+        let font_id = FontId::monospace(ui.style().text_styles[&egui::TextStyle::Monospace].size);
+        let color = ui.visuals().text_color();
+        let weak = ui.visuals().weak_text_color();
+        let shade = ui.visuals().faint_bg_color.gamma_multiply(4.0);
+
+        let format = |color: Color32, background: Color32| TextFormat {
+            font_id: font_id.clone(),
+            color,
+            background,
+            ..Default::default()
+        };
+
+        let mut job = LayoutJob {
+            wrap: TextWrapping::no_max_width(),
+            ..Default::default()
+        };
+
+        for (row, chunk) in self.bytes.chunks(BYTES_PER_ROW).enumerate() {
+            let offset = row * BYTES_PER_ROW;
+
+            if row > 0 {
+                job.append("\n", 0.0, format(color, Color32::TRANSPARENT));
+            }
+
+            job.append(
+                &format!("{offset:08X}  "),
+                0.0,
+                format(weak, Color32::TRANSPARENT),
+            );
+
+            for column in 0..BYTES_PER_ROW {
+                let separator = if column == BYTES_PER_ROW / 2 { " " } else { "" };
+
+                job.append(separator, 0.0, format(color, Color32::TRANSPARENT));
+
+                match chunk.get(column) {
+                    Some(byte) => {
+                        let background = self.background(offset + column, shade);
+
+                        job.append(&format!("{byte:02X}"), 0.0, format(color, background));
+                        job.append(" ", 0.0, format(color, Color32::TRANSPARENT));
+                    }
+                    None => job.append("   ", 0.0, format(color, Color32::TRANSPARENT)),
+                }
+            }
+
+            job.append(" ", 0.0, format(color, Color32::TRANSPARENT));
+
+            for (column, byte) in chunk.iter().enumerate() {
+                let background = self.background(offset + column, shade);
+                let printable = if byte.is_ascii_graphic() || *byte == b' ' {
+                    *byte as char
+                } else {
+                    '.'
+                };
+
+                job.append(&printable.to_string(), 0.0, format(color, background));
+            }
+        }
+
+        job
+    }
+}
+
+impl egui::Widget for HexDump<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let job = self.job(ui);
+
+        ui.add(egui::Label::new(job).selectable(true))
+    }
+}
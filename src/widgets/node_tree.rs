@@ -0,0 +1,84 @@
+use std::ops::Range;
+
+use eframe::egui::{self, RichText};
+
+use crate::inspect::Node;
+
+/// Collapsible tree of [`Node`]s
+pub struct NodeTree<'a> {
+    id_salt: egui::Id,
+    nodes: &'a [Node],
+    hovered: Option<&'a mut Option<Range<usize>>>,
+}
+
+impl<'a> NodeTree<'a> {
+    pub fn new(id_salt: impl std::hash::Hash, nodes: &'a [Node]) -> Self {
+        Self {
+            id_salt: egui::Id::new(id_salt),
+            nodes,
+            hovered: None,
+        }
+    }
+
+    /// Reports the byte range of the hovered node, if any.
+    pub fn hovered(mut self, hovered: &'a mut Option<Range<usize>>) -> Self {
+        self.hovered = Some(hovered);
+        self
+    }
+
+    fn node_ui(ui: &mut egui::Ui, id: egui::Id, node: &Node, hovered: &mut Option<Range<usize>>) {
+        if node.children.is_empty() {
+            let response = ui
+                .horizontal_wrapped(|ui| {
+                    ui.label(RichText::new(node.name.as_ref()).strong());
+                    ui.label(&node.value);
+                })
+                .response;
+
+            if response.hovered() {
+                *hovered = node.range.clone();
+            }
+
+            return;
+        }
+
+        let title = if node.value.is_empty() {
+            RichText::new(node.name.as_ref()).strong()
+        } else {
+            RichText::new(format!("{}: {}", node.name, node.value))
+        };
+
+        let response = egui::CollapsingHeader::new(title)
+            .id_salt(id)
+            .default_open(node.name == "header" || node.name == "body")
+            .show(ui, |ui| {
+                for (index, child) in node.children.iter().enumerate() {
+                    Self::node_ui(ui, id.with(index), child, hovered);
+                }
+            });
+
+        if response.header_response.hovered() {
+            *hovered = node.range.clone();
+        }
+    }
+}
+
+impl egui::Widget for NodeTree<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let mut hovered = None;
+
+        let response = ui
+            .vertical(|ui| {
+                for (index, node) in self.nodes.iter().enumerate() {
+                    Self::node_ui(ui, self.id_salt.with(index), node, &mut hovered);
+                }
+            })
+            .response;
+
+        if let Some(out) = self.hovered {
+            *out = hovered;
+        }
+
+        response
+    }
+}
@@ -0,0 +1,106 @@
+use eframe::egui;
+use strum::VariantArray;
+
+use crate::{
+    values::{AbsoluteTime, SmppTime, SmppTimeFormat},
+    widgets::ComboBox,
+};
+
+/// Format selector with a date and time picker for absolute times and a duration input for relative ones
+pub struct SmppTimeInput<'a> {
+    id_salt: &'a str,
+    time: &'a mut SmppTime,
+}
+
+impl<'a> SmppTimeInput<'a> {
+    pub fn new(id_salt: &'a str, time: &'a mut SmppTime) -> Self {
+        Self { id_salt, time }
+    }
+}
+
+fn two_digits(value: &mut u8, range: std::ops::RangeInclusive<u8>) -> egui::DragValue<'_> {
+    egui::DragValue::new(value)
+        .range(range)
+        .custom_formatter(|value, _| format!("{value:02}"))
+}
+
+fn utc_offset(quarters: &mut i8) -> egui::DragValue<'_> {
+    egui::DragValue::new(quarters)
+        .range(-48..=48)
+        .custom_formatter(|quarters, _| {
+            let minutes = quarters as i64 * 15;
+            let sign = if minutes < 0 { '-' } else { '+' };
+
+            format!(
+                "UTC{sign}{:02}:{:02}",
+                minutes.abs() / 60,
+                minutes.abs() % 60
+            )
+        })
+}
+
+impl<'a> egui::Widget for SmppTimeInput<'a> {
+    /// The response is marked as changed when the format or any part of the time changes.
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let time = self.time;
+
+        ui.horizontal(|ui| {
+            let mut response = ui.add(ComboBox::new(
+                &format!("{}_format", self.id_salt),
+                &mut time.format,
+                SmppTimeFormat::VARIANTS,
+            ));
+
+            match time.format {
+                SmppTimeFormat::NotSet => {}
+                SmppTimeFormat::Absolute => {
+                    let absolute = &mut time.absolute;
+
+                    response |= ui.add(egui::DragValue::new(&mut absolute.year).range(2000..=2099));
+                    ui.label("-");
+                    response |= ui.add(two_digits(&mut absolute.month, 1..=12));
+                    ui.label("-");
+                    response |= ui.add(two_digits(&mut absolute.day, 1..=31));
+                    ui.add_space(6.0);
+                    response |= ui.add(two_digits(&mut absolute.hour, 0..=23));
+                    ui.label(":");
+                    response |= ui.add(two_digits(&mut absolute.minute, 0..=59));
+                    ui.label(":");
+                    response |= ui.add(two_digits(&mut absolute.second, 0..=59));
+                    ui.label(".");
+                    response |= ui
+                        .add(egui::DragValue::new(&mut absolute.tenths).range(0..=9))
+                        .on_hover_text("Tenths of a second");
+                    ui.add_space(6.0);
+                    response |= ui.add(utc_offset(&mut absolute.utc_offset_quarters));
+
+                    let mut now = ui.button("Now").on_hover_text("Current local time");
+
+                    if now.clicked() {
+                        *absolute = AbsoluteTime::now();
+                        now.mark_changed();
+                    }
+
+                    response |= now;
+                }
+                SmppTimeFormat::Relative => {
+                    let relative = &mut time.relative;
+
+                    for (value, unit) in [
+                        (&mut relative.years, "y"),
+                        (&mut relative.months, "mo"),
+                        (&mut relative.days, "d"),
+                        (&mut relative.hours, "h"),
+                        (&mut relative.minutes, "m"),
+                        (&mut relative.seconds, "s"),
+                    ] {
+                        response |= ui.add(egui::DragValue::new(value).range(0..=99).suffix(unit));
+                    }
+                }
+            }
+
+            response
+        })
+        .inner
+    }
+}
@@ -0,0 +1,29 @@
+use rusmpp::{
+    Command,
+    decode::DecodeWithLength,
+    encode::{Encode, Length},
+};
+
+/// Re-encodes a command into the exact bytes sent on the wire, including the `command_length` header.
+pub fn encode(command: &Command) -> Vec<u8> {
+    let command_length = command.length() + 4;
+    let mut bytes = vec![0; command_length];
+
+    bytes[..4].copy_from_slice(&(command_length as u32).to_be_bytes());
+    command.encode(&mut bytes[4..]);
+
+    bytes
+}
+
+/// Decodes the bytes of a single command as produced by [`encode`].
+pub fn decode(bytes: &[u8]) -> Option<Command> {
+    let (header, body) = bytes.split_first_chunk::<4>()?;
+
+    if u32::from_be_bytes(*header) as usize != bytes.len() {
+        return None;
+    }
+
+    Command::decode(body, body.len())
+        .ok()
+        .map(|(command, _)| command)
+}